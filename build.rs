@@ -21,13 +21,22 @@ fn main() {
                 "proto/common/version.proto",
                 "proto/common/common.proto",
                 "proto/machine/machine.proto",
+                "proto/cluster/cluster.proto",
+                "proto/cosi/v1alpha1/resource.proto",
+                "proto/cosi/v1alpha1/state.proto",
             ],
             &["proto"],
         )
         .unwrap();
 
     // Add SPDX header to generated files
-    for file_name in &["version.rs", "common.rs", "machine.rs"] {
+    for file_name in &[
+        "version.rs",
+        "common.rs",
+        "machine.rs",
+        "cluster.rs",
+        "cosi.resource.rs",
+    ] {
         let generated_file = out_dir.join(file_name);
         if generated_file.exists() {
             let content = std::fs::read_to_string(&generated_file).unwrap();
@@ -52,4 +61,7 @@ fn main() {
     println!("cargo:rerun-if-changed=proto/common/version.proto");
     println!("cargo:rerun-if-changed=proto/common/common.proto");
     println!("cargo:rerun-if-changed=proto/machine/machine.proto");
+    println!("cargo:rerun-if-changed=proto/cluster/cluster.proto");
+    println!("cargo:rerun-if-changed=proto/cosi/v1alpha1/resource.proto");
+    println!("cargo:rerun-if-changed=proto/cosi/v1alpha1/state.proto");
 }
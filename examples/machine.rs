@@ -49,21 +49,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // List services
     println!("\n--- Services ---");
-    match machine.service_list(()).await {
+    match client.service_list().await {
         Ok(response) => {
-            for msg in &response.get_ref().messages {
-                let node = msg
-                    .metadata
-                    .as_ref()
-                    .map(|m| m.hostname.as_str())
-                    .unwrap_or("unknown");
-                println!("Node: {}", node);
-                for svc in &msg.services {
-                    let health = svc
-                        .health
-                        .as_ref()
-                        .map(|h| if h.healthy { "healthy" } else { "unhealthy" })
-                        .unwrap_or("unknown");
+            for result in &response.results {
+                println!("Node: {}", result.node.as_deref().unwrap_or("unknown"));
+                for svc in &result.services {
+                    let health = match svc.healthy {
+                        Some(true) => "healthy",
+                        Some(false) => "unhealthy",
+                        None => "unknown",
+                    };
                     println!("  - {} [state: {}, health: {}]", svc.id, svc.state, health);
                 }
             }
@@ -73,25 +68,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Get system statistics
     println!("\n--- System Statistics ---");
-    match machine.system_stat(()).await {
+    match client.system_stat().await {
         Ok(response) => {
-            for msg in &response.get_ref().messages {
-                let node = msg
-                    .metadata
-                    .as_ref()
-                    .map(|m| m.hostname.as_str())
-                    .unwrap_or("unknown");
-                println!("Node: {}", node);
-                println!("  Boot time: {}", msg.boot_time);
-                println!("  Processes running: {}", msg.process_running);
-                println!("  Processes blocked: {}", msg.process_blocked);
-                println!("  Context switches: {}", msg.context_switches);
-                if let Some(cpu) = &msg.cpu_total {
-                    println!(
-                        "  CPU total - user: {:.2}%, system: {:.2}%, idle: {:.2}%",
-                        cpu.user, cpu.system, cpu.idle
-                    );
-                }
+            for stat in &response.results {
+                println!("Node: {}", stat.node.as_deref().unwrap_or("unknown"));
+                println!("  Boot time: {}", stat.boot_time);
+                println!("  Processes running: {}", stat.process_running);
+                println!("  Processes blocked: {}", stat.process_blocked);
+                println!("  Context switches: {}", stat.context_switches);
+                println!(
+                    "  CPU usage since boot: {:.2}% across {} cores",
+                    stat.cpu_total_usage_percent(),
+                    stat.cpu.len()
+                );
             }
         }
         Err(e) => eprintln!("Failed to get system stats: {}", e),
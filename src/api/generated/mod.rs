@@ -3,11 +3,17 @@
 // DO NOT EDIT
 // This file is generated by build scripts.
 
+pub mod cosi {
+    pub mod resource {
+        include!("cosi.resource.rs");
+    }
+}
 pub mod google {
     pub mod rpc {
         include!("google.rpc.rs");
     }
 }
+pub mod cluster;
 pub mod common;
 pub mod machine;
 pub mod version;
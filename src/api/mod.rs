@@ -3,6 +3,8 @@
 pub mod generated;
 
 // Re-export API modules
+pub use generated::cluster;
 pub use generated::common;
+pub use generated::cosi;
 pub use generated::machine;
 pub use generated::version;
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Detection of RPCs a node does not implement.
+//!
+//! Docker-based and older Talos nodes answer some RPCs with `Unimplemented`.
+//! Every call made through a [`TalosClient`](super::TalosClient) records
+//! whether its method was implemented, so callers can check
+//! [`Capabilities::supports`] before issuing a call that would fail anyway.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::runtime::method_from_path;
+
+/// gRPC status code for `Unimplemented`.
+const GRPC_STATUS_UNIMPLEMENTED: &str = "12";
+
+/// Snapshot of which RPCs a node implements.
+///
+/// Methods are keyed by their gRPC name (e.g. `"EtcdSnapshot"`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    methods: HashMap<String, bool>,
+}
+
+impl Capabilities {
+    /// Check if `method` is supported.
+    ///
+    /// Methods that have not been called yet are assumed to be supported.
+    #[must_use]
+    pub fn supports(&self, method: &str) -> bool {
+        self.methods.get(method).copied().unwrap_or(true)
+    }
+
+    /// Check if `method` has been observed, either as supported or not.
+    #[must_use]
+    pub fn is_known(&self, method: &str) -> bool {
+        self.methods.contains_key(method)
+    }
+
+    /// Methods that returned `Unimplemented`, sorted by name.
+    #[must_use]
+    pub fn unsupported(&self) -> Vec<&str> {
+        let mut methods: Vec<&str> = self
+            .methods
+            .iter()
+            .filter(|(_, supported)| !**supported)
+            .map(|(method, _)| method.as_str())
+            .collect();
+        methods.sort_unstable();
+        methods
+    }
+}
+
+/// Capability observations shared by all clones of a channel.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CapabilityCache(Arc<RwLock<HashMap<String, bool>>>);
+
+impl CapabilityCache {
+    /// Record the outcome of a call to `path` from its response headers.
+    ///
+    /// `Unimplemented` is always a trailers-only response, so its status is
+    /// visible in the headers; any other response means the method exists.
+    pub(crate) fn record(&self, path: &str, headers: &http::HeaderMap) {
+        let supported = headers
+            .get("grpc-status")
+            .is_none_or(|status| status != GRPC_STATUS_UNIMPLEMENTED);
+        if let Ok(mut methods) = self.0.write() {
+            methods.insert(method_from_path(path).to_string(), supported);
+        }
+    }
+
+    /// Check if `method` has been observed.
+    pub(crate) fn is_known(&self, method: &str) -> bool {
+        self.0
+            .read()
+            .map(|methods| methods.contains_key(method))
+            .unwrap_or(false)
+    }
+
+    /// Copy the current observations.
+    pub(crate) fn snapshot(&self) -> Capabilities {
+        Capabilities {
+            methods: self.0.read().map(|m| m.clone()).unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(status: Option<&'static str>) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        if let Some(status) = status {
+            headers.insert("grpc-status", http::HeaderValue::from_static(status));
+        }
+        headers
+    }
+
+    #[test]
+    fn test_record_from_headers() {
+        let cache = CapabilityCache::default();
+        cache.record("/machine.MachineService/EtcdSnapshot", &headers(Some("12")));
+        cache.record("/machine.MachineService/Hostname", &headers(None));
+        cache.record("/machine.MachineService/EtcdStatus", &headers(Some("14")));
+
+        let caps = cache.snapshot();
+        assert!(!caps.supports("EtcdSnapshot"));
+        assert!(caps.supports("Hostname"));
+        assert!(caps.supports("EtcdStatus"));
+        assert!(caps.supports("Memory"));
+        assert!(!caps.is_known("Memory"));
+        assert_eq!(caps.unsupported(), vec!["EtcdSnapshot"]);
+    }
+
+    #[test]
+    fn test_later_success_overrides() {
+        let cache = CapabilityCache::default();
+        cache.record("/machine.MachineService/Read", &headers(Some("12")));
+        cache.record("/machine.MachineService/Read", &headers(Some("0")));
+        assert!(cache.snapshot().supports("Read"));
+    }
+}
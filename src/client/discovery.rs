@@ -31,65 +31,47 @@
 
 use crate::client::{TalosClient, TalosClientConfig};
 use crate::error::Result;
+pub use crate::resources::{ClusterMember, NodeRole};
+use serde::Deserialize;
 use std::collections::HashMap;
-use std::time::Duration;
-
-/// Role of a node in the Talos cluster.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum NodeRole {
-    /// Control plane node (runs etcd, API server, etc.)
-    ControlPlane,
-    /// Worker node (runs workloads only)
-    Worker,
-    /// Unknown role
-    Unknown,
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default number of endpoints health-checked at the same time.
+pub const DEFAULT_DISCOVERY_CONCURRENCY: usize = 8;
+
+/// COSI namespace of the cluster membership resources.
+const MEMBERS_NAMESPACE: &str = "cluster";
+/// COSI type of the resource listed by `talosctl get members`.
+const MEMBERS_TYPE: &str = "Members.cluster.talos.dev";
+/// Port of the Talos API on every node.
+const TALOS_API_PORT: u16 = 50000;
+
+/// Spec of a `Members.cluster.talos.dev` resource, as YAML.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MemberSpec {
+    #[serde(default)]
+    addresses: Vec<String>,
+    hostname: String,
+    #[serde(default)]
+    machine_type: String,
 }
 
-impl std::fmt::Display for NodeRole {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::ControlPlane => write!(f, "controlplane"),
-            Self::Worker => write!(f, "worker"),
-            Self::Unknown => write!(f, "unknown"),
-        }
-    }
-}
-
-/// Information about a discovered cluster member.
-#[derive(Debug, Clone)]
-pub struct ClusterMember {
-    /// Node name/hostname
-    pub name: String,
-    /// gRPC endpoint URL
-    pub endpoint: String,
-    /// Node role
-    pub role: NodeRole,
-    /// Whether this node is an etcd member
-    pub is_etcd_member: bool,
-}
-
-impl ClusterMember {
-    /// Create a new cluster member
-    #[must_use]
-    pub fn new(name: impl Into<String>, endpoint: impl Into<String>, role: NodeRole) -> Self {
-        Self {
-            name: name.into(),
-            endpoint: endpoint.into(),
-            role,
-            is_etcd_member: role == NodeRole::ControlPlane,
-        }
-    }
-
-    /// Check if this is a control plane node
-    #[must_use]
-    pub fn is_control_plane(&self) -> bool {
-        self.role == NodeRole::ControlPlane
-    }
-
-    /// Check if this is a worker node
-    #[must_use]
-    pub fn is_worker(&self) -> bool {
-        self.role == NodeRole::Worker
+impl MemberSpec {
+    /// Build a member reached through its first address
+    fn into_member(self) -> ClusterMember {
+        let host = match self.addresses.first() {
+            Some(addr) if addr.parse::<std::net::Ipv6Addr>().is_ok() => format!("[{addr}]"),
+            Some(addr) => addr.clone(),
+            None => self.hostname.clone(),
+        };
+        ClusterMember::new(
+            self.hostname,
+            format!("https://{host}:{TALOS_API_PORT}"),
+            NodeRole::from_machine_type(&self.machine_type),
+        )
     }
 }
 
@@ -222,6 +204,10 @@ pub struct ClusterDiscoveryBuilder {
     request_timeout: Duration,
     /// Skip TLS verification
     insecure: bool,
+    /// How long discovered members are reused (None disables caching)
+    member_cache_ttl: Option<Duration>,
+    /// Maximum number of endpoints checked at once
+    concurrency: usize,
 }
 
 impl ClusterDiscoveryBuilder {
@@ -236,6 +222,8 @@ impl ClusterDiscoveryBuilder {
             connect_timeout: Duration::from_secs(10),
             request_timeout: Duration::from_secs(5),
             insecure: false,
+            member_cache_ttl: None,
+            concurrency: DEFAULT_DISCOVERY_CONCURRENCY,
         }
     }
 
@@ -279,6 +267,28 @@ impl ClusterDiscoveryBuilder {
         self
     }
 
+    /// Cache discovered members for `ttl`
+    ///
+    /// Repeated calls to [`ClusterDiscovery::discover_members`] (and the health
+    /// checks built on it) within the TTL reuse the cached member list instead
+    /// of querying etcd again. Use [`ClusterDiscovery::invalidate`] to force a
+    /// refresh.
+    #[must_use]
+    pub fn with_member_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.member_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Set how many endpoints are health-checked at the same time
+    ///
+    /// Defaults to [`DEFAULT_DISCOVERY_CONCURRENCY`]; values below 1 are
+    /// treated as 1 (sequential checks).
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
     /// Build the cluster discovery instance
     #[must_use]
     pub fn build(self) -> ClusterDiscovery {
@@ -290,13 +300,25 @@ impl ClusterDiscoveryBuilder {
             connect_timeout: self.connect_timeout,
             request_timeout: self.request_timeout,
             insecure: self.insecure,
+            member_cache_ttl: self.member_cache_ttl,
+            member_cache: Arc::new(Mutex::new(None)),
+            concurrency: self.concurrency,
         }
     }
 }
 
+/// Member list cached by [`ClusterDiscovery`], with the time it was fetched.
+#[derive(Debug)]
+struct CachedMembers {
+    fetched_at: Instant,
+    members: Vec<ClusterMember>,
+}
+
 /// Cluster discovery helper for Talos clusters.
 ///
 /// Provides utilities for discovering cluster members and checking their health.
+///
+/// Clones share the member cache, so invalidating one clone refreshes all.
 #[derive(Debug, Clone)]
 pub struct ClusterDiscovery {
     endpoint: String,
@@ -306,6 +328,9 @@ pub struct ClusterDiscovery {
     connect_timeout: Duration,
     request_timeout: Duration,
     insecure: bool,
+    member_cache_ttl: Option<Duration>,
+    member_cache: Arc<Mutex<Option<CachedMembers>>>,
+    concurrency: usize,
 }
 
 impl ClusterDiscovery {
@@ -339,16 +364,91 @@ impl ClusterDiscovery {
     /// Connect to the primary endpoint and get a client
     async fn connect_primary(&self) -> Result<TalosClient> {
         let config = self.create_config(&self.endpoint);
-        TalosClient::new(config).await
+        TalosClient::new(config)
+            .await
+            .map_err(|e| e.at_endpoint(&self.endpoint))
     }
 
-    /// Discover cluster members via etcd member list
+    /// Drop the cached member list so the next discovery queries etcd again
+    pub fn invalidate(&self) {
+        *self.member_cache.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Discover cluster members and their roles
     ///
-    /// This connects to the initial endpoint and queries the etcd member list
-    /// to discover all control plane nodes.
+    /// This connects to the initial endpoint and lists the cluster's members
+    /// resource, which includes workers. Clusters without it fall back to the
+    /// etcd member list, which only finds control plane nodes. If a member
+    /// cache TTL is configured, a member list fetched within the TTL is
+    /// returned without reconnecting.
     pub async fn discover_members(&self) -> Result<Vec<ClusterMember>> {
+        self.cached_members(|| self.fetch_members()).await
+    }
+
+    /// Return the cached member list if still fresh, otherwise run `fetch` and cache its result
+    async fn cached_members<F, Fut>(&self, fetch: F) -> Result<Vec<ClusterMember>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<ClusterMember>>>,
+    {
+        let Some(ttl) = self.member_cache_ttl else {
+            return fetch().await;
+        };
+
+        {
+            let cache = self.member_cache.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(cached) = cache.as_ref() {
+                if cached.fetched_at.elapsed() < ttl {
+                    return Ok(cached.members.clone());
+                }
+            }
+        }
+
+        let members = fetch().await?;
+        *self.member_cache.lock().unwrap_or_else(|e| e.into_inner()) = Some(CachedMembers {
+            fetched_at: Instant::now(),
+            members: members.clone(),
+        });
+        Ok(members)
+    }
+
+    /// Query the cluster for the current member list, bypassing the cache
+    ///
+    /// Reads the `members` resource (as `talosctl get members` does), which
+    /// lists workers too and reports each node's actual role. Falls back to
+    /// the etcd member list if the resource can't be read, e.g. when cluster
+    /// discovery is disabled.
+    async fn fetch_members(&self) -> Result<Vec<ClusterMember>> {
         let client = self.connect_primary().await?;
 
+        match Self::resource_members(&client).await {
+            Ok(members) if !members.is_empty() => return Ok(members),
+            Ok(_) => tracing::debug!(
+                "No members resource on {}, falling back to etcd",
+                self.endpoint
+            ),
+            Err(e) => tracing::debug!(
+                "Failed to list members on {}, falling back to etcd: {}",
+                self.endpoint,
+                e
+            ),
+        }
+
+        self.etcd_members(&client).await
+    }
+
+    /// List members from the `Members.cluster.talos.dev` resources
+    async fn resource_members(client: &TalosClient) -> Result<Vec<ClusterMember>> {
+        client
+            .resource_list(MEMBERS_NAMESPACE, MEMBERS_TYPE)
+            .await?
+            .iter()
+            .map(|resource| Ok(resource.spec_as::<MemberSpec>()?.into_member()))
+            .collect()
+    }
+
+    /// Query etcd for the control plane members
+    async fn etcd_members(&self, client: &TalosClient) -> Result<Vec<ClusterMember>> {
         // Use EtcdMemberList to discover control plane nodes
         let etcd_response = client
             .etcd_member_list(crate::resources::EtcdMemberListRequest::new())
@@ -387,11 +487,15 @@ impl ClusterDiscovery {
     /// Tries the Version API first, falls back to Hostname API if unavailable.
     /// This is necessary because Docker-based Talos clusters don't implement
     /// the Version API.
-    async fn check_endpoint_health(&self, name: &str, endpoint: &str) -> NodeHealth {
-        let config = self.create_config(endpoint);
+    async fn check_endpoint_health(
+        config: TalosClientConfig,
+        tls_config: Arc<rustls::ClientConfig>,
+        name: &str,
+    ) -> NodeHealth {
+        let endpoint = config.endpoint.clone();
         let start = std::time::Instant::now();
 
-        match TalosClient::new(config).await {
+        match TalosClient::new_with_tls_config(config, tls_config).await {
             Ok(client) => {
                 // Try Version API first
                 let mut version_client = client.version();
@@ -448,30 +552,78 @@ impl ClusterDiscovery {
     ///
     /// Useful when you already have a list of members.
     pub async fn check_members_health(&self, members: &[ClusterMember]) -> Result<ClusterHealth> {
-        let mut health_results = Vec::with_capacity(members.len());
-
-        for member in members {
-            let health = self
-                .check_endpoint_health(&member.name, &member.endpoint)
-                .await;
-            health_results.push(health);
-        }
-
-        Ok(ClusterHealth::from_nodes(health_results))
+        let targets = members
+            .iter()
+            .map(|m| (m.name.clone(), m.endpoint.clone()))
+            .collect();
+        Ok(self.check_targets_health(targets).await)
     }
 
     /// Check health of multiple endpoints directly
     ///
     /// Useful when you have a list of endpoint URLs but not member info.
     pub async fn check_endpoints_health(&self, endpoints: &[String]) -> Result<ClusterHealth> {
-        let mut health_results = Vec::with_capacity(endpoints.len());
+        let targets = endpoints.iter().map(|e| (e.clone(), e.clone())).collect();
+        Ok(self.check_targets_health(targets).await)
+    }
 
-        for endpoint in endpoints {
-            let health = self.check_endpoint_health(endpoint, endpoint).await;
-            health_results.push(health);
+    /// Check `(name, endpoint)` pairs, up to `concurrency` at a time
+    ///
+    /// Results are sorted by name (ties keep their input order), whatever
+    /// order the checks finish in.
+    async fn check_targets_health(&self, targets: Vec<(String, String)>) -> ClusterHealth {
+        // One TLS config for all checks, so credentials are loaded once.
+        let base = self.create_config(&self.endpoint);
+        let tls_config = match TalosClient::native_tls_config(&base) {
+            Ok(tls_config) => tls_config,
+            Err(e) => {
+                let error = e.to_string();
+                let mut nodes: Vec<NodeHealth> = targets
+                    .into_iter()
+                    .map(|(name, endpoint)| NodeHealth::unhealthy(name, endpoint, error.clone()))
+                    .collect();
+                nodes.sort_by(|a, b| a.name.cmp(&b.name));
+                return ClusterHealth::from_nodes(nodes);
+            }
+        };
+
+        let mut results: Vec<Option<NodeHealth>> = vec![None; targets.len()];
+        let mut pending = targets.iter().enumerate();
+        let mut checks = tokio::task::JoinSet::new();
+        loop {
+            while checks.len() < self.concurrency {
+                let Some((i, (name, endpoint))) = pending.next() else {
+                    break;
+                };
+                let (config, tls_config, name) = (
+                    base.with_endpoint(endpoint),
+                    tls_config.clone(),
+                    name.clone(),
+                );
+                checks.spawn(async move {
+                    let health = Self::check_endpoint_health(config, tls_config, &name).await;
+                    (i, health)
+                });
+            }
+            let Some(joined) = checks.join_next().await else {
+                break;
+            };
+            if let Ok((i, health)) = joined {
+                results[i] = Some(health);
+            }
         }
 
-        Ok(ClusterHealth::from_nodes(health_results))
+        let mut nodes: Vec<NodeHealth> = results
+            .into_iter()
+            .zip(targets)
+            .map(|(health, (name, endpoint))| {
+                health.unwrap_or_else(|| {
+                    NodeHealth::unhealthy(name, endpoint, "health check task panicked")
+                })
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
+        ClusterHealth::from_nodes(nodes)
     }
 
     /// Get a map of endpoint to version for healthy nodes
@@ -497,6 +649,38 @@ mod tests {
         assert_eq!(format!("{}", NodeRole::Unknown), "unknown");
     }
 
+    #[test]
+    fn test_node_role_from_machine_type() {
+        assert_eq!(
+            NodeRole::from_machine_type("controlplane"),
+            NodeRole::ControlPlane
+        );
+        assert_eq!(NodeRole::from_machine_type("init"), NodeRole::ControlPlane);
+        assert_eq!(NodeRole::from_machine_type("worker"), NodeRole::Worker);
+        assert_eq!(NodeRole::from_machine_type("unknown"), NodeRole::Unknown);
+        assert_eq!(NodeRole::from_machine_type(""), NodeRole::Unknown);
+    }
+
+    #[test]
+    fn test_member_spec_into_member() {
+        let spec: MemberSpec = serde_yaml::from_str(
+            "nodeId: abc\naddresses:\n    - 172.20.0.5\n    - fd00::5\nhostname: worker-1\n\
+             machineType: worker\noperatingSystem: Talos (v1.9.0)\n",
+        )
+        .unwrap();
+        let member = spec.into_member();
+        assert_eq!(member.name, "worker-1");
+        assert_eq!(member.endpoint, "https://172.20.0.5:50000");
+        assert!(member.is_worker());
+        assert!(!member.is_etcd_member);
+
+        let spec: MemberSpec =
+            serde_yaml::from_str("hostname: cp-1\nmachineType: controlplane\n").unwrap();
+        let member = spec.into_member();
+        assert_eq!(member.endpoint, "https://cp-1:50000");
+        assert!(member.is_etcd_member);
+    }
+
     #[test]
     fn test_cluster_member_new() {
         let member = ClusterMember::new(
@@ -610,6 +794,17 @@ mod tests {
         assert!(!discovery.insecure);
     }
 
+    #[test]
+    fn test_cluster_discovery_builder_concurrency() {
+        let builder = ClusterDiscovery::from_endpoint("https://192.168.1.100:50000");
+        assert_eq!(
+            builder.clone().build().concurrency,
+            DEFAULT_DISCOVERY_CONCURRENCY
+        );
+        assert_eq!(builder.clone().with_concurrency(3).build().concurrency, 3);
+        assert_eq!(builder.with_concurrency(0).build().concurrency, 1);
+    }
+
     #[test]
     fn test_cluster_discovery_builder_insecure() {
         let discovery = ClusterDiscovery::from_endpoint("https://192.168.1.100:50000")
@@ -618,4 +813,86 @@ mod tests {
 
         assert!(discovery.insecure);
     }
+
+    fn counting_fetch(
+        calls: &std::sync::atomic::AtomicUsize,
+    ) -> impl Future<Output = Result<Vec<ClusterMember>>> + '_ {
+        calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        async {
+            Ok(vec![ClusterMember::new(
+                "cp-1",
+                "https://10.0.0.1:50000",
+                NodeRole::ControlPlane,
+            )])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_member_cache_reused_within_ttl() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let discovery = ClusterDiscovery::from_endpoint("https://192.168.1.100:50000")
+            .with_member_cache_ttl(Duration::from_secs(60))
+            .build();
+        let calls = AtomicUsize::new(0);
+
+        let first = discovery
+            .cached_members(|| counting_fetch(&calls))
+            .await
+            .unwrap();
+        let second = discovery
+            .cached_members(|| counting_fetch(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.len(), 1);
+        assert_eq!(second[0].name, "cp-1");
+
+        discovery.invalidate();
+        discovery
+            .cached_members(|| counting_fetch(&calls))
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_member_cache_refreshed_after_ttl() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let discovery = ClusterDiscovery::from_endpoint("https://192.168.1.100:50000")
+            .with_member_cache_ttl(Duration::from_millis(10))
+            .build();
+        let calls = AtomicUsize::new(0);
+
+        discovery
+            .cached_members(|| counting_fetch(&calls))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        discovery
+            .cached_members(|| counting_fetch(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_member_cache_disabled_by_default() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let discovery = ClusterDiscovery::from_endpoint("https://192.168.1.100:50000").build();
+        let calls = AtomicUsize::new(0);
+
+        for _ in 0..3 {
+            discovery
+                .cached_members(|| counting_fetch(&calls))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
 }
@@ -1,17 +1,33 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod capabilities;
 pub mod discovery;
 mod node_target;
+mod pinning;
 pub mod pool;
 #[cfg(test)]
 mod tests;
+mod transport;
+mod upload;
 
+pub use capabilities::Capabilities;
 pub use discovery::{ClusterDiscovery, ClusterHealth, ClusterMember, NodeHealth, NodeRole};
 pub use node_target::{NodeTarget, NODE_METADATA_KEY};
-
+pub use pinning::{certificate_fingerprint, parse_fingerprint};
+pub use transport::{TalosChannel, Transport};
+pub use upload::{ChunkedReader, Rechunk, UploadError, UploadStream, DEFAULT_UPLOAD_CHUNK_SIZE};
+
+use crate::api::cluster::cluster_service_client::ClusterServiceClient;
+use crate::api::cluster::HealthCheckRequest as ProtoHealthCheckRequest;
+use crate::api::cosi::resource::state_client::StateClient;
+use crate::api::cosi::resource::GetRequest as ProtoCosiGetRequest;
+use crate::api::cosi::resource::ListRequest as ProtoCosiListRequest;
+use crate::api::cosi::resource::WatchRequest as ProtoCosiWatchRequest;
+use crate::api::cosi::resource::WatchResponse as ProtoCosiWatchResponse;
 use crate::api::machine::machine_service_client::MachineServiceClient;
 use crate::api::machine::ApplyConfigurationRequest as ProtoApplyConfigRequest;
 use crate::api::machine::BootstrapRequest as ProtoBootstrapRequest;
+use crate::api::machine::ContainersRequest as ProtoContainersRequest;
 use crate::api::machine::CopyRequest as ProtoCopyRequest;
 use crate::api::machine::DiskUsageRequest as ProtoDiskUsageRequest;
 use crate::api::machine::DmesgRequest as ProtoDmesgRequest;
@@ -26,40 +42,151 @@ use crate::api::machine::ImageListRequest as ProtoImageListRequest;
 use crate::api::machine::ImagePullRequest as ProtoImagePullRequest;
 use crate::api::machine::ListRequest as ProtoListRequest;
 use crate::api::machine::LogsRequest as ProtoLogsRequest;
+use crate::api::machine::MetaDeleteRequest as ProtoMetaDeleteRequest;
+use crate::api::machine::MetaWriteRequest as ProtoMetaWriteRequest;
 use crate::api::machine::NetstatRequest as ProtoNetstatRequest;
 use crate::api::machine::PacketCaptureRequest as ProtoPacketCaptureRequest;
 use crate::api::machine::ReadRequest as ProtoReadRequest;
+use crate::api::machine::RebootRequest as ProtoRebootRequest;
 use crate::api::machine::ResetRequest as ProtoResetRequest;
 use crate::api::machine::RollbackRequest as ProtoRollbackRequest;
 use crate::api::machine::ServiceRestartRequest as ProtoServiceRestartRequest;
 use crate::api::machine::ServiceStartRequest as ProtoServiceStartRequest;
 use crate::api::machine::ServiceStopRequest as ProtoServiceStopRequest;
+use crate::api::machine::ShutdownRequest as ProtoShutdownRequest;
+use crate::api::machine::StatsRequest as ProtoStatsRequest;
 use crate::api::machine::UpgradeRequest as ProtoUpgradeRequest;
 use crate::api::version::version_service_client::VersionServiceClient;
+use crate::api::version::VersionRequest;
 use crate::error::Result;
 use crate::resources::{
-    ApplyConfigurationRequest, ApplyConfigurationResponse, BootstrapRequest, BootstrapResponse,
-    CopyRequest, CopyResponse, CpuInfoResponse, DiskStatsResponse, DiskUsageInfo, DiskUsageRequest,
-    DiskUsageResponse, DmesgRequest, DmesgResponse, EtcdAlarmDisarmResponse, EtcdAlarmListResponse,
-    EtcdDefragmentResponse, EtcdForfeitLeadershipRequest, EtcdForfeitLeadershipResponse,
-    EtcdLeaveClusterRequest, EtcdLeaveClusterResponse, EtcdMemberListRequest,
-    EtcdMemberListResponse, EtcdRemoveMemberByIdRequest, EtcdRemoveMemberByIdResponse,
+    ApplyConfigurationRequest, ApplyConfigurationResponse, ApplyRollbackOutcome,
+    ApplyRollbackPolicy, ApplyWarning, BootstrapRequest, BootstrapResponse,
+    ClusterHealthCheckRequest, ContainerStatsResponse, ContainersRequest, ContainersResponse,
+    CopyRequest, CopyResponse, CosiEvent, CosiResource, CosiWatchRequest, CpuInfoResponse,
+    DataChunk, DiskStatsResponse, DiskUsageInfo, DiskUsageRequest, DiskUsageResponse, DmesgChunk,
+    DmesgRequest, DmesgResponse, DrainReport, DrainStep, EtcdAlarmDisarmResponse,
+    EtcdAlarmListResponse, EtcdAlarmType, EtcdDefragmentResponse, EtcdForfeitLeadershipRequest,
+    EtcdForfeitLeadershipResponse, EtcdLeaveClusterRequest, EtcdLeaveClusterResponse, EtcdMember,
+    EtcdMemberListRequest, EtcdMemberListResponse, EtcdRecoverResponse,
+    EtcdRemoveMemberByIdRequest, EtcdRemoveMemberByIdResponse, EtcdSnapshotChunk,
     EtcdSnapshotRequest, EtcdSnapshotResponse, EtcdStatusResponse, Event, EventsRequest, FileInfo,
-    GenerateClientConfigurationRequest, GenerateClientConfigurationResponse, ImageInfo,
+    GenerateClientConfigurationRequest, GenerateClientConfigurationResponse,
+    GenerateClientConfigurationResult, HealthCheckProgress, HostnameResponse, ImageInfo,
     ImageListRequest, ImagePullRequest, ImagePullResponse, KubeconfigResponse, ListRequest,
-    ListResponse, LoadAvgResponse, LogsRequest, LogsResponse, MemoryResponse, MountsResponse,
-    NetstatRequest, NetstatResponse, NetworkDeviceStatsResponse, PacketCaptureRequest,
-    PacketCaptureResponse, ProcessesResponse, ReadRequest, ReadResponse, ResetRequest,
-    ResetResponse, RollbackResponse, ServiceRestartRequest, ServiceRestartResponse,
-    ServiceStartRequest, ServiceStartResponse, ServiceStopRequest, ServiceStopResponse,
-    UpgradeRequest, UpgradeResponse,
+    ListResponse, LoadAvgResponse, LogChunk, LogsRequest, LogsResponse, MachineStatus,
+    MemoryResponse, MetaDeleteRequest, MetaDeleteResponse, MetaWriteRequest, MetaWriteResponse,
+    MountsResponse, NetstatRequest, NetstatResponse, NetworkDeviceStatsResponse,
+    NodeUpgradeOutcome, NodeUpgradeStatus, NospaceRemediation, NospaceRemediationReport,
+    PacketCaptureRequest, PacketCaptureResponse, PcapFramer, ProcessesResponse, ReadRequest,
+    ReadResponse, RebootRequest, RebootResponse, ResetRequest, ResetResponse, RollbackResponse,
+    RollingUpgradeOptions, RollingUpgradeOrder, RollingUpgradeReport, ServiceListResponse,
+    ServiceRestartRequest, ServiceRestartResponse, ServiceStartRequest, ServiceStartResponse,
+    ServiceStopRequest, ServiceStopResponse, ShutdownRequest, ShutdownResponse, SystemStatResponse,
+    UpgradeRequest, UpgradeResponse, VersionResponse,
+};
+use crate::runtime::{
+    BackoffStrategy, ExponentialBackoff, MetricsCollector, MetricsInterceptor, RetryConfig,
 };
+use bytes::Bytes;
 use hyper_util::rt::TokioIo;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
+use tonic::codegen::tokio_stream::Stream;
 use tonic::transport::{Channel, Endpoint};
 
+/// TLS protocol version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TlsVersion {
+    /// TLS 1.2.
+    Tls12,
+    /// TLS 1.3.
+    Tls13,
+}
+
+impl TlsVersion {
+    fn as_rustls(self) -> &'static rustls::SupportedProtocolVersion {
+        match self {
+            TlsVersion::Tls12 => &rustls::version::TLS12,
+            TlsVersion::Tls13 => &rustls::version::TLS13,
+        }
+    }
+}
+
+impl std::fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlsVersion::Tls12 => write!(f, "TLS 1.2"),
+            TlsVersion::Tls13 => write!(f, "TLS 1.3"),
+        }
+    }
+}
+
+/// Client identity stored in a PKCS#12 (`.p12`/`.pfx`) bundle.
+///
+/// The password is redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Pkcs12Identity {
+    /// Path to the bundle.
+    pub path: String,
+    /// Bundle password (empty for unprotected bundles).
+    pub password: String,
+}
+
+impl Pkcs12Identity {
+    /// Create a new PKCS#12 identity.
+    #[must_use]
+    pub fn new(path: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            password: password.into(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Pkcs12Identity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pkcs12Identity")
+            .field("path", &self.path)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Client certificate and key held in memory as PEM.
+///
+/// The key is redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PemIdentity {
+    /// PEM-encoded client certificate (chain).
+    pub crt: Vec<u8>,
+    /// PEM-encoded private key.
+    pub key: Vec<u8>,
+}
+
+impl PemIdentity {
+    /// Create a new in-memory identity.
+    #[must_use]
+    pub fn new(crt: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            crt: crt.into(),
+            key: key.into(),
+        }
+    }
+}
+
+impl std::fmt::Debug for PemIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PemIdentity")
+            .field("crt", &String::from_utf8_lossy(&self.crt))
+            .field("key", &"<redacted>")
+            .finish()
+    }
+}
+
 /// Configuration for the Talos API client.
 #[derive(Clone, Debug)]
 pub struct TalosClientConfig {
@@ -71,18 +198,103 @@ pub struct TalosClientConfig {
     pub key_path: Option<String>,
     /// Path to CA certificate.
     pub ca_path: Option<String>,
+    /// PKCS#12 client identity, used instead of `crt_path`/`key_path` when set.
+    pub client_p12: Option<Pkcs12Identity>,
+    /// In-memory CA certificate (PEM), used instead of `ca_path` when set.
+    pub ca_pem: Option<Vec<u8>>,
+    /// In-memory client identity, used instead of `crt_path`/`key_path` when
+    /// set. A `client_p12` bundle takes precedence.
+    pub client_pem: Option<PemIdentity>,
     /// If true, skips TLS verification (insecure).
     pub insecure: bool,
+    /// SHA-256 fingerprints of accepted server certificates.
+    ///
+    /// When non-empty, the server must present one of these certificates, in
+    /// addition to passing CA verification (or instead of it in insecure mode).
+    pub pinned_server_certs: Vec<[u8; 32]>,
     /// Connection timeout for establishing the gRPC channel.
     pub connect_timeout: Option<Duration>,
     /// Request timeout for individual RPC calls.
+    ///
+    /// Override it for some calls with [`TalosClient::with_call_timeout`].
     pub request_timeout: Option<Duration>,
+    /// Overall deadline for draining a server-streaming response.
+    ///
+    /// `request_timeout` only covers the wait for response headers; this
+    /// bounds the whole stream. `None` means unlimited. The `*_stream`
+    /// methods are open-ended and only honour `stream_idle_timeout`.
+    pub stream_timeout: Option<Duration>,
+    /// Maximum gap between two messages of a server-streaming response,
+    /// including the streams returned by the `*_stream` methods.
+    /// `None` means unlimited.
+    pub stream_idle_timeout: Option<Duration>,
     /// Keepalive interval for long-running connections.
     pub keepalive_interval: Option<Duration>,
     /// Keepalive timeout.
     pub keepalive_timeout: Option<Duration>,
+    /// Set `TCP_NODELAY` on connections, disabling Nagle's algorithm so
+    /// small requests are sent immediately (defaults to `true`).
+    pub tcp_nodelay: bool,
+    /// Minimum TLS protocol version (defaults to TLS 1.2).
+    pub tls_min_version: Option<TlsVersion>,
+    /// Maximum TLS protocol version (defaults to TLS 1.3).
+    pub tls_max_version: Option<TlsVersion>,
+    /// Maximum bytes a buffered streaming call (`read`, `copy`, `logs`, ...)
+    /// may collect into memory. `None` means unlimited.
+    pub max_buffer_bytes: Option<usize>,
+    /// Wire transport (defaults to native gRPC over HTTP/2).
+    pub transport: Transport,
+    /// Speak HTTP/2 without negotiation on plaintext (`http://`) endpoints.
+    ///
+    /// Only matters for [`Transport::GrpcWeb`], which defaults to HTTP/1.1;
+    /// set it for gRPC-web behind an h2c proxy. [`Transport::Native`]
+    /// always uses prior knowledge on plaintext endpoints, so `Some(true)`
+    /// is a no-op for it and `Some(false)` is ignored with a warning. See
+    /// [`uses_http2_prior_knowledge`](Self::uses_http2_prior_knowledge).
+    pub http2_prior_knowledge: Option<bool>,
+    /// Retry policy for unary calls without a per-method override.
+    ///
+    /// `None` uses [`RetryConfig::default`]. Destructive methods in
+    /// [`NON_RETRYABLE_METHODS`] are still sent once unless overridden in
+    /// [`method_retry`](Self::method_retry). Streaming calls (logs, dmesg,
+    /// events, file reads, snapshots, ...) are never retried, since a retry
+    /// could replay output the caller has already consumed.
+    pub retry: Option<RetryConfig>,
+    /// Per-method retry overrides, keyed by gRPC method name (e.g. `"Memory"`).
+    ///
+    /// Methods without an entry use [`retry`](Self::retry), except the
+    /// destructive ones listed in [`NON_RETRYABLE_METHODS`], which are never
+    /// retried unless explicitly configured here.
+    pub method_retry: HashMap<String, RetryConfig>,
+    /// Collector recording every call made by the client, labelled with the
+    /// gRPC method and [`endpoint`](Self::endpoint). See [`MetricsInterceptor`].
+    pub metrics: Option<Arc<MetricsCollector>>,
 }
 
+/// gRPC methods that are not retried by default because they are destructive
+/// or not idempotent.
+pub const NON_RETRYABLE_METHODS: &[&str] = &[
+    "ApplyConfiguration",
+    "Bootstrap",
+    "EtcdAlarmDisarm",
+    "EtcdDefragment",
+    "EtcdForfeitLeadership",
+    "EtcdLeaveCluster",
+    "EtcdRecover",
+    "EtcdRemoveMemberByID",
+    "ImagePull",
+    "MetaDelete",
+    "MetaWrite",
+    "Reboot",
+    "Reset",
+    "Rollback",
+    "ServiceRestart",
+    "ServiceStart",
+    "ServiceStop",
+    "Shutdown",
+    "Upgrade",
+];
+
 impl Default for TalosClientConfig {
     fn default() -> Self {
         Self {
@@ -90,11 +302,26 @@ impl Default for TalosClientConfig {
             crt_path: None,
             key_path: None,
             ca_path: None,
+            client_p12: None,
+            ca_pem: None,
+            client_pem: None,
             insecure: false,
+            pinned_server_certs: Vec::new(),
             connect_timeout: Some(Duration::from_secs(10)),
             request_timeout: Some(Duration::from_secs(30)),
+            stream_timeout: None,
+            stream_idle_timeout: None,
             keepalive_interval: Some(Duration::from_secs(30)),
             keepalive_timeout: Some(Duration::from_secs(10)),
+            tcp_nodelay: true,
+            tls_min_version: None,
+            tls_max_version: None,
+            max_buffer_bytes: None,
+            transport: Transport::Native,
+            http2_prior_knowledge: None,
+            retry: None,
+            method_retry: HashMap::new(),
+            metrics: None,
         }
     }
 }
@@ -115,6 +342,18 @@ impl TalosClientConfig {
         TalosClientConfigBuilder::new(endpoint)
     }
 
+    /// Copy this configuration for a different endpoint.
+    ///
+    /// Every other setting (TLS material, timeouts, retry policies, ...) is
+    /// kept, so per-endpoint configs cannot drift from the base.
+    #[must_use]
+    pub fn with_endpoint(&self, endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            ..self.clone()
+        }
+    }
+
     /// Set client certificate path.
     #[must_use]
     pub fn with_client_cert(mut self, crt_path: impl Into<String>) -> Self {
@@ -136,6 +375,61 @@ impl TalosClientConfig {
         self
     }
 
+    /// Load the client certificate and key from a PKCS#12 (`.p12`/`.pfx`) bundle.
+    ///
+    /// Use an empty password for unprotected bundles. Requires the `pkcs12` feature.
+    #[must_use]
+    pub fn with_client_identity_p12(
+        mut self,
+        path: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.client_p12 = Some(Pkcs12Identity::new(path, password));
+        self
+    }
+
+    /// Set the CA certificate from PEM in memory instead of a file.
+    #[must_use]
+    pub fn with_ca_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.ca_pem = Some(pem.into());
+        self
+    }
+
+    /// Set the client certificate and key from PEM in memory instead of files.
+    #[must_use]
+    pub fn with_client_pem(mut self, crt: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        self.client_pem = Some(PemIdentity::new(crt, key));
+        self
+    }
+
+    /// Build a configuration from credentials returned by
+    /// [`TalosClient::generate_client_configuration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the CA, certificate or key is empty.
+    #[allow(clippy::result_large_err)]
+    pub fn from_generated(
+        endpoint: impl Into<String>,
+        generated: &GenerateClientConfigurationResult,
+    ) -> Result<Self> {
+        for (name, pem) in [
+            ("CA", &generated.ca),
+            ("certificate", &generated.crt),
+            ("key", &generated.key),
+        ] {
+            if pem.is_empty() {
+                return Err(crate::error::TalosError::Validation(format!(
+                    "Generated client configuration has an empty {name}"
+                )));
+            }
+        }
+
+        Ok(Self::new(endpoint)
+            .with_ca_pem(generated.ca.clone())
+            .with_client_pem(generated.crt.clone(), generated.key.clone()))
+    }
+
     /// Enable insecure mode (skip TLS verification).
     #[must_use]
     pub fn insecure(mut self) -> Self {
@@ -143,6 +437,16 @@ impl TalosClientConfig {
         self
     }
 
+    /// Pin a server certificate by its SHA-256 fingerprint.
+    ///
+    /// May be called repeatedly to accept several certificates, e.g. during
+    /// rotation. See [`parse_fingerprint`] for the `openssl` hex form.
+    #[must_use]
+    pub fn with_pinned_server_cert(mut self, fingerprint: [u8; 32]) -> Self {
+        self.pinned_server_certs.push(fingerprint);
+        self
+    }
+
     /// Set connect timeout.
     #[must_use]
     pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
@@ -157,13 +461,197 @@ impl TalosClientConfig {
         self
     }
 
+    /// Set the overall deadline for server-streaming responses.
+    #[must_use]
+    pub fn with_stream_timeout(mut self, timeout: Duration) -> Self {
+        self.stream_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum gap between messages of server-streaming responses.
+    #[must_use]
+    pub fn with_stream_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.stream_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Enable or disable `TCP_NODELAY` (enabled by default).
+    #[must_use]
+    pub fn with_tcp_nodelay(mut self, enabled: bool) -> Self {
+        self.tcp_nodelay = enabled;
+        self
+    }
+
+    /// Use (or refuse) HTTP/2 prior knowledge on plaintext gRPC-web endpoints.
+    ///
+    /// Native gRPC always uses it; see [`http2_prior_knowledge`](Self::http2_prior_knowledge).
+    #[must_use]
+    pub fn with_http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = Some(enabled);
+        self
+    }
+
+    /// Whether plaintext connections speak HTTP/2 without negotiation.
+    ///
+    /// Always true for [`Transport::Native`]. Over TLS, HTTP/2 is
+    /// negotiated via ALPN instead and this is ignored.
+    #[must_use]
+    pub fn uses_http2_prior_knowledge(&self) -> bool {
+        match self.transport {
+            Transport::Native => true,
+            Transport::GrpcWeb => self.http2_prior_knowledge.unwrap_or(false),
+        }
+    }
+
     /// Disable all timeouts.
     #[must_use]
     pub fn no_timeout(mut self) -> Self {
         self.connect_timeout = None;
         self.request_timeout = None;
+        self.stream_timeout = None;
+        self.stream_idle_timeout = None;
+        self
+    }
+
+    /// Apply debugging overrides from the environment.
+    ///
+    /// | Variable | Effect |
+    /// |----------|--------|
+    /// | `TALOS_INSECURE` | `1`/`true`/`yes`/`on` skips TLS verification, `0`/`false`/`no`/`off` re-enables it |
+    /// | `TALOS_CONNECT_TIMEOUT` | connect timeout |
+    /// | `TALOS_REQUEST_TIMEOUT` | request timeout |
+    /// | `TALOS_STREAM_IDLE_TIMEOUT` | stream idle timeout |
+    ///
+    /// Timeouts take a number with an optional `ms`, `s`, `m` or `h` suffix
+    /// (seconds by default), or `off`/`none` to disable the timeout. Unset
+    /// or empty variables leave the config unchanged. Enabling insecure mode
+    /// this way logs a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` naming the variable if a value cannot be
+    /// parsed; the config is left unchanged in that case.
+    #[allow(clippy::result_large_err)]
+    pub fn apply_env_overrides(&mut self) -> crate::error::Result<()> {
+        use crate::config::{
+            ENV_TALOS_CONNECT_TIMEOUT, ENV_TALOS_INSECURE, ENV_TALOS_REQUEST_TIMEOUT,
+            ENV_TALOS_STREAM_IDLE_TIMEOUT,
+        };
+
+        let insecure = env_override(ENV_TALOS_INSECURE, parse_env_bool)?;
+        let connect_timeout = env_override(ENV_TALOS_CONNECT_TIMEOUT, parse_env_timeout)?;
+        let request_timeout = env_override(ENV_TALOS_REQUEST_TIMEOUT, parse_env_timeout)?;
+        let stream_idle_timeout = env_override(ENV_TALOS_STREAM_IDLE_TIMEOUT, parse_env_timeout)?;
+
+        if let Some(insecure) = insecure {
+            if insecure && !self.insecure {
+                tracing::warn!(
+                    "{} is set: TLS certificate verification is DISABLED for {}",
+                    ENV_TALOS_INSECURE,
+                    self.endpoint
+                );
+            }
+            self.insecure = insecure;
+        }
+        if let Some(timeout) = connect_timeout {
+            self.connect_timeout = timeout;
+        }
+        if let Some(timeout) = request_timeout {
+            self.request_timeout = timeout;
+        }
+        if let Some(timeout) = stream_idle_timeout {
+            self.stream_idle_timeout = timeout;
+        }
+        Ok(())
+    }
+
+    /// Set the minimum TLS protocol version.
+    #[must_use]
+    pub fn with_tls_min_version(mut self, version: TlsVersion) -> Self {
+        self.tls_min_version = Some(version);
+        self
+    }
+
+    /// Set the maximum TLS protocol version.
+    #[must_use]
+    pub fn with_tls_max_version(mut self, version: TlsVersion) -> Self {
+        self.tls_max_version = Some(version);
+        self
+    }
+
+    /// Cap the bytes buffered by collecting streaming calls.
+    #[must_use]
+    pub fn with_max_buffer_bytes(mut self, max: usize) -> Self {
+        self.max_buffer_bytes = Some(max);
+        self
+    }
+
+    /// Set the wire transport.
+    #[must_use]
+    pub fn with_transport(mut self, transport: Transport) -> Self {
+        self.transport = transport;
         self
     }
+
+    /// Set the retry policy for unary calls without a per-method override.
+    #[must_use]
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
+    /// Set the retry configuration for a gRPC method (e.g. `"Memory"`).
+    #[must_use]
+    pub fn with_retry_for(mut self, method: impl Into<String>, config: RetryConfig) -> Self {
+        self.method_retry.insert(method.into(), config);
+        self
+    }
+
+    /// Record every call made by the client into `metrics`.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Get the retry configuration used for a gRPC method.
+    ///
+    /// Explicit overrides win; otherwise destructive methods get no retries
+    /// and everything else uses [`retry`](Self::retry), falling back to
+    /// [`RetryConfig::default`].
+    #[must_use]
+    pub fn retry_config_for(&self, method: &str) -> RetryConfig {
+        if let Some(config) = self.method_retry.get(method) {
+            config.clone()
+        } else if NON_RETRYABLE_METHODS.contains(&method) {
+            RetryConfig::builder().max_retries(0).build()
+        } else {
+            self.retry.clone().unwrap_or_default()
+        }
+    }
+
+    /// Resolve the TLS protocol versions allowed by `tls_min_version`/`tls_max_version`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` if the minimum is greater than the maximum.
+    #[allow(clippy::result_large_err)]
+    pub fn tls_protocol_versions(&self) -> Result<Vec<&'static rustls::SupportedProtocolVersion>> {
+        let min = self.tls_min_version.unwrap_or(TlsVersion::Tls12);
+        let max = self.tls_max_version.unwrap_or(TlsVersion::Tls13);
+        if min > max {
+            return Err(crate::error::TalosError::Config(format!(
+                "Invalid TLS version range: minimum {} is above maximum {}",
+                min, max
+            )));
+        }
+
+        Ok([TlsVersion::Tls12, TlsVersion::Tls13]
+            .into_iter()
+            .filter(|v| (min..=max).contains(v))
+            .map(TlsVersion::as_rustls)
+            .collect())
+    }
 }
 
 /// Builder for `TalosClientConfig`.
@@ -173,11 +661,26 @@ pub struct TalosClientConfigBuilder {
     crt_path: Option<String>,
     key_path: Option<String>,
     ca_path: Option<String>,
+    client_p12: Option<Pkcs12Identity>,
+    ca_pem: Option<Vec<u8>>,
+    client_pem: Option<PemIdentity>,
     insecure: bool,
+    pinned_server_certs: Vec<[u8; 32]>,
     connect_timeout: Option<Duration>,
     request_timeout: Option<Duration>,
+    stream_timeout: Option<Duration>,
+    stream_idle_timeout: Option<Duration>,
     keepalive_interval: Option<Duration>,
     keepalive_timeout: Option<Duration>,
+    tcp_nodelay: bool,
+    tls_min_version: Option<TlsVersion>,
+    tls_max_version: Option<TlsVersion>,
+    max_buffer_bytes: Option<usize>,
+    transport: Transport,
+    http2_prior_knowledge: Option<bool>,
+    retry: Option<RetryConfig>,
+    method_retry: HashMap<String, RetryConfig>,
+    metrics: Option<Arc<MetricsCollector>>,
 }
 
 impl TalosClientConfigBuilder {
@@ -189,11 +692,26 @@ impl TalosClientConfigBuilder {
             crt_path: None,
             key_path: None,
             ca_path: None,
+            client_p12: None,
+            ca_pem: None,
+            client_pem: None,
             insecure: false,
+            pinned_server_certs: Vec::new(),
             connect_timeout: Some(Duration::from_secs(10)),
             request_timeout: Some(Duration::from_secs(30)),
+            stream_timeout: None,
+            stream_idle_timeout: None,
             keepalive_interval: Some(Duration::from_secs(30)),
             keepalive_timeout: Some(Duration::from_secs(10)),
+            tcp_nodelay: true,
+            tls_min_version: None,
+            tls_max_version: None,
+            max_buffer_bytes: None,
+            transport: Transport::Native,
+            http2_prior_knowledge: None,
+            retry: None,
+            method_retry: HashMap::new(),
+            metrics: None,
         }
     }
 
@@ -218,6 +736,38 @@ impl TalosClientConfigBuilder {
         self
     }
 
+    /// Load the client certificate and key from a PKCS#12 (`.p12`/`.pfx`) bundle.
+    ///
+    /// Takes precedence over [`client_cert`](Self::client_cert) and
+    /// [`client_key`](Self::client_key). Use an empty password for unprotected
+    /// bundles. Requires the `pkcs12` feature.
+    #[must_use]
+    pub fn client_identity_p12(
+        mut self,
+        path: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.client_p12 = Some(Pkcs12Identity::new(path, password));
+        self
+    }
+
+    /// Set the CA certificate from PEM in memory instead of a file.
+    #[must_use]
+    pub fn ca_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.ca_pem = Some(pem.into());
+        self
+    }
+
+    /// Set the client certificate and key from PEM in memory instead of files.
+    ///
+    /// Takes precedence over [`client_cert`](Self::client_cert) and
+    /// [`client_key`](Self::client_key).
+    #[must_use]
+    pub fn client_pem(mut self, crt: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        self.client_pem = Some(PemIdentity::new(crt, key));
+        self
+    }
+
     /// Enable insecure mode.
     #[must_use]
     pub fn insecure(mut self) -> Self {
@@ -225,6 +775,16 @@ impl TalosClientConfigBuilder {
         self
     }
 
+    /// Pin a server certificate by its SHA-256 fingerprint.
+    ///
+    /// Connections fail unless the server presents a pinned certificate. In
+    /// insecure mode the pin replaces CA verification; otherwise both apply.
+    #[must_use]
+    pub fn pinned_server_cert(mut self, fingerprint: [u8; 32]) -> Self {
+        self.pinned_server_certs.push(fingerprint);
+        self
+    }
+
     /// Set connect timeout.
     #[must_use]
     pub fn connect_timeout(mut self, timeout: Duration) -> Self {
@@ -239,6 +799,23 @@ impl TalosClientConfigBuilder {
         self
     }
 
+    /// Set the overall deadline for server-streaming responses.
+    #[must_use]
+    pub fn stream_timeout(mut self, timeout: Duration) -> Self {
+        self.stream_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum gap between messages of server-streaming responses.
+    ///
+    /// A stalled stream then fails with `TalosError::Timeout` instead of
+    /// hanging until the connection drops.
+    #[must_use]
+    pub fn stream_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.stream_idle_timeout = Some(timeout);
+        self
+    }
+
     /// Set keepalive settings.
     #[must_use]
     pub fn keepalive(mut self, interval: Duration, timeout: Duration) -> Self {
@@ -247,11 +824,92 @@ impl TalosClientConfigBuilder {
         self
     }
 
+    /// Enable or disable `TCP_NODELAY` (enabled by default).
+    #[must_use]
+    pub fn tcp_nodelay(mut self, enabled: bool) -> Self {
+        self.tcp_nodelay = enabled;
+        self
+    }
+
     /// Disable timeouts.
     #[must_use]
     pub fn no_timeout(mut self) -> Self {
         self.connect_timeout = None;
         self.request_timeout = None;
+        self.stream_timeout = None;
+        self.stream_idle_timeout = None;
+        self
+    }
+
+    /// Set the minimum TLS protocol version.
+    ///
+    /// Use `TlsVersion::Tls13` to enforce TLS 1.3-only connections.
+    #[must_use]
+    pub fn tls_min_version(mut self, version: TlsVersion) -> Self {
+        self.tls_min_version = Some(version);
+        self
+    }
+
+    /// Set the maximum TLS protocol version.
+    #[must_use]
+    pub fn tls_max_version(mut self, version: TlsVersion) -> Self {
+        self.tls_max_version = Some(version);
+        self
+    }
+
+    /// Cap the bytes buffered by collecting streaming calls.
+    ///
+    /// Calls that would exceed the cap fail with `TalosError::Validation`
+    /// instead of growing memory without bound.
+    #[must_use]
+    pub fn max_buffer_bytes(mut self, max: usize) -> Self {
+        self.max_buffer_bytes = Some(max);
+        self
+    }
+
+    /// Set the wire transport.
+    ///
+    /// [`Transport::GrpcWeb`] routes calls over gRPC-web (HTTP/1.1) and
+    /// requires the `grpc-web` feature.
+    #[must_use]
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Use (or refuse) HTTP/2 prior knowledge on plaintext gRPC-web endpoints.
+    ///
+    /// Native gRPC always uses it; see [`http2_prior_knowledge`](Self::http2_prior_knowledge).
+    ///
+    /// See [`TalosClientConfig::http2_prior_knowledge`].
+    #[must_use]
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = Some(enabled);
+        self
+    }
+
+    /// Set the retry policy for unary calls without a per-method override.
+    ///
+    /// Streaming calls are not retried.
+    #[must_use]
+    pub fn retry(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
+    /// Set the retry configuration for a gRPC method (e.g. `"Memory"`).
+    ///
+    /// Overrides the default, including for methods in [`NON_RETRYABLE_METHODS`].
+    #[must_use]
+    pub fn retry_for(mut self, method: impl Into<String>, config: RetryConfig) -> Self {
+        self.method_retry.insert(method.into(), config);
+        self
+    }
+
+    /// Record every call made by the client into `metrics`.
+    #[must_use]
+    pub fn metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
         self
     }
 
@@ -263,11 +921,26 @@ impl TalosClientConfigBuilder {
             crt_path: self.crt_path,
             key_path: self.key_path,
             ca_path: self.ca_path,
+            client_p12: self.client_p12,
+            ca_pem: self.ca_pem,
+            client_pem: self.client_pem,
             insecure: self.insecure,
+            pinned_server_certs: self.pinned_server_certs,
             connect_timeout: self.connect_timeout,
             request_timeout: self.request_timeout,
+            stream_timeout: self.stream_timeout,
+            stream_idle_timeout: self.stream_idle_timeout,
             keepalive_interval: self.keepalive_interval,
             keepalive_timeout: self.keepalive_timeout,
+            tcp_nodelay: self.tcp_nodelay,
+            tls_min_version: self.tls_min_version,
+            tls_max_version: self.tls_max_version,
+            max_buffer_bytes: self.max_buffer_bytes,
+            transport: self.transport,
+            http2_prior_knowledge: self.http2_prior_knowledge,
+            retry: self.retry,
+            method_retry: self.method_retry,
+            metrics: self.metrics,
         }
     }
 }
@@ -277,26 +950,76 @@ pub struct TalosClient {
     /// Client configuration (retained for debugging and introspection)
     #[allow(dead_code)]
     config: TalosClientConfig,
-    channel: Channel,
+    channel: TalosChannel,
     /// Current node target for API calls
     node_target: NodeTarget,
 }
 
 impl TalosClient {
     pub async fn new(config: TalosClientConfig) -> Result<Self> {
+        Self::connect(config, None).await
+    }
+
+    /// Connect like [`new`](Self::new), reusing `tls_config` from
+    /// [`native_tls_config`](Self::native_tls_config) instead of building
+    /// one from `config`.
+    ///
+    /// Lets callers that connect to many endpoints with the same
+    /// credentials, such as cluster discovery, load them once.
+    pub(crate) async fn new_with_tls_config(
+        config: TalosClientConfig,
+        tls_config: Arc<rustls::ClientConfig>,
+    ) -> Result<Self> {
+        Self::connect(config, Some(tls_config)).await
+    }
+
+    async fn connect(
+        config: TalosClientConfig,
+        tls_config: Option<Arc<rustls::ClientConfig>>,
+    ) -> Result<Self> {
         // Install ring as default crypto provider (supports ED25519)
         let _ = rustls::crypto::ring::default_provider().install_default();
 
         // Check if using plain HTTP (no TLS)
         let is_http = config.endpoint.starts_with("http://");
+        if is_http && !config.pinned_server_certs.is_empty() {
+            return Err(crate::error::TalosError::Config(
+                "Certificate pinning requires a TLS (https://) endpoint".to_string(),
+            ));
+        }
+        if config.transport == Transport::Native && config.http2_prior_knowledge == Some(false) {
+            tracing::warn!(
+                "Ignoring http2_prior_knowledge = false for {}: native gRPC always uses \
+                 HTTP/2 prior knowledge on plaintext endpoints",
+                config.endpoint
+            );
+        }
 
-        let channel = if is_http {
-            // Plain HTTP - no TLS at all
-            Self::create_http_channel(&config).await?
-        } else if config.insecure {
-            Self::create_insecure_channel(&config).await?
-        } else {
-            Self::create_mtls_channel(&config).await?
+        let channel: TalosChannel = match config.transport {
+            Transport::Native if is_http => {
+                // Plain HTTP - no TLS at all
+                Self::create_http_channel(&config).await?.into()
+            }
+            Transport::Native => {
+                let tls_config = match tls_config {
+                    Some(tls_config) => tls_config,
+                    None => Self::native_tls_config(&config)?,
+                };
+                Self::connect_with_custom_tls(&config, tls_config)
+                    .await?
+                    .into()
+            }
+            Transport::GrpcWeb => Self::create_grpc_web_channel(&config, is_http)?,
+        };
+        // Applied per call by the channel, so clients built with
+        // `with_call_timeout` can exceed it.
+        let channel = channel.with_request_timeout(config.request_timeout);
+        let channel = match &config.metrics {
+            Some(metrics) => channel.with_metrics(MetricsInterceptor::new(
+                metrics.clone(),
+                config.endpoint.clone(),
+            )),
+            None => channel,
         };
 
         Ok(Self {
@@ -306,27 +1029,78 @@ impl TalosClient {
         })
     }
 
-    /// Create a client from a TalosConfig context
+    /// Connect to the first reachable endpoint in `endpoints`
     ///
-    /// This loads credentials from the talosconfig and connects to the first endpoint.
+    /// Endpoints are tried in order, like talosctl's endpoint list, with
+    /// `config` supplying everything but the endpoint itself. The chosen
+    /// endpoint is available via [`endpoint`](Self::endpoint); call
+    /// `new_multi` again to re-select after losing the connection. For
+    /// continuous health tracking across endpoints, use [`ConnectionPool`].
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use talos_api_rs::{TalosClient, config::TalosConfig};
+    /// ```ignore
+    /// use talos_api_rs::{TalosClient, TalosClientConfig};
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let config = TalosConfig::load_with_env()?;
-    /// let client = TalosClient::from_talosconfig(&config, None).await?;
-    /// # Ok(())
-    /// # }
+    /// let client = TalosClient::new_multi(
+    ///     vec![
+    ///         "https://10.0.0.2:50000".to_string(),
+    ///         "https://10.0.0.3:50000".to_string(),
+    ///     ],
+    ///     TalosClientConfig::default(),
+    /// )
+    /// .await?;
+    /// println!("connected to {}", client.endpoint());
     /// ```
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `config` - The loaded TalosConfig
-    /// * `context_name` - Optional context name to use (defaults to active context)
-    pub async fn from_talosconfig(
+    /// Returns `TalosError::Config` if `endpoints` is empty, otherwise the
+    /// error from the last endpoint if none could be reached.
+    pub async fn new_multi(endpoints: Vec<String>, config: TalosClientConfig) -> Result<Self> {
+        let mut last_error = None;
+
+        for endpoint in endpoints {
+            let result = match Self::new(config.with_endpoint(&endpoint)).await {
+                // gRPC-web connects lazily, so confirm the endpoint answers.
+                Ok(client) if client.config.transport == Transport::GrpcWeb => {
+                    client.probe().await.map(|()| client)
+                }
+                other => other,
+            };
+            match result {
+                Ok(client) => return Ok(client),
+                Err(e) => last_error = Some(e.at_endpoint(&endpoint)),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            crate::error::TalosError::Config("At least one endpoint is required".to_string())
+        }))
+    }
+
+    /// Create a client from a TalosConfig context
+    ///
+    /// This loads credentials from the talosconfig and connects as described
+    /// in [`from_context`](Self::from_context).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use talos_api_rs::{TalosClient, config::TalosConfig};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = TalosConfig::load_with_env()?;
+    /// let client = TalosClient::from_talosconfig(&config, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The loaded TalosConfig
+    /// * `context_name` - Optional context name to use (defaults to active context)
+    pub async fn from_talosconfig(
         config: &crate::config::TalosConfig,
         context_name: Option<&str>,
     ) -> Result<Self> {
@@ -340,59 +1114,103 @@ impl TalosClient {
             })?
         };
 
-        let endpoint = context.first_endpoint().ok_or_else(|| {
-            crate::error::TalosError::Config("No endpoints in context".to_string())
-        })?;
-
-        // Build endpoint URL
-        let endpoint_url = if endpoint.contains("://") {
-            endpoint.clone()
-        } else if endpoint.contains(':') {
-            format!("https://{}", endpoint)
-        } else {
-            format!("https://{}:50000", endpoint)
-        };
-
-        // Build client config
-        let mut client_config = TalosClientConfig::new(&endpoint_url);
-
-        // Write certs to temp files if provided inline
-        if let (Some(ca), Some(crt), Some(key)) = (&context.ca, &context.crt, &context.key) {
-            let temp_dir = std::env::temp_dir().join("talos-api-rs");
-            std::fs::create_dir_all(&temp_dir).map_err(|e| {
-                crate::error::TalosError::Config(format!("Failed to create temp dir: {}", e))
-            })?;
+        Self::from_context(context).await
+    }
 
-            let ca_path = temp_dir.join("ca.crt");
-            let crt_path = temp_dir.join("client.crt");
-            let key_path = temp_dir.join("client.key");
+    /// Create a client from a single talosconfig context
+    ///
+    /// Follows talosctl semantics: the client connects to the context's
+    /// `endpoints` (tried in order, see [`new_multi`](Self::new_multi)) and
+    /// proxies every request to its `nodes` via [`NodeTarget`]. Without
+    /// `nodes`, requests are answered by the endpoint itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` if the context has no endpoints, or the
+    /// error from the last endpoint if none could be reached.
+    pub async fn from_context(context: &crate::config::TalosContext) -> Result<Self> {
+        Self::connect_context(context, context.to_client_config()?).await
+    }
 
-            std::fs::write(&ca_path, ca).map_err(|e| {
-                crate::error::TalosError::Config(format!("Failed to write CA cert: {}", e))
-            })?;
-            std::fs::write(&crt_path, crt).map_err(|e| {
-                crate::error::TalosError::Config(format!("Failed to write client cert: {}", e))
-            })?;
-            std::fs::write(&key_path, key).map_err(|e| {
-                crate::error::TalosError::Config(format!("Failed to write client key: {}", e))
-            })?;
+    /// Connect to a context's endpoints with `client_config` and target its nodes.
+    async fn connect_context(
+        context: &crate::config::TalosContext,
+        client_config: TalosClientConfig,
+    ) -> Result<Self> {
+        let client = Self::new_multi(context.endpoint_urls(), client_config).await?;
+        Ok(client.with_node(context.node_target()))
+    }
 
-            client_config = client_config
-                .with_ca(ca_path.to_string_lossy().to_string())
-                .with_client_cert(crt_path.to_string_lossy().to_string())
-                .with_client_key(key_path.to_string_lossy().to_string());
+    /// Create a client from the environment
+    ///
+    /// Loads the talosconfig with [`TalosConfig::load_with_env`](crate::config::TalosConfig::load_with_env)
+    /// (honouring `TALOSCONFIG`, `TALOS_CONTEXT`, `TALOS_ENDPOINTS` and `TALOS_NODES`)
+    /// and connects to the active context's endpoints (tried in order, see
+    /// [`new_multi`](Self::new_multi)). Nodes from `TALOS_NODES` become the
+    /// client's [`NodeTarget`], so requests are proxied through the endpoint
+    /// to those nodes.
+    ///
+    /// The client config also picks up the debugging overrides described in
+    /// [`TalosClientConfig::apply_env_overrides`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` if `TALOS_NODES` is set but lists no
+    /// nodes, if an override variable is malformed, or if the talosconfig
+    /// cannot be loaded or has no usable context.
+    pub async fn from_env() -> Result<Self> {
+        if let Ok(nodes) = std::env::var(crate::config::ENV_TALOS_NODES) {
+            if NodeTarget::from_csv(&nodes).is_default() {
+                return Err(crate::error::TalosError::Config(format!(
+                    "{} is set but contains no nodes",
+                    crate::config::ENV_TALOS_NODES
+                )));
+            }
         }
 
-        let mut client = Self::new(client_config).await?;
+        let config = crate::config::TalosConfig::load_with_env()?;
+        let context = config.active_context().ok_or_else(|| {
+            crate::error::TalosError::Config("No active context in talosconfig".to_string())
+        })?;
 
-        // Set node target from context if available
-        if let Some(nodes) = &context.nodes {
-            if !nodes.is_empty() {
-                client.node_target = NodeTarget::from(nodes.clone());
-            }
-        }
+        let mut client_config = context.to_client_config()?;
+        client_config.apply_env_overrides()?;
+        Self::connect_context(context, client_config).await
+    }
 
-        Ok(client)
+    /// Connect to `endpoint` with credentials returned by
+    /// [`generate_client_configuration`](Self::generate_client_configuration)
+    ///
+    /// The PEM blobs are used in memory; see
+    /// [`TalosClientConfig::from_generated`] to adjust the configuration first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use talos_api_rs::resources::GenerateClientConfigurationRequest;
+    /// use talos_api_rs::TalosClient;
+    ///
+    /// # async fn example(admin: TalosClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let generated = admin
+    ///     .generate_client_configuration(GenerateClientConfigurationRequest::with_roles(vec![
+    ///         "os:reader".to_string(),
+    ///     ]))
+    ///     .await?;
+    /// let reader =
+    ///     TalosClient::from_generated("https://10.0.0.2:50000", generated.first().unwrap()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the CA, certificate or key is
+    /// empty, or the connection error if the endpoint cannot be reached.
+    pub async fn from_generated(
+        endpoint: impl Into<String>,
+        generated: &GenerateClientConfigurationResult,
+    ) -> Result<Self> {
+        Self::new(TalosClientConfig::from_generated(endpoint, generated)?).await
     }
 
     /// Create a new client targeting a specific node
@@ -412,21 +1230,86 @@ impl TalosClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// The target applies to every request sent by the new client, including
+    /// those of the raw clients returned by [`machine`](Self::machine) and
+    /// [`version`](Self::version). The endpoint proxies them to the target
+    /// nodes, and each node's answer comes back as a separate entry in the
+    /// response's `results`.
     #[must_use]
     pub fn with_node(&self, target: NodeTarget) -> Self {
         Self {
             config: self.config.clone(),
-            channel: self.channel.clone(),
+            channel: self.channel.with_node_target(&target),
             node_target: target,
         }
     }
 
+    /// Create a new client with a different retry policy for unary calls.
+    ///
+    /// Shares the connection with `self`; see [`TalosClientConfig::retry`].
+    #[must_use]
+    pub fn with_retry(&self, retry: RetryConfig) -> Self {
+        Self {
+            config: self.config.clone().with_retry(retry),
+            channel: self.channel.clone(),
+            node_target: self.node_target.clone(),
+        }
+    }
+
+    /// Create a new client whose calls have a deadline of `timeout`.
+    ///
+    /// Replaces [`TalosClientConfig::request_timeout`] for this client only,
+    /// in either direction: give slow operations such as etcd defragment or
+    /// upgrade more time, or fail fast on a health probe. Shares the
+    /// connection with `self`.
+    ///
+    /// The deadline is sent as `grpc-timeout` metadata, so the node stops
+    /// working on the call once it passes; for streaming calls it bounds
+    /// the whole stream, not just the wait for the first response. A call
+    /// that misses it fails with `DeadlineExceeded`. Unary calls are not
+    /// retried on this client, so the deadline bounds the call as a whole.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let report = client
+    ///     .with_call_timeout(Duration::from_secs(600))
+    ///     .etcd_defragment()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn with_call_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            config: self.config.clone(),
+            channel: self.channel.with_call_timeout(timeout),
+            node_target: self.node_target.clone(),
+        }
+    }
+
     /// Create a new client targeting multiple nodes
     ///
-    /// Convenience method for cluster-wide operations.
+    /// Convenience method for cluster-wide operations. An empty list targets
+    /// the connected endpoint, like a client without node targeting.
+    #[must_use]
+    pub fn with_nodes(&self, nodes: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let nodes: Vec<String> = nodes
+            .into_iter()
+            .map(|node| node.as_ref().to_string())
+            .collect();
+        self.with_node(NodeTarget::from(nodes))
+    }
+
+    /// Create a new client targeting the nodes a talosconfig resolves to
+    ///
+    /// Uses [`TalosConfig::node_target`](crate::config::TalosConfig::node_target):
+    /// `TALOS_NODES` first, then the active context's `nodes`, otherwise the
+    /// connected endpoint.
     #[must_use]
-    pub fn with_nodes(&self, nodes: impl IntoIterator<Item = impl Into<String>>) -> Self {
-        self.with_node(NodeTarget::multiple(nodes))
+    pub fn with_config_nodes(&self, config: &crate::config::TalosConfig) -> Self {
+        self.with_node(config.node_target())
     }
 
     /// Get the current node target
@@ -435,6 +1318,12 @@ impl TalosClient {
         &self.node_target
     }
 
+    /// Get the endpoint this client is connected to
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.config.endpoint
+    }
+
     /// Create a plain HTTP channel (no TLS)
     async fn create_http_channel(config: &TalosClientConfig) -> Result<Channel> {
         let mut endpoint = Channel::from_shared(config.endpoint.clone())
@@ -444,9 +1333,6 @@ impl TalosClient {
         if let Some(timeout) = config.connect_timeout {
             endpoint = endpoint.connect_timeout(timeout);
         }
-        if let Some(timeout) = config.request_timeout {
-            endpoint = endpoint.timeout(timeout);
-        }
         if let Some(interval) = config.keepalive_interval {
             if let Some(ka_timeout) = config.keepalive_timeout {
                 endpoint = endpoint
@@ -454,27 +1340,102 @@ impl TalosClient {
                     .keep_alive_timeout(ka_timeout);
             }
         }
+        endpoint = endpoint.tcp_nodelay(config.tcp_nodelay);
 
         let channel = endpoint.connect().await?;
         Ok(channel)
     }
 
-    /// Create an insecure channel (TLS without certificate verification)
-    async fn create_insecure_channel(config: &TalosClientConfig) -> Result<Channel> {
-        let tls_config = rustls::ClientConfig::builder()
-            .with_root_certificates(rustls::RootCertStore::empty())
-            .with_no_client_auth();
+    /// TLS configuration for the native transport: full certificate
+    /// verification, or none in insecure mode (pins still apply), with
+    /// ALPN `h2`.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn native_tls_config(
+        config: &TalosClientConfig,
+    ) -> Result<Arc<rustls::ClientConfig>> {
+        let mut tls_config = if config.insecure {
+            let mut tls_config = Self::insecure_tls_config(config)?;
+            tls_config
+                .dangerous()
+                .set_certificate_verifier(Self::insecure_verifier(config));
+            tls_config
+        } else {
+            Self::mtls_tls_config(config)?
+        };
+
+        // gRPC requires ALPN h2
+        tls_config.alpn_protocols = vec![b"h2".to_vec()];
+        Ok(Arc::new(tls_config))
+    }
+
+    /// Create a gRPC-web channel using the same TLS settings as the native transport
+    #[cfg(feature = "grpc-web")]
+    #[allow(clippy::result_large_err)]
+    fn create_grpc_web_channel(config: &TalosClientConfig, is_http: bool) -> Result<TalosChannel> {
+        let tls_config = if is_http {
+            None
+        } else if config.insecure {
+            let mut tls_config = Self::insecure_tls_config(config)?;
+            tls_config
+                .dangerous()
+                .set_certificate_verifier(Self::insecure_verifier(config));
+            Some(tls_config)
+        } else {
+            Some(Self::mtls_tls_config(config)?)
+        };
+
+        transport::connect_grpc_web(config, tls_config)
+    }
+
+    /// gRPC-web is unavailable without the `grpc-web` feature
+    #[cfg(not(feature = "grpc-web"))]
+    #[allow(clippy::result_large_err)]
+    fn create_grpc_web_channel(
+        _config: &TalosClientConfig,
+        _is_http: bool,
+    ) -> Result<TalosChannel> {
+        Err(crate::error::TalosError::Config(
+            "gRPC-web transport requires the `grpc-web` feature".to_string(),
+        ))
+    }
+
+    /// Verifier for insecure mode: checks pins if any are configured, otherwise nothing
+    fn insecure_verifier(
+        config: &TalosClientConfig,
+    ) -> Arc<dyn rustls::client::danger::ServerCertVerifier> {
+        if config.pinned_server_certs.is_empty() {
+            Arc::new(NoVerifier)
+        } else {
+            Arc::new(pinning::PinnedCertVerifier::new(
+                config.pinned_server_certs.clone(),
+                None,
+            ))
+        }
+    }
 
-        Self::connect_with_custom_tls(config, tls_config, true).await
+    /// TLS configuration that accepts any server certificate (verifier set by the caller)
+    #[allow(clippy::result_large_err)]
+    fn insecure_tls_config(config: &TalosClientConfig) -> Result<rustls::ClientConfig> {
+        let versions = config.tls_protocol_versions()?;
+        Ok(
+            rustls::ClientConfig::builder_with_protocol_versions(&versions)
+                .with_root_certificates(rustls::RootCertStore::empty())
+                .with_no_client_auth(),
+        )
     }
 
-    /// Create an mTLS channel with full certificate verification
-    async fn create_mtls_channel(config: &TalosClientConfig) -> Result<Channel> {
+    /// TLS configuration with CA verification and optional client certificate
+    #[allow(clippy::result_large_err)]
+    fn mtls_tls_config(config: &TalosClientConfig) -> Result<rustls::ClientConfig> {
         // Load CA certificate
-        let root_store = if let Some(ca_path) = &config.ca_path {
-            let ca_pem = std::fs::read(ca_path).map_err(|e| {
+        let ca_pem = match (&config.ca_pem, &config.ca_path) {
+            (Some(pem), _) => Some(pem.clone()),
+            (None, Some(ca_path)) => Some(std::fs::read(ca_path).map_err(|e| {
                 crate::error::TalosError::Config(format!("Failed to read CA cert: {e}"))
-            })?;
+            })?),
+            (None, None) => None,
+        };
+        let root_store = if let Some(ca_pem) = ca_pem {
             let mut root_store = rustls::RootCertStore::empty();
             let certs = Self::load_pem_certs(&ca_pem)?;
             for cert in certs {
@@ -491,53 +1452,125 @@ impl TalosClient {
         };
 
         // Build TLS config with or without client auth
-        let tls_config =
-            if let (Some(crt_path), Some(key_path)) = (&config.crt_path, &config.key_path) {
-                // mTLS with client certificate
-                let cert_pem = std::fs::read(crt_path).map_err(|e| {
-                    crate::error::TalosError::Config(format!("Failed to read client cert: {e}"))
-                })?;
-                let key_pem = std::fs::read(key_path).map_err(|e| {
-                    crate::error::TalosError::Config(format!("Failed to read client key: {e}"))
-                })?;
+        let versions = config.tls_protocol_versions()?;
+        let builder = rustls::ClientConfig::builder_with_protocol_versions(&versions);
+        let builder = if config.pinned_server_certs.is_empty() {
+            builder.with_root_certificates(root_store)
+        } else {
+            let ca = rustls::client::WebPkiServerVerifier::builder_with_provider(
+                Arc::new(root_store),
+                Arc::new(rustls::crypto::ring::default_provider()),
+            )
+            .build()
+            .map_err(|e| {
+                crate::error::TalosError::Config(format!("Failed to build CA verifier: {e}"))
+            })?;
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(pinning::PinnedCertVerifier::new(
+                    config.pinned_server_certs.clone(),
+                    Some(ca),
+                )))
+        };
+        let tls_config = match Self::load_client_identity(config)? {
+            // mTLS with client certificate
+            Some((client_certs, client_key)) => builder
+                .with_client_auth_cert(client_certs, client_key)
+                .map_err(|e| {
+                    crate::error::TalosError::Config(format!(
+                        "Failed to configure client auth: {e}"
+                    ))
+                })?,
+            // TLS without client auth
+            None => builder.with_no_client_auth(),
+        };
 
-                let client_certs = Self::load_pem_certs(&cert_pem)?;
-                let client_key = Self::load_pem_key(&key_pem)?;
+        Ok(tls_config)
+    }
 
-                rustls::ClientConfig::builder()
-                    .with_root_certificates(root_store)
-                    .with_client_auth_cert(client_certs, client_key)
-                    .map_err(|e| {
-                        crate::error::TalosError::Config(format!(
-                            "Failed to configure client auth: {e}"
-                        ))
-                    })?
-            } else {
-                // TLS without client auth
-                rustls::ClientConfig::builder()
-                    .with_root_certificates(root_store)
-                    .with_no_client_auth()
-            };
+    /// Load the client certificate chain and key, from a PKCS#12 bundle or PEM files
+    #[allow(clippy::result_large_err)]
+    fn load_client_identity(
+        config: &TalosClientConfig,
+    ) -> Result<Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>> {
+        if let Some(identity) = &config.client_p12 {
+            let data = std::fs::read(&identity.path).map_err(|e| {
+                crate::error::TalosError::Config(format!("Failed to read PKCS#12 bundle: {e}"))
+            })?;
+            return Self::load_pkcs12_identity(&data, &identity.password).map(Some);
+        }
+        if let Some(identity) = &config.client_pem {
+            return Ok(Some((
+                Self::load_pem_certs(&identity.crt)?,
+                Self::load_pem_key(&identity.key)?,
+            )));
+        }
+
+        let (Some(crt_path), Some(key_path)) = (&config.crt_path, &config.key_path) else {
+            return Ok(None);
+        };
+        let cert_pem = std::fs::read(crt_path).map_err(|e| {
+            crate::error::TalosError::Config(format!("Failed to read client cert: {e}"))
+        })?;
+        let key_pem = std::fs::read(key_path).map_err(|e| {
+            crate::error::TalosError::Config(format!("Failed to read client key: {e}"))
+        })?;
+
+        Ok(Some((
+            Self::load_pem_certs(&cert_pem)?,
+            Self::load_pem_key(&key_pem)?,
+        )))
+    }
+
+    /// Extract the certificate chain and private key from a PKCS#12 bundle
+    #[cfg(feature = "pkcs12")]
+    #[allow(clippy::result_large_err)]
+    fn load_pkcs12_identity(
+        data: &[u8],
+        password: &str,
+    ) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)> {
+        let keystore = p12_keystore::KeyStore::from_pkcs12(
+            data,
+            password,
+            p12_keystore::Pkcs12ImportPolicy::Strict,
+        )
+        .map_err(|e| {
+            crate::error::TalosError::Config(format!("Failed to parse PKCS#12 bundle: {e}"))
+        })?;
+        let (_, chain) = keystore.private_key_chain().ok_or_else(|| {
+            crate::error::TalosError::Config(
+                "No private key with certificate found in PKCS#12 bundle".to_string(),
+            )
+        })?;
+
+        let certs = chain
+            .certs()
+            .iter()
+            .map(|cert| CertificateDer::from(cert.as_der().to_vec()))
+            .collect();
+        let key = PrivateKeyDer::Pkcs8(rustls::pki_types::PrivatePkcs8KeyDer::from(
+            chain.key().as_der().to_vec(),
+        ));
+        Ok((certs, key))
+    }
 
-        Self::connect_with_custom_tls(config, tls_config, false).await
+    /// PKCS#12 bundles are unavailable without the `pkcs12` feature
+    #[cfg(not(feature = "pkcs12"))]
+    #[allow(clippy::result_large_err)]
+    fn load_pkcs12_identity(
+        _data: &[u8],
+        _password: &str,
+    ) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)> {
+        Err(crate::error::TalosError::Config(
+            "PKCS#12 client identities require the `pkcs12` feature".to_string(),
+        ))
     }
 
     /// Connect using a custom rustls TLS configuration
     async fn connect_with_custom_tls(
         config: &TalosClientConfig,
-        mut tls_config: rustls::ClientConfig,
-        skip_verification: bool,
+        tls_config: Arc<rustls::ClientConfig>,
     ) -> Result<Channel> {
-        // Override verifier for insecure mode
-        if skip_verification {
-            tls_config
-                .dangerous()
-                .set_certificate_verifier(Arc::new(NoVerifier));
-        }
-
-        // gRPC requires ALPN h2
-        tls_config.alpn_protocols = vec![b"h2".to_vec()];
-        let tls_config = Arc::new(tls_config);
         let connector = tokio_rustls::TlsConnector::from(tls_config);
 
         // Extract host for SNI
@@ -565,9 +1598,6 @@ impl TalosClient {
         if let Some(timeout) = config.connect_timeout {
             endpoint = endpoint.connect_timeout(timeout);
         }
-        if let Some(timeout) = config.request_timeout {
-            endpoint = endpoint.timeout(timeout);
-        }
         if let Some(interval) = config.keepalive_interval {
             if let Some(ka_timeout) = config.keepalive_timeout {
                 endpoint = endpoint
@@ -576,6 +1606,7 @@ impl TalosClient {
             }
         }
 
+        let nodelay = config.tcp_nodelay;
         let channel = endpoint
             .connect_with_connector(tower::service_fn(move |uri: tonic::transport::Uri| {
                 let connector = connector.clone();
@@ -585,7 +1616,7 @@ impl TalosClient {
                     let uri_port = uri.port_u16().unwrap_or(50000);
                     let addr = format!("{}:{}", uri_host, uri_port);
 
-                    let tcp = tokio::net::TcpStream::connect(addr).await?;
+                    let tcp = connect_tcp(&addr, nodelay).await?;
 
                     // Use actual hostname for SNI (important for cert verification)
                     let server_name = ServerName::try_from(host.clone())
@@ -595,7 +1626,8 @@ impl TalosClient {
                     Ok::<_, std::io::Error>(TokioIo::new(tls_stream))
                 }
             }))
-            .await?;
+            .await
+            .map_err(crate::error::TalosError::from_connect_error)?;
 
         Ok(channel)
     }
@@ -691,103 +1723,531 @@ impl TalosClient {
     }
 
     /// Access the Version API group
-    pub fn version(&self) -> VersionServiceClient<Channel> {
+    pub fn version(&self) -> VersionServiceClient<TalosChannel> {
         VersionServiceClient::new(self.channel.clone())
     }
 
     /// Access the Machine API group
-    pub fn machine(&self) -> MachineServiceClient<Channel> {
+    pub fn machine(&self) -> MachineServiceClient<TalosChannel> {
         MachineServiceClient::new(self.channel.clone())
     }
 
+    /// Access the Cluster API group
+    pub fn cluster(&self) -> ClusterServiceClient<TalosChannel> {
+        ClusterServiceClient::new(self.channel.clone())
+    }
+
+    /// Access the COSI resource state API
+    pub fn cosi(&self) -> StateClient<TalosChannel> {
+        StateClient::new(self.channel.clone())
+    }
+
     /// Create a gRPC request with node targeting applied
     fn make_request<T>(&self, inner: T) -> tonic::Request<T> {
         self.node_target
             .apply_to_request(tonic::Request::new(inner))
     }
 
-    // ========================================================================
-    // High-level convenience methods
-    // ========================================================================
-
-    /// Apply a configuration to the node.
-    ///
-    /// This is a high-level wrapper around the `MachineService::ApplyConfiguration` RPC.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// use talos_api_rs::{TalosClient, TalosClientConfig, ApplyConfigurationRequest, ApplyMode};
-    ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = TalosClient::new(TalosClientConfig {
-    ///     endpoint: "https://192.168.1.100:50000".to_string(),
-    ///     insecure: true,
-    ///     ..Default::default()
-    /// }).await?;
+    /// Run a unary `MachineService` call through the retry policy for `method`.
     ///
-    /// // Apply configuration in dry-run mode
-    /// let request = ApplyConfigurationRequest::builder()
-    ///     .config_yaml("machine:\n  type: worker")
-    ///     .mode(ApplyMode::NoReboot)
-    ///     .dry_run(true)
-    ///     .build();
+    /// `method` is the gRPC method name (e.g. `"Memory"`), used to look up the
+    /// policy via [`TalosClientConfig::retry_config_for`]. Clients built with
+    /// [`with_call_timeout`](Self::with_call_timeout) send the call once, so
+    /// the deadline bounds the whole call rather than each attempt.
+    async fn unary_with_retry<T, F, Fut>(&self, method: &str, mut call: F) -> Result<T>
+    where
+        F: FnMut(MachineServiceClient<TalosChannel>) -> Fut,
+        Fut: std::future::Future<Output = std::result::Result<tonic::Response<T>, tonic::Status>>,
+    {
+        let mut retry = self.config.retry_config_for(method);
+        if self.channel.call_timeout().is_some() {
+            retry.max_retries = 0;
+        }
+        let response = match retry.execute(|| call(self.machine())).await {
+            Err(status) if is_broken_connection(&status) => {
+                // A GOAWAY or dropped connection can leave the channel broken;
+                // rebuild it once. Only methods that may be retried are replayed.
+                tracing::debug!(
+                    "{} failed on a broken connection to {}, reconnecting: {}",
+                    method,
+                    self.config.endpoint,
+                    status
+                );
+                if self.reconnect().await.is_err() || retry.max_retries == 0 {
+                    return Err(status.into());
+                }
+                call(self.machine()).await?
+            }
+            other => other?,
+        };
+        Ok(response.into_inner())
+    }
+
+    /// Rebuild the connection from this client's configuration.
     ///
-    /// let response = client.apply_configuration(request).await?;
-    /// println!("Warnings: {:?}", response.all_warnings());
-    /// # Ok(())
-    /// # }
-    /// ```
+    /// The new connection replaces the old one for this client and all its
+    /// clones (including those returned by [`with_node`](Self::with_node)).
+    /// Unary calls do this on their own, once, when a request fails because
+    /// the server closed the connection (e.g. an HTTP/2 GOAWAY during a
+    /// restart).
     ///
     /// # Errors
     ///
-    /// Returns an error if the RPC call fails or the configuration is invalid.
-    pub async fn apply_configuration(
-        &self,
-        request: ApplyConfigurationRequest,
-    ) -> Result<ApplyConfigurationResponse> {
-        let proto_request: ProtoApplyConfigRequest = request.into();
-        let grpc_request = self.make_request(proto_request);
-        let response = self
-            .machine()
-            .apply_configuration(grpc_request)
-            .await?
-            .into_inner();
-        Ok(response.into())
+    /// Returns the connection error if the endpoint cannot be reached; the
+    /// old connection is kept in that case.
+    pub async fn reconnect(&self) -> Result<()> {
+        let fresh = Self::new(self.config.clone()).await?;
+        self.channel.replace_connection(&fresh.channel);
+        Ok(())
     }
 
-    /// Apply a YAML configuration string to the node.
-    ///
-    /// Convenience method for simple configuration application.
-    ///
-    /// # Example
+    /// Start the stream deadline and idle timer for `method`.
+    fn stream_timer<'a>(&self, method: &'a str) -> StreamTimer<'a> {
+        StreamTimer {
+            method,
+            deadline: self
+                .config
+                .stream_timeout
+                .map(|timeout| std::time::Instant::now() + timeout),
+            idle: self.config.stream_idle_timeout,
+        }
+    }
+
+    /// Bound the gap between two items of a stream handed to the caller by
+    /// `stream_idle_timeout`.
     ///
-    /// ```ignore
-    /// use talos_api_rs::{TalosClient, TalosClientConfig, ApplyMode};
+    /// The `*_stream` methods may stay open indefinitely (follow mode,
+    /// watches), so only the idle timeout applies to them, not
+    /// `stream_timeout`.
+    fn idle_timeout<S>(&self, method: &'static str, stream: S) -> IdleTimeout<S> {
+        IdleTimeout {
+            inner: stream,
+            method,
+            idle: self.config.stream_idle_timeout,
+            timer: None,
+            expired: false,
+        }
+    }
+
+    /// Collect a `common.Data` stream into memory, honouring `max_buffer_bytes`.
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = TalosClient::new(TalosClientConfig::default()).await?;
-    /// let config_yaml = std::fs::read_to_string("machine.yaml")?;
-    /// let response = client.apply_configuration_yaml(&config_yaml, ApplyMode::Auto, false).await?;
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub async fn apply_configuration_yaml(
+    /// Returns the concatenated bytes and the node from the first chunk
+    /// carrying metadata.
+    async fn collect_data(
         &self,
-        yaml: &str,
-        mode: crate::ApplyMode,
-        dry_run: bool,
-    ) -> Result<ApplyConfigurationResponse> {
-        let request = ApplyConfigurationRequest::builder()
-            .config_yaml(yaml)
-            .mode(mode)
-            .dry_run(dry_run)
-            .build();
-        self.apply_configuration(request).await
+        method: &str,
+        stream: tonic::Streaming<crate::api::common::Data>,
+    ) -> Result<(Vec<u8>, Option<String>)> {
+        self.collect_data_with(method, stream, |_| {}).await
     }
 
-    /// Bootstrap the etcd cluster on this node.
-    ///
+    /// Like [`collect_data`](Self::collect_data), passing each chunk to
+    /// `inspect` as it arrives.
+    async fn collect_data_with(
+        &self,
+        method: &str,
+        mut stream: tonic::Streaming<crate::api::common::Data>,
+        mut inspect: impl FnMut(&[u8]),
+    ) -> Result<(Vec<u8>, Option<String>)> {
+        let timer = self.stream_timer(method);
+        let mut data = Vec::new();
+        let mut node = None;
+
+        while let Some(chunk) = timer.next(&mut stream).await? {
+            let chunk = chunk?;
+            // Capture node from first chunk with metadata
+            if node.is_none() {
+                if let Some(metadata) = &chunk.metadata {
+                    node = Some(metadata.hostname.clone());
+                }
+            }
+            self.check_buffer_limit(method, data.len() + chunk.bytes.len())?;
+            inspect(&chunk.bytes);
+            data.extend(chunk.bytes);
+        }
+
+        Ok((data, node))
+    }
+
+    /// Fail once a buffered `method` response would grow to `len` bytes
+    /// beyond `max_buffer_bytes`.
+    #[allow(clippy::result_large_err)]
+    fn check_buffer_limit(&self, method: &str, len: usize) -> Result<()> {
+        match self.config.max_buffer_bytes {
+            Some(max) if len > max => Err(crate::error::TalosError::Validation(format!(
+                "{} response exceeds max_buffer_bytes ({} bytes); \
+                 use a streaming API or raise the limit",
+                method, max
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    // ========================================================================
+    // Readiness
+    // ========================================================================
+
+    /// Check whether the node answers API requests.
+    ///
+    /// Issues a lightweight `Version` call. Returns `Ok(())` if the node
+    /// responded, or the underlying error otherwise.
+    pub async fn probe(&self) -> Result<()> {
+        let mut client = self.version();
+        let request = self.make_request(VersionRequest { client: false });
+        client.version(request).await?;
+        Ok(())
+    }
+
+    /// Get the lifecycle stage and readiness of each targeted node.
+    ///
+    /// Reads the `MachineStatuses.runtime.talos.dev` resource, like
+    /// `talosctl get machinestatus`, so `ready` reflects whether the node
+    /// finished booting and met all of its readiness conditions. The API
+    /// proxy does not fan COSI calls out to several nodes, so each targeted
+    /// node is asked on its own. Statuses come back in target order, with
+    /// `node` set to the target (`None` for the connected endpoint).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first node's error, or `TalosError::Unknown` if a status
+    /// cannot be decoded.
+    pub async fn machine_status(&self) -> Result<Vec<MachineStatus>> {
+        let nodes: Vec<Option<String>> = match self.node_target.nodes() {
+            [] => vec![None],
+            nodes => nodes.iter().cloned().map(Some).collect(),
+        };
+
+        let mut statuses = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            let client = match &node {
+                Some(node) => self.with_node(NodeTarget::single(node.clone())),
+                None => self.clone(),
+            };
+            let resource = client
+                .resource_get(
+                    MACHINE_STATUS_NAMESPACE,
+                    MACHINE_STATUS_TYPE,
+                    MACHINE_STATUS_ID,
+                )
+                .await?;
+            statuses.push(MachineStatus::from_resource(&resource, node)?);
+        }
+        Ok(statuses)
+    }
+
+    /// Check whether the targeted nodes are up and report themselves ready.
+    ///
+    /// Nodes that do not serve the COSI API count as ready once they answer
+    /// [`probe`](Self::probe).
+    async fn check_ready(&self) -> Result<()> {
+        self.probe().await?;
+
+        match self.machine_status().await {
+            Ok(statuses) => match statuses.iter().find(|status| !status.ready) {
+                None => Ok(()),
+                Some(status) => {
+                    let unmet: Vec<&str> = status
+                        .unmet_conditions
+                        .iter()
+                        .map(|c| c.name.as_str())
+                        .collect();
+                    Err(crate::error::TalosError::Unknown(format!(
+                        "node{} is {}, unmet conditions: [{}]",
+                        status
+                            .node
+                            .as_ref()
+                            .map(|node| format!(" {}", node))
+                            .unwrap_or_default(),
+                        status.stage,
+                        unmet.join(", ")
+                    )))
+                }
+            },
+            Err(crate::error::TalosError::Api(status))
+                if status.code() == tonic::Code::Unimplemented =>
+            {
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Report which RPCs the node implements.
+    ///
+    /// Every call made through this client (and its clones) records whether
+    /// the node answered `Unimplemented`, and the result is cached for the
+    /// lifetime of the connection. On top of that, this probes a few cheap
+    /// read-only RPCs that have not been called yet, so the first call
+    /// costs a handful of round trips and later calls none.
+    ///
+    /// Methods never called are reported as supported.
+    pub async fn capabilities(&self) -> Capabilities {
+        let cache = self.channel.capabilities();
+
+        // Only whether the node implements the method matters here, so
+        // other errors are ignored; the channel records the outcome.
+        if !cache.is_known("Version") {
+            let _ = self.probe().await;
+        }
+        if !cache.is_known("Hostname") {
+            let _ = self.machine().hostname(self.make_request(())).await;
+        }
+        if !cache.is_known("ServiceList") {
+            let _ = self.machine().service_list(self.make_request(())).await;
+        }
+        if !cache.is_known("EtcdStatus") {
+            let _ = self.machine().etcd_status(self.make_request(())).await;
+        }
+
+        cache.snapshot()
+    }
+
+    /// Wait until the node is ready.
+    ///
+    /// Polls the node with exponential backoff until it answers API requests
+    /// and its [`machine_status`](Self::machine_status) reports ready, or
+    /// `timeout` elapses. Useful right after provisioning, when the channel
+    /// is up but the node may still be booting.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::{TalosClient, TalosClientConfig};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TalosClient::new(TalosClientConfig::default()).await?;
+    /// client.wait_until_ready(Duration::from_secs(120)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Timeout` if the node did not become ready in time.
+    pub async fn wait_until_ready(&self, timeout: Duration) -> Result<()> {
+        let backoff = ExponentialBackoff::new(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(5));
+        let start = std::time::Instant::now();
+        let mut attempt = 0;
+
+        loop {
+            let remaining = timeout.saturating_sub(start.elapsed());
+            let last_error = match tokio::time::timeout(remaining, self.check_ready()).await {
+                Ok(Ok(())) => return Ok(()),
+                Ok(Err(e)) => e.to_string(),
+                Err(_) => "probe did not complete".to_string(),
+            };
+
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return Err(crate::error::TalosError::Timeout(format!(
+                    "node not ready after {:?}: {}",
+                    timeout, last_error
+                )));
+            }
+
+            tokio::time::sleep(backoff.delay(attempt).min(remaining)).await;
+            attempt += 1;
+        }
+    }
+
+    // ========================================================================
+    // High-level convenience methods
+    // ========================================================================
+
+    /// Apply a configuration to the node.
+    ///
+    /// This is a high-level wrapper around the `MachineService::ApplyConfiguration` RPC.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::{TalosClient, TalosClientConfig, ApplyConfigurationRequest, ApplyMode};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TalosClient::new(TalosClientConfig {
+    ///     endpoint: "https://192.168.1.100:50000".to_string(),
+    ///     insecure: true,
+    ///     ..Default::default()
+    /// }).await?;
+    ///
+    /// // Apply configuration in dry-run mode
+    /// let request = ApplyConfigurationRequest::builder()
+    ///     .config_yaml("machine:\n  type: worker")
+    ///     .mode(ApplyMode::NoReboot)
+    ///     .dry_run(true)
+    ///     .build()?;
+    ///
+    /// let response = client.apply_configuration(request).await?;
+    /// println!("Warnings: {:?}", response.all_warnings());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RPC call fails or the configuration is invalid.
+    pub async fn apply_configuration(
+        &self,
+        request: ApplyConfigurationRequest,
+    ) -> Result<ApplyConfigurationResponse> {
+        let proto_request: ProtoApplyConfigRequest = request.into();
+        let response = self
+            .unary_with_retry("ApplyConfiguration", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.apply_configuration(request).await }
+            })
+            .await?;
+        Ok(response.into())
+    }
+
+    /// Apply a YAML configuration string to the node.
+    ///
+    /// Convenience method for simple configuration application.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::{TalosClient, TalosClientConfig, ApplyMode};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TalosClient::new(TalosClientConfig::default()).await?;
+    /// let config_yaml = std::fs::read_to_string("machine.yaml")?;
+    /// let response = client.apply_configuration_yaml(&config_yaml, ApplyMode::Auto, false).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_configuration_yaml(
+        &self,
+        yaml: &str,
+        mode: crate::ApplyMode,
+        dry_run: bool,
+    ) -> Result<ApplyConfigurationResponse> {
+        let request = ApplyConfigurationRequest::builder()
+            .config_yaml(yaml)
+            .mode(mode)
+            .dry_run(dry_run)
+            .build()?;
+        self.apply_configuration(request).await
+    }
+
+    /// Stage a YAML configuration for the next boot.
+    ///
+    /// The running configuration is untouched until the node reboots, which
+    /// makes this the safest way to roll out a change. Returns the distinct
+    /// warnings and node errors, see
+    /// [`ApplyConfigurationResponse::unique_warnings`].
+    pub async fn apply_configuration_staged(&self, yaml: &str) -> Result<Vec<ApplyWarning>> {
+        let response = self
+            .apply_configuration_yaml(yaml, crate::ApplyMode::Staged, false)
+            .await?;
+        Ok(response.unique_warnings())
+    }
+
+    /// Apply a configuration, then roll the node back to its previous Talos
+    /// installation if it reports a failure shortly after.
+    ///
+    /// **This is not a configuration revert.** [`rollback`](Self::rollback)
+    /// switches the node back to the other A/B boot image, i.e. the Talos
+    /// version installed before the last upgrade, and reboots into it. Use it
+    /// when a configuration is applied together with an upgrade. To revert
+    /// only the configuration, apply it with
+    /// [`ApplyMode::Try`](crate::ApplyMode::Try): Talos restores the previous
+    /// configuration after the try timeout unless the change is confirmed by
+    /// applying it again in another mode.
+    ///
+    /// Subscribes to [`events_stream`](Self::events_stream) before applying,
+    /// so no event is missed, and watches new events for
+    /// `policy.watch_timeout`. Only events correlated with this apply are
+    /// passed to the policy: they must come from a node that accepted the
+    /// configuration and carry a timestamp (see [`Event::timestamp`]) no
+    /// earlier than the apply, allowing for a little clock skew. The first
+    /// such event matching the policy triggers the rollback. Watching also
+    /// stops, keeping the configuration, when the event stream ends or fails,
+    /// e.g. because the node reboots into the new configuration.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    /// use talos_api_rs::{ApplyConfigurationRequest, ApplyRollbackPolicy};
+    ///
+    /// let policy = ApplyRollbackPolicy::new(Duration::from_secs(120));
+    /// let outcome = client
+    ///     .apply_then_rollback_on_failure(ApplyConfigurationRequest::from_yaml(yaml), policy)
+    ///     .await?;
+    /// if outcome.is_rolled_back() {
+    ///     eprintln!("configuration failed and was rolled back");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if a node rejected the configuration
+    /// (nothing is rolled back then), or the error of the apply, the events
+    /// subscription or the rollback.
+    pub async fn apply_then_rollback_on_failure(
+        &self,
+        request: ApplyConfigurationRequest,
+        policy: ApplyRollbackPolicy,
+    ) -> Result<ApplyRollbackOutcome> {
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let applied_at = std::time::SystemTime::now();
+        let events = self.events_stream(EventsRequest::new()).await?;
+        let applied = self.apply_configuration(request).await?;
+        if applied.has_errors() {
+            let errors: Vec<String> = applied
+                .results
+                .iter()
+                .filter_map(|r| {
+                    let error = r.error.as_ref()?;
+                    Some(match &r.node {
+                        Some(node) => format!("{}: {}", node, error),
+                        None => error.clone(),
+                    })
+                })
+                .collect();
+            return Err(crate::error::TalosError::Validation(format!(
+                "configuration rejected: {}",
+                errors.join("; ")
+            )));
+        }
+
+        let watch = async {
+            tokio::pin!(events);
+            while let Some(Ok(event)) = events.next().await {
+                if caused_by_apply(&event, applied_at, &applied) && policy.is_failure(&event) {
+                    return Some(event);
+                }
+            }
+            None
+        };
+        let failure = tokio::time::timeout(policy.watch_timeout, watch)
+            .await
+            .ok()
+            .flatten();
+
+        match failure {
+            Some(failure) => {
+                tracing::warn!(
+                    event_id = %failure.id,
+                    "Applied configuration failed, rolling back"
+                );
+                let rollback = self.rollback().await?;
+                Ok(ApplyRollbackOutcome::RolledBack {
+                    applied,
+                    failure: Box::new(failure),
+                    rollback,
+                })
+            }
+            None => Ok(ApplyRollbackOutcome::Kept { applied }),
+        }
+    }
+
+    /// Bootstrap the etcd cluster on this node.
+    ///
     /// This initializes a new etcd cluster. **This should only be called ONCE**
     /// on the first control-plane node when creating a new Talos cluster.
     ///
@@ -808,7 +2268,8 @@ impl TalosClient {
     ///
     /// # Recovery
     ///
-    /// To recover from an etcd snapshot (uploaded via `EtcdRecover` RPC):
+    /// To recover from an etcd snapshot (uploaded via
+    /// [`etcd_recover`](Self::etcd_recover)):
     ///
     /// ```ignore
     /// use talos_api_rs::{TalosClient, TalosClientConfig, BootstrapRequest};
@@ -828,8 +2289,12 @@ impl TalosClient {
     /// - Network/connection issues
     pub async fn bootstrap(&self, request: BootstrapRequest) -> Result<BootstrapResponse> {
         let proto_request: ProtoBootstrapRequest = request.into();
-        let grpc_request = self.make_request(proto_request);
-        let response = self.machine().bootstrap(grpc_request).await?.into_inner();
+        let response = self
+            .unary_with_retry("Bootstrap", |mut client| {
+                let request = self.make_request(proto_request);
+                async move { client.bootstrap(request).await }
+            })
+            .await?;
         Ok(response.into())
     }
 
@@ -840,6 +2305,25 @@ impl TalosClient {
         self.bootstrap(BootstrapRequest::new()).await
     }
 
+    /// Bootstrap etcd and wait until it has elected a leader.
+    ///
+    /// Calls [`bootstrap_cluster`](Self::bootstrap_cluster), then polls
+    /// [`etcd_status`](Self::etcd_status) until a member reports a leader and
+    /// no errors. Status errors while etcd is still starting are treated as
+    /// "not ready yet".
+    ///
+    /// # Errors
+    ///
+    /// Returns the bootstrap error if bootstrapping fails, or
+    /// `TalosError::Timeout` if etcd is not healthy within `timeout`.
+    pub async fn bootstrap_and_wait(&self, timeout: Duration) -> Result<BootstrapResponse> {
+        let response = self.bootstrap_cluster().await?;
+
+        wait_for_etcd_leader(|| self.etcd_status(), timeout, ETCD_READY_POLL_INTERVAL).await?;
+
+        Ok(response)
+    }
+
     /// Retrieve the kubeconfig from the cluster.
     ///
     /// This is a server-streaming RPC that retrieves the kubeconfig file
@@ -875,23 +2359,9 @@ impl TalosClient {
     /// - The cluster is not yet bootstrapped
     /// - Network/connection issues
     pub async fn kubeconfig(&self) -> Result<KubeconfigResponse> {
-        use tonic::codegen::tokio_stream::StreamExt;
-
-        let mut stream = self.machine().kubeconfig(()).await?.into_inner();
-
-        let mut data = Vec::new();
-        let mut node = None;
+        let stream = self.machine().kubeconfig(()).await?.into_inner();
 
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            // Capture node from first chunk with metadata
-            if node.is_none() {
-                if let Some(metadata) = &chunk.metadata {
-                    node = Some(metadata.hostname.clone());
-                }
-            }
-            data.extend(chunk.bytes);
-        }
+        let (data, node) = self.collect_data("Kubeconfig", stream).await?;
 
         Ok(KubeconfigResponse::new(data, node))
     }
@@ -933,11 +2403,13 @@ impl TalosClient {
     /// # }
     /// ```
     pub async fn reset(&self, request: ResetRequest) -> Result<ResetResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
         let proto_request: ProtoResetRequest = request.into();
-        let response = client.reset(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("Reset", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.reset(request).await }
+            })
+            .await?;
 
         Ok(ResetResponse::from(inner))
     }
@@ -954,6 +2426,64 @@ impl TalosClient {
         self.reset(ResetRequest::graceful()).await
     }
 
+    // =========================================================================
+    // Power
+    // =========================================================================
+
+    /// Reboot a Talos node.
+    ///
+    /// The call returns once the reboot is initiated; the node drops its
+    /// connections shortly after.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::{RebootMode, RebootRequest};
+    ///
+    /// let response = client.reboot(RebootRequest::new()).await?;
+    ///
+    /// // Bypass kexec, e.g. after firmware changes
+    /// let response = client
+    ///     .reboot(RebootRequest::with_mode(RebootMode::PowerCycle))
+    ///     .await?;
+    /// ```
+    pub async fn reboot(&self, request: RebootRequest) -> Result<RebootResponse> {
+        let proto_request: ProtoRebootRequest = request.into();
+        let inner = self
+            .unary_with_retry("Reboot", |mut client| {
+                let request = self.make_request(proto_request);
+                async move { client.reboot(request).await }
+            })
+            .await?;
+
+        Ok(RebootResponse::from(inner))
+    }
+
+    /// Shut down a Talos node.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::ShutdownRequest;
+    ///
+    /// // Cordon and drain first
+    /// let response = client.shutdown(ShutdownRequest::new()).await?;
+    ///
+    /// // Power off immediately
+    /// let response = client.shutdown(ShutdownRequest::force()).await?;
+    /// ```
+    pub async fn shutdown(&self, request: ShutdownRequest) -> Result<ShutdownResponse> {
+        let proto_request: ProtoShutdownRequest = request.into();
+        let inner = self
+            .unary_with_retry("Shutdown", |mut client| {
+                let request = self.make_request(proto_request);
+                async move { client.shutdown(request).await }
+            })
+            .await?;
+
+        Ok(ShutdownResponse::from(inner))
+    }
+
     // =========================================================================
     // etcd Operations
     // =========================================================================
@@ -980,11 +2510,12 @@ impl TalosClient {
         &self,
         request: EtcdMemberListRequest,
     ) -> Result<EtcdMemberListResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
         let proto_request: ProtoEtcdMemberListRequest = request.into();
-        let response = client.etcd_member_list(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("EtcdMemberList", |mut client| async move {
+                client.etcd_member_list(proto_request).await
+            })
+            .await?;
 
         Ok(EtcdMemberListResponse::from(inner))
     }
@@ -1017,29 +2548,130 @@ impl TalosClient {
         &self,
         request: EtcdRemoveMemberByIdRequest,
     ) -> Result<EtcdRemoveMemberByIdResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
         let proto_request: ProtoEtcdRemoveMemberByIdRequest = request.into();
-        let response = client.etcd_remove_member_by_id(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("EtcdRemoveMemberByID", |mut client| async move {
+                client.etcd_remove_member_by_id(proto_request).await
+            })
+            .await?;
 
         Ok(EtcdRemoveMemberByIdResponse::from(inner))
     }
 
-    /// Make a node leave the etcd cluster gracefully.
+    /// Remove an etcd member by ID and wait until it is gone from the member list.
     ///
-    /// This should be called on the node that is being removed.
-    pub async fn etcd_leave_cluster(
+    /// Removes the member, then polls [`etcd_member_list`](Self::etcd_member_list)
+    /// until the member no longer appears or `timeout` elapses.
+    ///
+    /// # Errors
+    ///
+    /// Returns the removal error if the member could not be removed, or
+    /// `TalosError::Timeout` if the member is still listed after `timeout`.
+    pub async fn remove_member_and_confirm(
         &self,
-        request: EtcdLeaveClusterRequest,
-    ) -> Result<EtcdLeaveClusterResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
+        member_id: u64,
+        timeout: Duration,
+    ) -> Result<EtcdRemoveMemberByIdResponse> {
+        let response = self
+            .etcd_remove_member_by_id(EtcdRemoveMemberByIdRequest::new(member_id))
+            .await?;
 
-        let proto_request: ProtoEtcdLeaveClusterRequest = request.into();
-        let response = client.etcd_leave_cluster(proto_request).await?;
-        let inner = response.into_inner();
+        wait_for_member_removal(
+            || self.etcd_member_list(EtcdMemberListRequest::new()),
+            member_id,
+            timeout,
+            MEMBER_REMOVAL_POLL_INTERVAL,
+        )
+        .await?;
 
-        Ok(EtcdLeaveClusterResponse::from(inner))
+        Ok(response)
+    }
+
+    /// Remove a control-plane node from etcd and reset it.
+    ///
+    /// Runs the safe removal sequence against `node` (via node targeting):
+    ///
+    /// 1. Read the node's etcd status to find its member ID.
+    /// 2. Forfeit leadership if the node is the leader; skip otherwise.
+    /// 3. Leave the etcd cluster.
+    /// 4. Poll the member list through this client's own target until the
+    ///    member is gone (up to `timeout`).
+    /// 5. Reset the node with `reset`.
+    ///
+    /// Connect this client to a *different* control-plane node, since the
+    /// drained node's etcd stops answering after it leaves. This is
+    /// destructive and never runs implicitly.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first failing step. Returns `TalosError::Validation` if
+    /// the node is not an etcd member, or `TalosError::Timeout` if the member
+    /// is still listed after `timeout`; the node is not reset in either case.
+    pub async fn drain_node(
+        &self,
+        node: impl Into<String>,
+        reset: ResetRequest,
+        timeout: Duration,
+    ) -> Result<DrainReport> {
+        let node = node.into();
+        let target = self.with_node(NodeTarget::single(node.clone()));
+        let mut steps = Vec::new();
+
+        let status = target.etcd_status().await?;
+        let member = status.first().cloned().ok_or_else(|| {
+            crate::error::TalosError::Validation(format!("node {} is not an etcd member", node))
+        })?;
+
+        if member.is_leader() {
+            target
+                .etcd_forfeit_leadership(EtcdForfeitLeadershipRequest::new())
+                .await?;
+            steps.push(DrainStep::ForfeitedLeadership);
+        } else {
+            steps.push(DrainStep::SkippedForfeit);
+        }
+
+        target
+            .etcd_leave_cluster(EtcdLeaveClusterRequest::new())
+            .await?;
+        steps.push(DrainStep::LeftEtcd);
+
+        wait_for_member_removal(
+            || self.etcd_member_list(EtcdMemberListRequest::new()),
+            member.member_id,
+            timeout,
+            MEMBER_REMOVAL_POLL_INTERVAL,
+        )
+        .await?;
+        steps.push(DrainStep::ConfirmedRemoval);
+
+        let reset = target.reset(reset).await?;
+        steps.push(DrainStep::Reset);
+
+        Ok(DrainReport {
+            node,
+            member_id: member.member_id,
+            steps,
+            reset,
+        })
+    }
+
+    /// Make a node leave the etcd cluster gracefully.
+    ///
+    /// This should be called on the node that is being removed.
+    pub async fn etcd_leave_cluster(
+        &self,
+        request: EtcdLeaveClusterRequest,
+    ) -> Result<EtcdLeaveClusterResponse> {
+        let proto_request: ProtoEtcdLeaveClusterRequest = request.into();
+        let inner = self
+            .unary_with_retry("EtcdLeaveCluster", |mut client| {
+                let request = self.make_request(proto_request);
+                async move { client.etcd_leave_cluster(request).await }
+            })
+            .await?;
+
+        Ok(EtcdLeaveClusterResponse::from(inner))
     }
 
     /// Forfeit etcd leadership.
@@ -1049,41 +2681,49 @@ impl TalosClient {
         &self,
         request: EtcdForfeitLeadershipRequest,
     ) -> Result<EtcdForfeitLeadershipResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
         let proto_request: ProtoEtcdForfeitLeadershipRequest = request.into();
-        let response = client.etcd_forfeit_leadership(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("EtcdForfeitLeadership", |mut client| {
+                let request = self.make_request(proto_request);
+                async move { client.etcd_forfeit_leadership(request).await }
+            })
+            .await?;
 
         Ok(EtcdForfeitLeadershipResponse::from(inner))
     }
 
     /// Get etcd status for the current member.
     pub async fn etcd_status(&self) -> Result<EtcdStatusResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.etcd_status(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("EtcdStatus", |mut client| {
+                let request = self.make_request(());
+                async move { client.etcd_status(request).await }
+            })
+            .await?;
 
         Ok(EtcdStatusResponse::from(inner))
     }
 
     /// List etcd alarms.
     pub async fn etcd_alarm_list(&self) -> Result<EtcdAlarmListResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.etcd_alarm_list(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("EtcdAlarmList", |mut client| {
+                let request = self.make_request(());
+                async move { client.etcd_alarm_list(request).await }
+            })
+            .await?;
 
         Ok(EtcdAlarmListResponse::from(inner))
     }
 
     /// Disarm etcd alarms.
     pub async fn etcd_alarm_disarm(&self) -> Result<EtcdAlarmDisarmResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.etcd_alarm_disarm(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("EtcdAlarmDisarm", |mut client| {
+                let request = self.make_request(());
+                async move { client.etcd_alarm_disarm(request).await }
+            })
+            .await?;
 
         Ok(EtcdAlarmDisarmResponse::from(inner))
     }
@@ -1092,17 +2732,101 @@ impl TalosClient {
     ///
     /// **Warning**: This is a resource-heavy operation.
     pub async fn etcd_defragment(&self) -> Result<EtcdDefragmentResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.etcd_defragment(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("EtcdDefragment", |mut client| {
+                let request = self.make_request(());
+                async move { client.etcd_defragment(request).await }
+            })
+            .await?;
 
         Ok(EtcdDefragmentResponse::from(inner))
     }
 
+    /// Clear etcd NOSPACE alarms by defragmenting and disarming.
+    ///
+    /// Lists alarms and, for each member with a NOSPACE alarm, defragments
+    /// that member's node and then disarms alarms there. Finally the alarm
+    /// list is read again; anything still active ends up in
+    /// [`remaining_alarms`](NospaceRemediationReport::remaining_alarms).
+    ///
+    /// Disarming clears every alarm on a member, so a member is only touched
+    /// when NOSPACE is its sole active alarm. Other alarm types (e.g. CORRUPT)
+    /// need manual investigation and are never cleared here.
+    ///
+    /// Defragmentation blocks the member while it runs, so this never runs
+    /// implicitly.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first failing call. Returns `TalosError::Validation`
+    /// without defragmenting or disarming anything if a NOSPACE member also
+    /// has another active alarm, or if an alarmed member is missing from the
+    /// member list.
+    pub async fn remediate_nospace_alarms(&self) -> Result<NospaceRemediationReport> {
+        let alarms = self.etcd_alarm_list().await?;
+        let active = alarms.active_alarms();
+        let mut member_ids: Vec<u64> = active
+            .iter()
+            .filter(|a| a.alarm == EtcdAlarmType::NoSpace)
+            .map(|a| a.member_id)
+            .collect();
+        member_ids.sort_unstable();
+        member_ids.dedup();
+
+        let blocking: Vec<String> = active
+            .iter()
+            .filter(|a| a.alarm != EtcdAlarmType::NoSpace && member_ids.contains(&a.member_id))
+            .map(|a| format!("{} on member {:x}", a.alarm, a.member_id))
+            .collect();
+        if !blocking.is_empty() {
+            return Err(crate::error::TalosError::Validation(format!(
+                "refusing to disarm NOSPACE alongside other active etcd alarms: {}",
+                blocking.join(", ")
+            )));
+        }
+
+        let mut remediated = Vec::new();
+        if !member_ids.is_empty() {
+            let members = self.etcd_member_list(EtcdMemberListRequest::new()).await?;
+            for member_id in member_ids {
+                let member = members.find_by_id(member_id).ok_or_else(|| {
+                    crate::error::TalosError::Validation(format!(
+                        "alarmed etcd member {:x} is not in the member list",
+                        member_id
+                    ))
+                })?;
+                let node = member_node(member);
+                let target = self.with_node(NodeTarget::single(node.clone()));
+
+                let defragment = target.etcd_defragment().await?;
+                let disarm = target.etcd_alarm_disarm().await?;
+                remediated.push(NospaceRemediation {
+                    member_id,
+                    node,
+                    defragment,
+                    disarm,
+                });
+            }
+        }
+
+        let remaining_alarms = self
+            .etcd_alarm_list()
+            .await?
+            .active_alarms()
+            .into_iter()
+            .cloned()
+            .collect();
+
+        Ok(NospaceRemediationReport {
+            remediated,
+            remaining_alarms,
+        })
+    }
+
     /// Create an etcd snapshot for backup.
     ///
-    /// This is a server-streaming RPC that returns the snapshot data.
+    /// Collects [`etcd_snapshot_stream`](Self::etcd_snapshot_stream) into
+    /// memory, failing once the snapshot exceeds `max_buffer_bytes`.
     /// The snapshot can be used for disaster recovery with `etcd_recover`.
     ///
     /// # Example
@@ -1127,21 +2851,98 @@ impl TalosClient {
         &self,
         request: EtcdSnapshotRequest,
     ) -> Result<EtcdSnapshotResponse> {
-        use tonic::codegen::tokio_stream::StreamExt;
+        let mut stream = self.etcd_snapshot_stream(request).await?;
+        let timer = self.stream_timer("EtcdSnapshot");
+        let mut data = Vec::new();
+        let mut node = None;
 
-        let mut client = MachineServiceClient::new(self.channel.clone());
+        while let Some(chunk) = timer.next(&mut stream).await? {
+            let chunk = chunk?;
+            if node.is_none() {
+                node = chunk.node;
+            }
+            self.check_buffer_limit("EtcdSnapshot", data.len() + chunk.bytes.len())?;
+            data.extend_from_slice(&chunk.bytes);
+        }
+
+        Ok(EtcdSnapshotResponse { data, node })
+    }
+
+    /// Create an etcd snapshot, yielding chunks as they arrive.
+    ///
+    /// Unlike [`etcd_snapshot`](Self::etcd_snapshot) nothing is buffered, so
+    /// large databases can be written straight to disk. A failure
+    /// mid-stream is yielded as an `Err` item; the snapshot is incomplete
+    /// in that case and must be discarded.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use tokio::io::AsyncWriteExt;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let stream = client.etcd_snapshot_stream(EtcdSnapshotRequest::new()).await?;
+    /// tokio::pin!(stream);
+    /// let mut file = tokio::fs::File::create("etcd-backup.db").await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     file.write_all(&chunk?.bytes).await?;
+    /// }
+    /// file.sync_all().await?;
+    /// ```
+    pub async fn etcd_snapshot_stream(
+        &self,
+        request: EtcdSnapshotRequest,
+    ) -> Result<impl Stream<Item = Result<EtcdSnapshotChunk>>> {
+        let mut client = self.machine();
 
         let proto_request: ProtoEtcdSnapshotRequest = request.into();
-        let response = client.etcd_snapshot(proto_request).await?;
-        let mut stream = response.into_inner();
+        let response = client
+            .etcd_snapshot(self.make_request(proto_request))
+            .await?;
 
-        let mut data = Vec::new();
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            data.extend_from_slice(&chunk.bytes);
+        Ok(self.idle_timeout("EtcdSnapshot", map_data_stream(response.into_inner())))
+    }
+
+    /// Upload an etcd snapshot to the node for recovery.
+    ///
+    /// The snapshot (as created by [`etcd_snapshot`](Self::etcd_snapshot))
+    /// is sent in [`DEFAULT_UPLOAD_CHUNK_SIZE`] chunks. Uploading does not
+    /// restore anything by itself: it must be followed by
+    /// [`bootstrap`](Self::bootstrap) with [`BootstrapRequest::recovery`] on
+    /// the same node, which starts etcd from the uploaded snapshot. Talos
+    /// only accepts the upload while the node is waiting to be bootstrapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TalosError::Validation`](crate::error::TalosError::Validation)
+    /// if `snapshot` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let snapshot = std::fs::read("etcd-backup.db")?;
+    /// let target = client.with_node(NodeTarget::single("10.0.0.1"));
+    /// target.etcd_recover(&snapshot).await?;
+    /// target.bootstrap(BootstrapRequest::recovery()).await?;
+    /// ```
+    pub async fn etcd_recover(&self, snapshot: impl AsRef<[u8]>) -> Result<EtcdRecoverResponse> {
+        let snapshot = snapshot.as_ref();
+        if snapshot.is_empty() {
+            return Err(crate::error::TalosError::Validation(
+                "etcd snapshot is empty".to_string(),
+            ));
         }
 
-        Ok(EtcdSnapshotResponse::new(data))
+        let chunks = Rechunk::new(
+            tonic::codegen::tokio_stream::once(bytes::Bytes::copy_from_slice(snapshot)),
+            DEFAULT_UPLOAD_CHUNK_SIZE,
+        );
+        let (upload, _) = UploadStream::new(chunks);
+
+        let mut client = self.machine();
+        let response = client.etcd_recover(self.make_request(upload)).await?;
+
+        Ok(EtcdRecoverResponse::from(response.into_inner()))
     }
 
     // =========================================================================
@@ -1175,21 +2976,209 @@ impl TalosClient {
     /// # }
     /// ```
     pub async fn events(&self, request: EventsRequest) -> Result<Vec<Event>> {
+        let stream = self.events_stream(request).await?;
+        tokio::pin!(stream);
+
+        let timer = self.stream_timer("Events");
+        let mut events = Vec::new();
+        while let Some(event) = timer.next(&mut stream).await? {
+            events.push(event?);
+        }
+
+        Ok(events)
+    }
+
+    /// Subscribe to Talos cluster events, yielding them as they happen.
+    ///
+    /// The stream replays the history selected by `request` and then stays
+    /// open for new events until it is dropped, which makes it suitable for
+    /// watching an upgrade or reset complete. A failure mid-stream is
+    /// yielded as an `Err` item before the stream ends.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::resources::{EventsRequest, MachineEventKind};
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let stream = client.events_stream(EventsRequest::new()).await?;
+    /// tokio::pin!(stream);
+    /// while let Some(event) = stream.next().await {
+    ///     if let Some(MachineEventKind::Sequence { sequence, action, .. }) = event?.kind() {
+    ///         println!("{sequence}: {action:?}");
+    ///     }
+    /// }
+    /// ```
+    pub async fn events_stream(
+        &self,
+        request: EventsRequest,
+    ) -> Result<impl Stream<Item = Result<Event>>> {
         use tonic::codegen::tokio_stream::StreamExt;
 
-        let mut client = MachineServiceClient::new(self.channel.clone());
+        let mut client = self.machine();
 
         let proto_request: ProtoEventsRequest = request.into();
-        let response = client.events(proto_request).await?;
-        let mut stream = response.into_inner();
+        let response = client.events(self.make_request(proto_request)).await?;
 
-        let mut events = Vec::new();
-        while let Some(event) = stream.next().await {
-            let event = event?;
-            events.push(Event::from(event));
+        let events = response.into_inner().map(|event| Ok(Event::from(event?)));
+        Ok(self.idle_timeout("Events", events))
+    }
+
+    // =========================================================================
+    // Cluster
+    // =========================================================================
+
+    /// Run the server-side cluster health check, yielding progress as the
+    /// node checks etcd, the Kubernetes API server, kubelets and node
+    /// readiness.
+    ///
+    /// This is the check behind `talosctl health`. The stream ends once the
+    /// cluster is healthy; if it is not healthy within
+    /// [`wait_timeout`](ClusterHealthCheckRequest::wait_timeout) the node
+    /// reports the failing check as an `Err` item. Target a single node:
+    /// the check runs on whichever node receives it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::resources::ClusterHealthCheckRequest;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let request = ClusterHealthCheckRequest::new()
+    ///     .with_control_plane_nodes(["10.0.0.2"])
+    ///     .with_worker_nodes(["10.0.0.10", "10.0.0.11"]);
+    /// let stream = client.cluster_health_check(request).await?;
+    /// tokio::pin!(stream);
+    /// while let Some(progress) = stream.next().await {
+    ///     println!("{}", progress?.message);
+    /// }
+    /// ```
+    pub async fn cluster_health_check(
+        &self,
+        request: ClusterHealthCheckRequest,
+    ) -> Result<impl Stream<Item = Result<HealthCheckProgress>>> {
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let mut client = self.cluster();
+
+        let proto_request: ProtoHealthCheckRequest = request.into();
+        let response = client
+            .health_check(self.make_request(proto_request))
+            .await?;
+
+        let progress = response
+            .into_inner()
+            .map(|progress| Ok(HealthCheckProgress::from(progress?)));
+        Ok(self.idle_timeout("HealthCheck", progress))
+    }
+
+    // =========================================================================
+    // Resources (COSI)
+    // =========================================================================
+
+    /// Get a single COSI resource, like `talosctl get <type> <id>`.
+    ///
+    /// Returns `TalosError::Api` with `NotFound` if the resource doesn't
+    /// exist.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let status = client
+    ///     .resource_get("runtime", "MachineStatuses.runtime.talos.dev", "machine")
+    ///     .await?;
+    /// println!("stage: {:?}", status.spec["stage"]);
+    /// ```
+    pub async fn resource_get(
+        &self,
+        namespace: &str,
+        resource_type: &str,
+        id: &str,
+    ) -> Result<CosiResource> {
+        let mut client = self.cosi();
+
+        let request = ProtoCosiGetRequest {
+            namespace: namespace.to_string(),
+            r#type: resource_type.to_string(),
+            id: id.to_string(),
+            options: None,
+        };
+        let response = client.get(self.make_request(request)).await?.into_inner();
+
+        let resource = response.resource.ok_or_else(|| {
+            crate::error::TalosError::Unknown(format!(
+                "Get {}/{} returned no resource",
+                resource_type, id
+            ))
+        })?;
+        CosiResource::try_from(resource)
+    }
+
+    /// List all COSI resources of a type, like `talosctl get <type>`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for link in client.resource_list("network", "LinkStatuses.net.talos.dev").await? {
+    ///     println!("{}: {:?}", link.metadata.id, link.spec["operationalState"]);
+    /// }
+    /// ```
+    pub async fn resource_list(
+        &self,
+        namespace: &str,
+        resource_type: &str,
+    ) -> Result<Vec<CosiResource>> {
+        let mut client = self.cosi();
+
+        let request = ProtoCosiListRequest {
+            namespace: namespace.to_string(),
+            r#type: resource_type.to_string(),
+            options: None,
+        };
+        let mut stream = client.list(self.make_request(request)).await?.into_inner();
+
+        let timer = self.stream_timer("List");
+        let mut resources = Vec::new();
+        while let Some(response) = timer.next(&mut stream).await? {
+            if let Some(resource) = response?.resource {
+                resources.push(CosiResource::try_from(resource)?);
+            }
         }
 
-        Ok(events)
+        Ok(resources)
+    }
+
+    /// Watch COSI resources for changes, like `talosctl get <type> --watch`.
+    ///
+    /// The stream runs until the server ends it or it is dropped. An
+    /// [`Errored`](crate::resources::CosiEventType::Errored) event carries
+    /// the server's error and is followed by the end of the stream.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::resources::CosiWatchRequest;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let request = CosiWatchRequest::new("cluster", "Members.cluster.talos.dev")
+    ///     .with_bootstrap_contents(true);
+    /// let stream = client.resource_watch(request).await?;
+    /// tokio::pin!(stream);
+    /// while let Some(event) = stream.next().await {
+    ///     let event = event?;
+    ///     println!("{:?} {:?}", event.event_type, event.resource.map(|r| r.metadata.id));
+    /// }
+    /// ```
+    pub async fn resource_watch(
+        &self,
+        request: CosiWatchRequest,
+    ) -> Result<impl Stream<Item = Result<CosiEvent>>> {
+        let mut client = self.cosi();
+
+        let proto_request: ProtoCosiWatchRequest = request.into();
+        let response = client.watch(self.make_request(proto_request)).await?;
+
+        Ok(self.idle_timeout("Watch", WatchEvents::new(response.into_inner())))
     }
 
     // =========================================================================
@@ -1215,30 +3204,52 @@ impl TalosClient {
     /// # }
     /// ```
     pub async fn dmesg(&self, request: DmesgRequest) -> Result<DmesgResponse> {
-        use tonic::codegen::tokio_stream::StreamExt;
-
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let proto_request: ProtoDmesgRequest = request.into();
-        let response = client.dmesg(proto_request).await?;
-        let mut stream = response.into_inner();
+        let stream = self.dmesg_stream(request).await?;
+        tokio::pin!(stream);
 
+        let timer = self.stream_timer("Dmesg");
         let mut data = Vec::new();
         let mut node = None;
 
-        while let Some(chunk) = stream.next().await {
+        while let Some(chunk) = timer.next(&mut stream).await? {
             let chunk = chunk?;
-            if node.is_none() {
-                if let Some(metadata) = &chunk.metadata {
-                    node = Some(metadata.hostname.clone());
-                }
-            }
-            data.extend(chunk.bytes);
+            node = node.or(chunk.node);
+            self.check_buffer_limit("Dmesg", data.len() + chunk.bytes.len())?;
+            data.extend_from_slice(&chunk.bytes);
         }
 
         Ok(DmesgResponse::new(data, node))
     }
 
+    /// Get kernel messages, yielding chunks as they arrive.
+    ///
+    /// Unlike [`dmesg`](Self::dmesg) nothing is buffered, so this suits
+    /// [`DmesgRequest::follow`] on busy nodes. A failure mid-stream is
+    /// yielded as an `Err` item before the stream ends.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let stream = client.dmesg_stream(DmesgRequest::follow()).await?;
+    /// tokio::pin!(stream);
+    /// while let Some(chunk) = stream.next().await {
+    ///     print!("{}", chunk?.as_string_lossy());
+    /// }
+    /// ```
+    pub async fn dmesg_stream(
+        &self,
+        request: DmesgRequest,
+    ) -> Result<impl Stream<Item = Result<DmesgChunk>>> {
+        let mut client = self.machine();
+
+        let proto_request: ProtoDmesgRequest = request.into();
+        let response = client.dmesg(self.make_request(proto_request)).await?;
+
+        Ok(self.idle_timeout("Dmesg", map_data_stream(response.into_inner())))
+    }
+
     // =========================================================================
     // Upgrade
     // =========================================================================
@@ -1270,154 +3281,578 @@ impl TalosClient {
     /// # }
     /// ```
     pub async fn upgrade(&self, request: UpgradeRequest) -> Result<UpgradeResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
         let proto_request: ProtoUpgradeRequest = request.into();
-        let response = client.upgrade(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("Upgrade", |mut client| {
+                let request = proto_request.clone();
+                async move { client.upgrade(request).await }
+            })
+            .await?;
 
         Ok(UpgradeResponse::from(inner))
     }
 
+    /// Upgrade a whole cluster, one group of nodes at a time.
+    ///
+    /// Visits the nodes of `options` through this client's endpoint (via
+    /// node targeting), workers first by default:
+    ///
+    /// - Workers are upgraded in batches of `worker_concurrency`.
+    /// - Control-plane nodes are upgraded one at a time. etcd must be
+    ///   healthy (every member answering, no errors, one agreed leader)
+    ///   before and after each one.
+    ///
+    /// A node counts as upgraded once it reports the image's version tag,
+    /// within `node_timeout`. After a failure the remaining nodes are
+    /// skipped unless `continue_on_failure` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` for a staged request (staged upgrades
+    /// only apply on the next reboot), an image without a version tag, a
+    /// `worker_concurrency` of 0, or a node listed in both groups. Node
+    /// failures are reported in the [`RollingUpgradeReport`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let options = RollingUpgradeOptions::new(["10.0.0.1", "10.0.0.2"], ["10.0.0.11"])
+    ///     .with_worker_concurrency(2);
+    /// let report = client
+    ///     .rolling_upgrade(UpgradeRequest::new("ghcr.io/siderolabs/installer:v1.7.0"), options)
+    ///     .await?;
+    /// for node in report.failed() {
+    ///     eprintln!("{}: {:?}", node.node, node.status);
+    /// }
+    /// ```
+    pub async fn rolling_upgrade(
+        &self,
+        request: UpgradeRequest,
+        options: RollingUpgradeOptions,
+    ) -> Result<RollingUpgradeReport> {
+        let invalid = |message: String| Err(crate::error::TalosError::Validation(message));
+        if request.stage {
+            return invalid("rolling upgrades cannot be staged".to_string());
+        }
+        if options.worker_concurrency == 0 {
+            return invalid("worker_concurrency must be at least 1".to_string());
+        }
+        let Some(tag) = request.image_tag().map(str::to_string) else {
+            return invalid(format!("image {} has no version tag", request.image));
+        };
+        if let Some(node) = options
+            .workers
+            .iter()
+            .find(|node| options.control_plane.contains(node))
+        {
+            return invalid(format!(
+                "node {} is listed as both control plane and worker",
+                node
+            ));
+        }
+
+        let groups = match options.order {
+            RollingUpgradeOrder::WorkersFirst => [NodeRole::Worker, NodeRole::ControlPlane],
+            RollingUpgradeOrder::ControlPlaneFirst => [NodeRole::ControlPlane, NodeRole::Worker],
+        };
+        let mut report = RollingUpgradeReport::default();
+        for role in groups {
+            if role == NodeRole::ControlPlane {
+                self.upgrade_control_plane(&request, &tag, &options, &mut report)
+                    .await;
+            } else {
+                self.upgrade_workers(&request, &tag, &options, &mut report)
+                    .await;
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Upgrade the control-plane nodes of a rolling upgrade, one at a time.
+    async fn upgrade_control_plane(
+        &self,
+        request: &UpgradeRequest,
+        tag: &str,
+        options: &RollingUpgradeOptions,
+        report: &mut RollingUpgradeReport,
+    ) {
+        for node in &options.control_plane {
+            let status = if let Some(reason) = upgrade_halt_reason(report, options) {
+                NodeUpgradeStatus::Skipped { reason }
+            } else if let Err(e) = self
+                .wait_for_etcd_healthy(&options.control_plane, options)
+                .await
+            {
+                NodeUpgradeStatus::Failed {
+                    error: format!("etcd is not healthy: {}", e),
+                }
+            } else {
+                let target = self.with_node(NodeTarget::single(node.clone()));
+                match target.upgrade_and_wait(request, tag, options).await {
+                    NodeUpgradeStatus::Upgraded { version } => match self
+                        .wait_for_etcd_healthy(&options.control_plane, options)
+                        .await
+                    {
+                        Ok(()) => NodeUpgradeStatus::Upgraded { version },
+                        Err(e) => NodeUpgradeStatus::Failed {
+                            error: format!(
+                                "upgraded to {}, but etcd did not recover: {}",
+                                version, e
+                            ),
+                        },
+                    },
+                    other => other,
+                }
+            };
+            report.nodes.push(NodeUpgradeOutcome {
+                node: node.clone(),
+                role: NodeRole::ControlPlane,
+                status,
+            });
+        }
+    }
+
+    /// Upgrade the workers of a rolling upgrade, `worker_concurrency` at a time.
+    async fn upgrade_workers(
+        &self,
+        request: &UpgradeRequest,
+        tag: &str,
+        options: &RollingUpgradeOptions,
+        report: &mut RollingUpgradeReport,
+    ) {
+        for batch in options.workers.chunks(options.worker_concurrency) {
+            let mut statuses: Vec<Option<NodeUpgradeStatus>> = vec![None; batch.len()];
+
+            if let Some(reason) = upgrade_halt_reason(report, options) {
+                statuses.fill(Some(NodeUpgradeStatus::Skipped { reason }));
+            } else {
+                let mut upgrades = tokio::task::JoinSet::new();
+                for (i, node) in batch.iter().enumerate() {
+                    let target = self.with_node(NodeTarget::single(node.clone()));
+                    let (request, tag, options) =
+                        (request.clone(), tag.to_string(), options.clone());
+                    upgrades.spawn(async move {
+                        (i, target.upgrade_and_wait(&request, &tag, &options).await)
+                    });
+                }
+                // A panicked task leaves only its own slot empty; the
+                // remaining upgrades keep running to completion.
+                while let Some(joined) = upgrades.join_next().await {
+                    if let Ok((i, status)) = joined {
+                        statuses[i] = Some(status);
+                    }
+                }
+            }
+
+            for (node, status) in batch.iter().zip(statuses) {
+                report.nodes.push(NodeUpgradeOutcome {
+                    node: node.clone(),
+                    role: NodeRole::Worker,
+                    status: status.unwrap_or_else(|| NodeUpgradeStatus::Failed {
+                        error: "upgrade task panicked".to_string(),
+                    }),
+                });
+            }
+        }
+    }
+
+    /// Upgrade this client's target node and wait until it runs `tag`.
+    async fn upgrade_and_wait(
+        &self,
+        request: &UpgradeRequest,
+        tag: &str,
+        options: &RollingUpgradeOptions,
+    ) -> NodeUpgradeStatus {
+        if let Err(e) = self.upgrade(request.clone()).await {
+            return NodeUpgradeStatus::Failed {
+                error: e.to_string(),
+            };
+        }
+
+        let deadline = tokio::time::Instant::now() + options.node_timeout;
+        loop {
+            tokio::time::sleep(options.poll_interval).await;
+            let last = match self.server_version().await {
+                Ok(response) => match response.first() {
+                    Some(result) if result.tag == tag => {
+                        return NodeUpgradeStatus::Upgraded {
+                            version: result.tag.clone(),
+                        }
+                    }
+                    Some(result) => format!("still running {}", result.tag),
+                    None => "no version reported".to_string(),
+                },
+                Err(e) => e.to_string(),
+            };
+            if tokio::time::Instant::now() >= deadline {
+                return NodeUpgradeStatus::Failed {
+                    error: format!(
+                        "not running {} after {:?}: {}",
+                        tag, options.node_timeout, last
+                    ),
+                };
+            }
+        }
+    }
+
+    /// Poll etcd on `control_plane` until it is healthy or `node_timeout` passes.
+    async fn wait_for_etcd_healthy(
+        &self,
+        control_plane: &[String],
+        options: &RollingUpgradeOptions,
+    ) -> Result<()> {
+        let cluster = self.with_nodes(control_plane);
+        let deadline = tokio::time::Instant::now() + options.node_timeout;
+        loop {
+            let problem = match cluster.etcd_status().await {
+                Ok(status) => match etcd_health_problem(&status, control_plane.len()) {
+                    None => return Ok(()),
+                    Some(problem) => problem,
+                },
+                Err(e) => e.to_string(),
+            };
+            if tokio::time::Instant::now() + options.poll_interval > deadline {
+                return Err(crate::error::TalosError::Timeout(format!(
+                    "etcd not healthy after {:?}: {}",
+                    options.node_timeout, problem
+                )));
+            }
+            tokio::time::sleep(options.poll_interval).await;
+        }
+    }
+
     // =========================================================================
     // Service Management
     // =========================================================================
 
+    /// List the services on each targeted node.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let services = client.service_list().await?;
+    /// for (node, service) in services.unhealthy() {
+    ///     println!("{:?}: {} is unhealthy: {}", node, service.id, service.health_last_message);
+    /// }
+    /// ```
+    pub async fn service_list(&self) -> Result<ServiceListResponse> {
+        let inner = self
+            .unary_with_retry("ServiceList", |mut client| {
+                let request = self.make_request(());
+                async move { client.service_list(request).await }
+            })
+            .await?;
+
+        Ok(ServiceListResponse::from(inner))
+    }
+
     /// Start a service.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` for an empty service id, and
+    /// `TalosError::NotFound` if the node has no such service.
     pub async fn service_start(
         &self,
         request: ServiceStartRequest,
     ) -> Result<ServiceStartResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
+        request.validate()?;
+        let id = request.id.clone();
         let proto_request: ProtoServiceStartRequest = request.into();
-        let response = client.service_start(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("ServiceStart", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.service_start(request).await }
+            })
+            .await
+            .map_err(|e| service_not_found(&id, e))?;
 
         Ok(ServiceStartResponse::from(inner))
     }
 
     /// Stop a service.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` for an empty service id, and
+    /// `TalosError::NotFound` if the node has no such service.
     pub async fn service_stop(&self, request: ServiceStopRequest) -> Result<ServiceStopResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
+        request.validate()?;
+        let id = request.id.clone();
         let proto_request: ProtoServiceStopRequest = request.into();
-        let response = client.service_stop(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("ServiceStop", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.service_stop(request).await }
+            })
+            .await
+            .map_err(|e| service_not_found(&id, e))?;
 
         Ok(ServiceStopResponse::from(inner))
     }
 
     /// Restart a service.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` for an empty service id, and
+    /// `TalosError::NotFound` if the node has no such service.
     pub async fn service_restart(
         &self,
         request: ServiceRestartRequest,
     ) -> Result<ServiceRestartResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
+        request.validate()?;
+        let id = request.id.clone();
         let proto_request: ProtoServiceRestartRequest = request.into();
-        let response = client.service_restart(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("ServiceRestart", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.service_restart(request).await }
+            })
+            .await
+            .map_err(|e| service_not_found(&id, e))?;
 
         Ok(ServiceRestartResponse::from(inner))
     }
 
-    /// Get service/container logs (server-streaming).
-    pub async fn logs(&self, request: LogsRequest) -> Result<LogsResponse> {
-        use tonic::codegen::tokio_stream::StreamExt;
+    /// Start a service after checking it exists via `ServiceList`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::NotFound` without sending the start if the
+    /// service is not listed.
+    pub async fn service_start_validated(
+        &self,
+        request: ServiceStartRequest,
+    ) -> Result<ServiceStartResponse> {
+        request.validate()?;
+        self.ensure_service_exists(&request.id).await?;
+        self.service_start(request).await
+    }
 
-        let mut client = MachineServiceClient::new(self.channel.clone());
+    /// Stop a service after checking it exists via `ServiceList`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::NotFound` without sending the stop if the
+    /// service is not listed.
+    pub async fn service_stop_validated(
+        &self,
+        request: ServiceStopRequest,
+    ) -> Result<ServiceStopResponse> {
+        request.validate()?;
+        self.ensure_service_exists(&request.id).await?;
+        self.service_stop(request).await
+    }
 
-        let proto_request: ProtoLogsRequest = request.into();
-        let response = client.logs(proto_request).await?;
-        let mut stream = response.into_inner();
+    /// Restart a service after checking it exists via `ServiceList`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::NotFound` without sending the restart if the
+    /// service is not listed.
+    pub async fn service_restart_validated(
+        &self,
+        request: ServiceRestartRequest,
+    ) -> Result<ServiceRestartResponse> {
+        request.validate()?;
+        self.ensure_service_exists(&request.id).await?;
+        self.service_restart(request).await
+    }
+
+    /// Fail with `TalosError::NotFound` unless every targeted node lists `id`.
+    async fn ensure_service_exists(&self, id: &str) -> Result<()> {
+        let list = self.service_list().await?;
+
+        for node in &list.results {
+            if node.find(id).is_none() {
+                return Err(crate::error::TalosError::NotFound(
+                    match node.node.as_deref() {
+                        Some(hostname) if !hostname.is_empty() => {
+                            format!("service {:?} on node {}", id, hostname)
+                        }
+                        _ => format!("service {:?}", id),
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get service/container logs, buffered into a single response.
+    ///
+    /// Use [`logs_stream`](Self::logs_stream) with
+    /// [`LogsRequestBuilder::follow`](crate::resources::LogsRequestBuilder::follow)
+    /// to tail logs live.
+    pub async fn logs(&self, request: LogsRequest) -> Result<LogsResponse> {
+        let stream = self.logs_stream(request).await?;
+        tokio::pin!(stream);
 
+        let timer = self.stream_timer("Logs");
         let mut data = Vec::new();
         let mut node = None;
 
-        while let Some(chunk) = stream.next().await {
+        while let Some(chunk) = timer.next(&mut stream).await? {
             let chunk = chunk?;
-            if node.is_none() {
-                if let Some(metadata) = &chunk.metadata {
-                    node = Some(metadata.hostname.clone());
-                }
-            }
-            data.extend(chunk.bytes);
+            node = node.or(chunk.node);
+            self.check_buffer_limit("Logs", data.len() + chunk.bytes.len())?;
+            data.extend_from_slice(&chunk.bytes);
         }
 
         Ok(LogsResponse::new(data, node))
     }
 
+    /// Get service/container logs, yielding chunks as they arrive.
+    ///
+    /// With `follow` set the stream stays open and yields new output until
+    /// it is dropped. A failure mid-stream is yielded as an `Err` item
+    /// before the stream ends.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let request = LogsRequest::builder("kubelet").follow(true).tail(100).build();
+    /// let stream = client.logs_stream(request).await?;
+    /// tokio::pin!(stream);
+    /// while let Some(chunk) = stream.next().await {
+    ///     print!("{}", chunk?.as_string_lossy());
+    /// }
+    /// ```
+    pub async fn logs_stream(
+        &self,
+        request: LogsRequest,
+    ) -> Result<impl Stream<Item = Result<LogChunk>>> {
+        let mut client = self.machine();
+
+        let proto_request: ProtoLogsRequest = request.into();
+        let response = client.logs(self.make_request(proto_request)).await?;
+
+        Ok(self.idle_timeout("Logs", map_data_stream(response.into_inner())))
+    }
+
     // =========================================================================
     // System Information
     // =========================================================================
 
+    /// Get the Talos version of each targeted node.
+    ///
+    /// Unlike [`version`](Self::version), which returns the raw
+    /// `VersionService` client, this reports build details per node; see
+    /// [`VersionResult::semver`](crate::resources::VersionResult::semver).
+    pub async fn server_version(&self) -> Result<VersionResponse> {
+        let inner = self
+            .unary_with_retry(
+                "Version",
+                |mut client| async move { client.version(()).await },
+            )
+            .await?;
+
+        Ok(VersionResponse::from(inner))
+    }
+
+    /// Get the hostname of each targeted node.
+    pub async fn hostname(&self) -> Result<HostnameResponse> {
+        let inner = self
+            .unary_with_retry(
+                "Hostname",
+                |mut client| async move { client.hostname(()).await },
+            )
+            .await?;
+
+        Ok(HostnameResponse::from(inner))
+    }
+
     /// Get system load averages.
     pub async fn load_avg(&self) -> Result<LoadAvgResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.load_avg(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry(
+                "LoadAvg",
+                |mut client| async move { client.load_avg(()).await },
+            )
+            .await?;
 
         Ok(LoadAvgResponse::from(inner))
     }
 
     /// Get memory information.
     pub async fn memory(&self) -> Result<MemoryResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.memory(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry(
+                "Memory",
+                |mut client| async move { client.memory(()).await },
+            )
+            .await?;
 
         Ok(MemoryResponse::from(inner))
     }
 
+    /// Get kernel statistics (`/proc/stat`).
+    pub async fn system_stat(&self) -> Result<SystemStatResponse> {
+        let inner = self
+            .unary_with_retry("SystemStat", |mut client| async move {
+                client.system_stat(()).await
+            })
+            .await?;
+
+        Ok(SystemStatResponse::from(inner))
+    }
+
     /// Get CPU information.
     pub async fn cpu_info(&self) -> Result<CpuInfoResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.cpu_info(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry(
+                "CPUInfo",
+                |mut client| async move { client.cpu_info(()).await },
+            )
+            .await?;
 
         Ok(CpuInfoResponse::from(inner))
     }
 
     /// Get disk statistics.
     pub async fn disk_stats(&self) -> Result<DiskStatsResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.disk_stats(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("DiskStats", |mut client| async move {
+                client.disk_stats(()).await
+            })
+            .await?;
 
         Ok(DiskStatsResponse::from(inner))
     }
 
     /// Get network device statistics.
     pub async fn network_device_stats(&self) -> Result<NetworkDeviceStatsResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.network_device_stats(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("NetworkDeviceStats", |mut client| async move {
+                client.network_device_stats(()).await
+            })
+            .await?;
 
         Ok(NetworkDeviceStatsResponse::from(inner))
     }
 
     /// Get mount points.
     pub async fn mounts(&self) -> Result<MountsResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.mounts(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry(
+                "Mounts",
+                |mut client| async move { client.mounts(()).await },
+            )
+            .await?;
 
         Ok(MountsResponse::from(inner))
     }
 
     /// Get process list.
     pub async fn processes(&self) -> Result<ProcessesResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.processes(()).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("Processes", |mut client| async move {
+                client.processes(()).await
+            })
+            .await?;
 
         Ok(ProcessesResponse::from(inner))
     }
@@ -1428,79 +3863,349 @@ impl TalosClient {
 
     /// List directory contents (server-streaming).
     pub async fn list(&self, request: ListRequest) -> Result<ListResponse> {
-        use tonic::codegen::tokio_stream::StreamExt;
-
+        let timer = self.stream_timer("List");
         let mut client = MachineServiceClient::new(self.channel.clone());
 
+        let requested_path = request.root.clone();
         let proto_request: ProtoListRequest = request.into();
         let response = client.list(proto_request).await?;
         let mut stream = response.into_inner();
 
         let mut entries = Vec::new();
-        while let Some(info) = stream.next().await {
+        while let Some(info) = timer.next(&mut stream).await? {
             let info = info?;
             entries.push(FileInfo::from(info));
         }
 
-        Ok(ListResponse::new(entries))
+        Ok(ListResponse::new(entries).with_requested_path(requested_path))
     }
 
     /// Read a file (server-streaming).
+    ///
+    /// The whole file is buffered in memory, up to `max_buffer_bytes`; use
+    /// [`read_stream`](Self::read_stream) or
+    /// [`read_to_writer`](Self::read_to_writer) for large files.
     pub async fn read(&self, request: ReadRequest) -> Result<ReadResponse> {
+        let mut client = MachineServiceClient::new(self.channel.clone());
+
+        let requested_path = request.path.clone();
+        let expected_sha256 = request.expected_sha256;
+        let proto_request: ProtoReadRequest = request.into();
+        let response = client.read(proto_request).await?;
+        let stream = response.into_inner();
+
+        let mut digest = expected_sha256.map(|_| ring::digest::Context::new(&ring::digest::SHA256));
+        let (data, node) = self
+            .collect_data_with("Read", stream, |chunk| {
+                if let Some(digest) = digest.as_mut() {
+                    digest.update(chunk);
+                }
+            })
+            .await?;
+
+        if let (Some(expected), Some(digest)) = (expected_sha256, digest) {
+            verify_sha256(&requested_path, &expected, digest)?;
+        }
+
+        Ok(ReadResponse::new(data, node).with_requested_path(requested_path))
+    }
+
+    /// Read a file, yielding chunks as they arrive.
+    ///
+    /// Unlike [`read`](Self::read) nothing is buffered, so large logs or
+    /// images can be processed without holding them in memory. An error
+    /// surfaced mid-stream (e.g. permission denied) is yielded as an `Err`
+    /// item. `expected_sha256` is not checked here; use
+    /// [`read_to_writer`](Self::read_to_writer) for that.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let stream = client.read_stream(ReadRequest::new("/var/log/audit/kube/kube-apiserver.log")).await?;
+    /// tokio::pin!(stream);
+    /// while let Some(chunk) = stream.next().await {
+    ///     process(&chunk?);
+    /// }
+    /// ```
+    pub async fn read_stream(
+        &self,
+        request: ReadRequest,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
         use tonic::codegen::tokio_stream::StreamExt;
 
+        let mut client = self.machine();
+
+        let proto_request: ProtoReadRequest = request.into();
+        let response = client.read(self.make_request(proto_request)).await?;
+
+        let chunks = self.idle_timeout("Read", map_data_stream(response.into_inner()));
+        Ok(chunks.map(|chunk| chunk.map(|c| c.bytes)))
+    }
+
+    /// Read a file into `writer` chunk by chunk, returning the bytes written.
+    ///
+    /// The file is never held in memory as a whole. If the request sets
+    /// `expected_sha256`, the digest is checked once the stream ends; the
+    /// writer has received the data by then, so discard it on error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first stream error, `TalosError::Config` if writing
+    /// fails, or `TalosError::Validation` on a checksum mismatch.
+    pub async fn read_to_writer<W>(&self, request: ReadRequest, writer: W) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let requested_path = request.path.clone();
+        let expected_sha256 = request.expected_sha256;
+        let write_error = |e: std::io::Error| {
+            crate::error::TalosError::Config(format!("Failed to write {}: {}", requested_path, e))
+        };
+
+        let stream = self.read_stream(request).await?;
+        tokio::pin!(stream);
+        let mut writer = writer;
+        let mut digest = expected_sha256.map(|_| ring::digest::Context::new(&ring::digest::SHA256));
+        let mut written = 0_u64;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            if let Some(digest) = digest.as_mut() {
+                digest.update(&chunk);
+            }
+            writer.write_all(&chunk).await.map_err(write_error)?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await.map_err(write_error)?;
+
+        if let (Some(expected), Some(digest)) = (expected_sha256, digest) {
+            verify_sha256(&requested_path, &expected, digest)?;
+        }
+
+        Ok(written)
+    }
+
+    /// Read every file matching a glob pattern such as `/etc/cri/conf.d/*.toml`.
+    ///
+    /// Lists the deepest directory without wildcards (recursing as deep as
+    /// the pattern reaches), filters the entries with the [`glob`] pattern
+    /// (`*` does not cross `/`), then reads the matching files concurrently.
+    /// Directories are skipped. Returns a map from path to content, which is
+    /// empty if nothing matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the pattern is not an absolute,
+    /// valid glob, or the first error from listing or reading a match.
+    pub async fn read_glob(&self, pattern: &str) -> Result<HashMap<String, Vec<u8>>> {
+        let (root, depth) = glob_root(pattern)?;
+        let matcher = glob::Pattern::new(pattern).map_err(|e| {
+            crate::error::TalosError::Validation(format!(
+                "invalid glob pattern {:?}: {}",
+                pattern, e
+            ))
+        })?;
+        let options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+
+        let listing = self
+            .list(
+                ListRequest::builder(root)
+                    .recurse(depth > 1)
+                    .recursion_depth(if depth > 1 { depth } else { 0 })
+                    .build(),
+            )
+            .await?;
+        let mut pending: Vec<String> = listing
+            .entries
+            .into_iter()
+            .filter(|entry| !entry.is_dir && entry.error.is_none())
+            .map(|entry| entry.name)
+            .filter(|name| matcher.matches_with(name, options))
+            .collect();
+
+        let mut files = HashMap::with_capacity(pending.len());
+        let mut reads = tokio::task::JoinSet::new();
+        loop {
+            while reads.len() < READ_GLOB_CONCURRENCY {
+                let Some(path) = pending.pop() else { break };
+                let client = self.clone();
+                reads.spawn(async move {
+                    let response = client.read(ReadRequest::new(path.clone())).await;
+                    (path, response)
+                });
+            }
+            let Some(joined) = reads.join_next().await else {
+                break;
+            };
+            let (path, response) = joined.map_err(|e| {
+                crate::error::TalosError::Unknown(format!("read task failed: {}", e))
+            })?;
+            files.insert(path, response?.data);
+        }
+
+        Ok(files)
+    }
+
+    /// Copy a file or directory as tar archive (server-streaming).
+    ///
+    /// The archive is a standard gzip-compressed tar (`.tar.gz`), as
+    /// extracted by `talosctl cp` or `tar -xzf`. It is buffered in memory, up
+    /// to `max_buffer_bytes`; use [`copy_to_writer`](Self::copy_to_writer)
+    /// or [`copy_stream`](Self::copy_stream) for large trees.
+    pub async fn copy(&self, request: CopyRequest) -> Result<CopyResponse> {
         let mut client = MachineServiceClient::new(self.channel.clone());
 
-        let proto_request: ProtoReadRequest = request.into();
-        let response = client.read(proto_request).await?;
-        let mut stream = response.into_inner();
+        let proto_request: ProtoCopyRequest = request.into();
+        let response = client.copy(proto_request).await?;
+        let stream = response.into_inner();
+
+        let (data, node) = self.collect_data("Copy", stream).await?;
+
+        Ok(CopyResponse::new(data, node))
+    }
+
+    /// Copy a file or directory, yielding the archive bytes as they arrive.
+    ///
+    /// Chunks are raw slices of the `.tar.gz` stream described in
+    /// [`copy`](Self::copy) and need not align with tar entries. An error
+    /// surfaced mid-stream is yielded as an `Err` item; the archive is
+    /// truncated in that case.
+    pub async fn copy_stream(
+        &self,
+        request: CopyRequest,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let mut client = self.machine();
+
+        let proto_request: ProtoCopyRequest = request.into();
+        let response = client.copy(self.make_request(proto_request)).await?;
+
+        let chunks = self.idle_timeout("Copy", map_data_stream(response.into_inner()));
+        Ok(chunks.map(|chunk| chunk.map(|c| c.bytes)))
+    }
+
+    /// Copy a file or directory into `writer` as a `.tar.gz` archive,
+    /// returning the bytes written.
+    ///
+    /// The archive is never held in memory as a whole, so whole trees such
+    /// as `/var` can be saved to a file or piped to another process.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let file = tokio::fs::File::create("var-log.tar.gz").await?;
+    /// client.copy_to_writer(CopyRequest::new("/var/log"), file).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first stream error, or `TalosError::Config` if writing
+    /// fails.
+    pub async fn copy_to_writer<W>(&self, request: CopyRequest, writer: W) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let root_path = request.root_path.clone();
+        let write_error = |e: std::io::Error| {
+            crate::error::TalosError::Config(format!(
+                "Failed to write archive of {}: {}",
+                root_path, e
+            ))
+        };
 
-        let mut data = Vec::new();
-        let mut node = None;
+        let stream = self.copy_stream(request).await?;
+        tokio::pin!(stream);
+        let mut writer = writer;
+        let mut written = 0_u64;
 
         while let Some(chunk) = stream.next().await {
             let chunk = chunk?;
-            if node.is_none() {
-                if let Some(metadata) = &chunk.metadata {
-                    node = Some(metadata.hostname.clone());
-                }
-            }
-            data.extend(chunk.bytes);
+            writer.write_all(&chunk).await.map_err(write_error)?;
+            written += chunk.len() as u64;
         }
+        writer.flush().await.map_err(write_error)?;
 
-        Ok(ReadResponse::new(data, node))
+        Ok(written)
     }
 
-    /// Copy a file or directory as tar archive (server-streaming).
-    pub async fn copy(&self, request: CopyRequest) -> Result<CopyResponse> {
+    /// Copy a file or directory and unpack it under `dest_dir` as it arrives.
+    ///
+    /// Accepts both gzip-compressed and plain tar streams. Entries that
+    /// would land outside `dest_dir` are skipped, as with
+    /// [`tar::Archive::unpack`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first stream error, or `TalosError::Config` if the
+    /// archive cannot be decoded or written.
+    #[cfg(feature = "tar")]
+    pub async fn copy_extract(
+        &self,
+        request: CopyRequest,
+        dest_dir: impl AsRef<Path>,
+    ) -> Result<()> {
         use tonic::codegen::tokio_stream::StreamExt;
 
-        let mut client = MachineServiceClient::new(self.channel.clone());
+        let dest_dir = dest_dir.as_ref().to_path_buf();
+        let stream = self.copy_stream(request).await?;
 
-        let proto_request: ProtoCopyRequest = request.into();
-        let response = client.copy(proto_request).await?;
-        let mut stream = response.into_inner();
+        // tar is synchronous: unpack on a blocking thread fed through a
+        // bounded channel, so the archive is never buffered as a whole.
+        let (tx, rx) = tokio::sync::mpsc::channel::<Bytes>(16);
+        let unpack = tokio::task::spawn_blocking(move || -> std::io::Result<()> {
+            use std::io::BufRead;
 
-        let mut data = Vec::new();
-        let mut node = None;
+            let mut reader = std::io::BufReader::new(ChunkReader::new(rx));
+            let gzip = reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+            if gzip {
+                tar::Archive::new(flate2::bufread::GzDecoder::new(reader)).unpack(&dest_dir)
+            } else {
+                tar::Archive::new(reader).unpack(&dest_dir)
+            }
+        });
 
+        tokio::pin!(stream);
+        let mut stream_result = Ok(());
         while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            if node.is_none() {
-                if let Some(metadata) = &chunk.metadata {
-                    node = Some(metadata.hostname.clone());
+            match chunk {
+                // The unpacker hung up early; its error is reported below.
+                Ok(chunk) => {
+                    if tx.send(chunk).await.is_err() {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    stream_result = Err(e);
+                    break;
                 }
             }
-            data.extend(chunk.bytes);
         }
+        drop(tx);
 
-        Ok(CopyResponse::new(data, node))
+        let unpacked = unpack.await.map_err(|e| {
+            crate::error::TalosError::Unknown(format!("archive extraction task failed: {}", e))
+        })?;
+        stream_result?;
+        unpacked.map_err(|e| {
+            crate::error::TalosError::Config(format!("Failed to extract archive: {}", e))
+        })
     }
 
     /// Get disk usage (server-streaming).
     pub async fn disk_usage(&self, request: DiskUsageRequest) -> Result<DiskUsageResponse> {
-        use tonic::codegen::tokio_stream::StreamExt;
-
+        let timer = self.stream_timer("DiskUsage");
         let mut client = MachineServiceClient::new(self.channel.clone());
 
         let proto_request: ProtoDiskUsageRequest = request.into();
@@ -1508,7 +4213,7 @@ impl TalosClient {
         let mut stream = response.into_inner();
 
         let mut entries = Vec::new();
-        while let Some(info) = stream.next().await {
+        while let Some(info) = timer.next(&mut stream).await? {
             let info = info?;
             entries.push(DiskUsageInfo::from(info));
         }
@@ -1522,64 +4227,213 @@ impl TalosClient {
 
     /// Rollback a Talos node to the previous installed version.
     pub async fn rollback(&self) -> Result<RollbackResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
-        let response = client.rollback(ProtoRollbackRequest {}).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("Rollback", |mut client| async move {
+                client.rollback(ProtoRollbackRequest {}).await
+            })
+            .await?;
 
         Ok(RollbackResponse::from(inner))
     }
 
     /// Generate client configuration (talosconfig).
+    ///
+    /// The request is checked with
+    /// [`GenerateClientConfigurationRequest::validate`] before it is sent, and
+    /// an unset certificate TTL defaults to 24 hours. Connect with the
+    /// result using [`from_generated`](Self::from_generated).
     pub async fn generate_client_configuration(
         &self,
         request: GenerateClientConfigurationRequest,
     ) -> Result<GenerateClientConfigurationResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
+        request.validate()?;
         let proto_request: ProtoGenerateClientConfigRequest = request.into();
-        let response = client.generate_client_configuration(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("GenerateClientConfiguration", |mut client| {
+                let request = proto_request.clone();
+                async move { client.generate_client_configuration(request).await }
+            })
+            .await?;
 
         Ok(GenerateClientConfigurationResponse::from(inner))
     }
 
+    /// Write `value` under `key` in the META partition of each targeted node.
+    ///
+    /// The META partition survives reinstalls and holds flags such as the
+    /// upgrade state; writing the wrong key can change how a node boots.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if `key` is above
+    /// [`MAX_META_KEY`](crate::resources::MAX_META_KEY).
+    pub async fn meta_write(&self, key: u32, value: Vec<u8>) -> Result<MetaWriteResponse> {
+        let request = MetaWriteRequest::new(key, value);
+        request.validate()?;
+        let proto_request: ProtoMetaWriteRequest = request.into();
+        let inner = self
+            .unary_with_retry("MetaWrite", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.meta_write(request).await }
+            })
+            .await?;
+
+        Ok(MetaWriteResponse::from(inner))
+    }
+
+    /// Delete `key` from the META partition of each targeted node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if `key` is above
+    /// [`MAX_META_KEY`](crate::resources::MAX_META_KEY).
+    pub async fn meta_delete(&self, key: u32) -> Result<MetaDeleteResponse> {
+        let request = MetaDeleteRequest::new(key);
+        request.validate()?;
+        let proto_request: ProtoMetaDeleteRequest = request.into();
+        let inner = self
+            .unary_with_retry("MetaDelete", |mut client| {
+                let request = self.make_request(proto_request);
+                async move { client.meta_delete(request).await }
+            })
+            .await?;
+
+        Ok(MetaDeleteResponse::from(inner))
+    }
+
     /// Capture packets on a network interface (server-streaming).
     pub async fn packet_capture(
         &self,
         request: PacketCaptureRequest,
     ) -> Result<PacketCaptureResponse> {
-        use tonic::codegen::tokio_stream::StreamExt;
-
         let mut client = MachineServiceClient::new(self.channel.clone());
 
         let proto_request: ProtoPacketCaptureRequest = request.into();
         let response = client.packet_capture(proto_request).await?;
-        let mut stream = response.into_inner();
+        let stream = response.into_inner();
 
-        let mut data = Vec::new();
-        let mut node = None;
+        let (data, node) = self.collect_data("PacketCapture", stream).await?;
 
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            if node.is_none() {
-                if let Some(metadata) = &chunk.metadata {
-                    node = Some(metadata.hostname.clone());
-                }
+        Ok(PacketCaptureResponse::new(data, node))
+    }
+
+    /// Capture packets on a network interface, yielding pcap chunks as they arrive.
+    ///
+    /// Chunks are raw slices of the pcap stream and need not align with packet
+    /// boundaries. The capture runs until the stream is dropped.
+    pub async fn packet_capture_stream(
+        &self,
+        request: PacketCaptureRequest,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let mut client = self.machine();
+
+        let proto_request: ProtoPacketCaptureRequest = request.into();
+        let response = client
+            .packet_capture(self.make_request(proto_request))
+            .await?;
+
+        let chunks = self.idle_timeout("PacketCapture", map_data_stream(response.into_inner()));
+        Ok(chunks.map(|chunk| chunk.map(|c| c.bytes)))
+    }
+
+    /// Capture packets into a pcap file at `path` until the node ends the stream.
+    ///
+    /// Returns the number of packets written. See
+    /// [`packet_capture_to_file_until`](Self::packet_capture_to_file_until).
+    pub async fn packet_capture_to_file(
+        &self,
+        request: PacketCaptureRequest,
+        path: impl AsRef<Path>,
+    ) -> Result<u64> {
+        self.packet_capture_to_file_until(request, path, std::future::pending())
+            .await
+    }
+
+    /// Capture packets into a pcap file at `path` until `stop` completes.
+    ///
+    /// Only the pcap global header and complete packet records are written,
+    /// and each batch is flushed as it arrives, so the file stays readable if
+    /// the capture is stopped, fails mid-stream, or is cancelled between
+    /// batches. If no header arrived, an empty Ethernet capture is written.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let packets = client
+    ///     .packet_capture_to_file_until(
+    ///         PacketCaptureRequest::new("eth0"),
+    ///         "eth0.pcap",
+    ///         tokio::time::sleep(Duration::from_secs(30)),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn packet_capture_to_file_until(
+        &self,
+        request: PacketCaptureRequest,
+        path: impl AsRef<Path>,
+        stop: impl std::future::Future<Output = ()>,
+    ) -> Result<u64> {
+        use tokio::io::AsyncWriteExt;
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let path = path.as_ref();
+        let write_error = |e: std::io::Error| {
+            crate::error::TalosError::Config(format!(
+                "Failed to write packet capture to {}: {}",
+                path.display(),
+                e
+            ))
+        };
+
+        let snap_len = request.snap_len;
+        let stream = self.packet_capture_stream(request).await?;
+        let mut file = tokio::fs::File::create(path).await.map_err(write_error)?;
+        let mut framer = PcapFramer::new();
+
+        tokio::pin!(stream);
+        tokio::pin!(stop);
+        let result = loop {
+            let chunk = tokio::select! {
+                biased;
+                () = &mut stop => break Ok(()),
+                chunk = stream.next() => chunk,
+            };
+            let Some(chunk) = chunk else {
+                break Ok(());
+            };
+            let frames = match chunk.and_then(|bytes| framer.push(&bytes)) {
+                Ok(frames) => frames,
+                Err(e) => break Err(e),
+            };
+            if !frames.is_empty() {
+                file.write_all(&frames).await.map_err(write_error)?;
+                file.flush().await.map_err(write_error)?;
             }
-            data.extend(chunk.bytes);
+        };
+
+        if !framer.has_header() {
+            file.write_all(&PcapFramer::empty_header(snap_len))
+                .await
+                .map_err(write_error)?;
         }
+        file.sync_all().await.map_err(write_error)?;
 
-        Ok(PacketCaptureResponse::new(data, node))
+        result.map(|()| framer.packets())
     }
 
     /// Get network connection information (netstat).
     pub async fn netstat(&self, request: NetstatRequest) -> Result<NetstatResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
-
         let proto_request: ProtoNetstatRequest = request.into();
-        let response = client.netstat(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("Netstat", |mut client| {
+                let request = proto_request.clone();
+                async move { client.netstat(request).await }
+            })
+            .await?;
 
         Ok(NetstatResponse::from(inner))
     }
@@ -1615,17 +4469,16 @@ impl TalosClient {
     /// # }
     /// ```
     pub async fn image_list(&self, request: ImageListRequest) -> Result<Vec<ImageInfo>> {
-        use tonic::codegen::tokio_stream::StreamExt;
-
-        let mut client = MachineServiceClient::new(self.channel.clone());
+        let timer = self.stream_timer("ImageList");
+        let mut client = self.machine();
         let proto_request: ProtoImageListRequest = request.into();
-        let response = client.image_list(proto_request).await?;
+        let response = client.image_list(self.make_request(proto_request)).await?;
 
         let mut stream = response.into_inner();
         let mut images = Vec::new();
 
         // Each item in the stream is a single image
-        while let Some(item) = stream.next().await {
+        while let Some(item) = timer.next(&mut stream).await? {
             let item = item?;
             // Check for errors in metadata
             if let Some(ref metadata) = item.metadata {
@@ -1647,6 +4500,11 @@ impl TalosClient {
     ///
     /// * `request` - The image pull request specifying the image reference and namespace.
     ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` without contacting the node if the
+    /// image reference is empty.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -1665,13 +4523,559 @@ impl TalosClient {
     /// # }
     /// ```
     pub async fn image_pull(&self, request: ImagePullRequest) -> Result<ImagePullResponse> {
-        let mut client = MachineServiceClient::new(self.channel.clone());
+        request.validate()?;
         let proto_request: ProtoImagePullRequest = request.into();
-        let response = client.image_pull(proto_request).await?;
-        let inner = response.into_inner();
+        let inner = self
+            .unary_with_retry("ImagePull", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.image_pull(request).await }
+            })
+            .await?;
 
         Ok(ImagePullResponse::from(inner))
     }
+
+    // =========================================================================
+    // Containers
+    // =========================================================================
+
+    /// List containers in a namespace on each targeted node.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use talos_api_rs::ContainersRequest;
+    ///
+    /// let pods = client.containers(ContainersRequest::kubernetes()).await?;
+    /// for container in &pods.results[0].containers {
+    ///     println!("{} {} [{}]", container.pod_id, container.name, container.status);
+    /// }
+    /// ```
+    pub async fn containers(&self, request: ContainersRequest) -> Result<ContainersResponse> {
+        let proto_request: ProtoContainersRequest = request.into();
+        let inner = self
+            .unary_with_retry("Containers", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.containers(request).await }
+            })
+            .await?;
+
+        Ok(ContainersResponse::from(inner))
+    }
+
+    /// Get resource usage of containers in a namespace on each targeted node.
+    pub async fn stats(&self, request: ContainersRequest) -> Result<ContainerStatsResponse> {
+        let proto_request: ProtoStatsRequest = request.into();
+        let inner = self
+            .unary_with_retry("Stats", |mut client| {
+                let request = self.make_request(proto_request.clone());
+                async move { client.stats(request).await }
+            })
+            .await?;
+
+        Ok(ContainerStatsResponse::from(inner))
+    }
+}
+
+/// Node address of an etcd member: the host of its first peer URL, or its
+/// hostname if it has none.
+fn member_node(member: &EtcdMember) -> String {
+    member
+        .peer_urls
+        .first()
+        .and_then(|url| url.parse::<http::Uri>().ok())
+        .and_then(|uri| {
+            uri.host()
+                .map(|host| host.trim_matches(['[', ']']).to_string())
+        })
+        .unwrap_or_else(|| member.hostname.clone())
+}
+
+/// Map a "no such service" answer from a service call to `TalosError::NotFound`.
+///
+/// Talos reports unknown services as `Unknown` with "service ... not defined";
+/// a plain `NotFound` status is accepted too.
+fn service_not_found(id: &str, err: crate::error::TalosError) -> crate::error::TalosError {
+    match &err {
+        crate::error::TalosError::Api(status)
+            if status.code() == tonic::Code::NotFound
+                || status.message().contains("not defined") =>
+        {
+            crate::error::TalosError::NotFound(format!("service {:?}: {}", id, status.message()))
+        }
+        _ => err,
+    }
+}
+
+/// Read and parse environment variable `name`; `None` if unset or empty.
+#[allow(clippy::result_large_err)]
+fn env_override<T>(
+    name: &str,
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> crate::error::Result<Option<T>> {
+    match std::env::var(name) {
+        Ok(value) if !value.trim().is_empty() => parse(value.trim()).map(Some).ok_or_else(|| {
+            crate::error::TalosError::Config(format!("Invalid value for {}: {:?}", name, value))
+        }),
+        _ => Ok(None),
+    }
+}
+
+/// Parse a boolean environment value.
+fn parse_env_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parse a timeout such as `60s`, `500ms`, `2m` or `30` (seconds); `off` or
+/// `none` disables it.
+fn parse_env_timeout(value: &str) -> Option<Option<Duration>> {
+    let value = value.to_ascii_lowercase();
+    if value == "off" || value == "none" {
+        return Some(None);
+    }
+
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number.parse().ok()?;
+    let duration = match unit {
+        "ms" => Duration::from_millis(number),
+        "" | "s" => Duration::from_secs(number),
+        "m" => Duration::from_secs(number.checked_mul(60)?),
+        "h" => Duration::from_secs(number.checked_mul(3600)?),
+        _ => return None,
+    };
+    Some(Some(duration))
+}
+
+/// Whether `status` reports a connection the server closed or refused to
+/// use further (HTTP/2 GOAWAY, reset or broken pipe), as opposed to an
+/// error from the API itself.
+///
+/// Only the status code and the transport errors in its source chain are
+/// considered; a server message that merely mentions a closed connection
+/// is an API error.
+fn is_broken_connection(status: &tonic::Status) -> bool {
+    use std::io::ErrorKind;
+
+    if !matches!(
+        status.code(),
+        tonic::Code::Unavailable | tonic::Code::Unknown | tonic::Code::Internal
+    ) {
+        return false;
+    }
+
+    let mut source = std::error::Error::source(status);
+    while let Some(err) = source {
+        if let Some(h2) = err.downcast_ref::<h2::Error>() {
+            if h2.is_go_away() || h2.is_io() {
+                return true;
+            }
+        }
+        if let Some(io) = err.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io.kind(),
+                ErrorKind::BrokenPipe
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::NotConnected
+                    | ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+        source = err.source();
+    }
+
+    false
+}
+
+/// Open a TCP connection to `addr`, applying `TCP_NODELAY` as configured.
+async fn connect_tcp(addr: &str, nodelay: bool) -> std::io::Result<tokio::net::TcpStream> {
+    let tcp = tokio::net::TcpStream::connect(addr).await?;
+    tcp.set_nodelay(nodelay)?;
+    Ok(tcp)
+}
+
+/// Maximum number of concurrent reads issued by [`TalosClient::read_glob`].
+const READ_GLOB_CONCURRENCY: usize = 8;
+
+/// Directory to list for a glob pattern, and how many levels below it the
+/// pattern reaches.
+///
+/// The directory is the longest wildcard-free prefix, excluding the final
+/// component: `/etc/*/conf.d/*.toml` lists `/etc` three levels deep.
+#[allow(clippy::result_large_err)]
+fn glob_root(pattern: &str) -> crate::error::Result<(String, i32)> {
+    let Some(relative) = pattern.strip_prefix('/') else {
+        return Err(crate::error::TalosError::Validation(format!(
+            "glob pattern must be an absolute path: {:?}",
+            pattern
+        )));
+    };
+
+    let components: Vec<&str> = relative.split('/').collect();
+    let literal = components
+        .iter()
+        .position(|c| c.contains(['*', '?', '[']))
+        .unwrap_or(components.len() - 1);
+
+    let root = format!("/{}", components[..literal].join("/"));
+    Ok((root, (components.len() - literal) as i32))
+}
+
+/// Map a streamed `common.Data` response to [`DataChunk`]s, carrying the
+/// node of the last chunk with metadata over to chunks without it.
+fn map_data_stream(
+    stream: tonic::Streaming<crate::api::common::Data>,
+) -> impl Stream<Item = Result<DataChunk>> {
+    use tonic::codegen::tokio_stream::StreamExt;
+
+    let mut node = None;
+    stream.map(move |chunk| {
+        let chunk = DataChunk::from_proto(chunk?, node.take());
+        node.clone_from(&chunk.node);
+        Ok(chunk)
+    })
+}
+
+/// Blocking [`std::io::Read`] over chunks received from an async task.
+#[cfg(feature = "tar")]
+struct ChunkReader {
+    chunks: tokio::sync::mpsc::Receiver<Bytes>,
+    current: Bytes,
+}
+
+#[cfg(feature = "tar")]
+impl ChunkReader {
+    fn new(chunks: tokio::sync::mpsc::Receiver<Bytes>) -> Self {
+        Self {
+            chunks,
+            current: Bytes::new(),
+        }
+    }
+}
+
+#[cfg(feature = "tar")]
+impl std::io::Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        use bytes::Buf;
+
+        while self.current.is_empty() {
+            match self.chunks.blocking_recv() {
+                Some(chunk) => self.current = chunk,
+                None => return Ok(0),
+            }
+        }
+        let len = buf.len().min(self.current.len());
+        buf[..len].copy_from_slice(&self.current[..len]);
+        self.current.advance(len);
+        Ok(len)
+    }
+}
+
+/// Lowercase hex encoding of a digest.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Compare the SHA-256 of a file read from `path` with `expected`.
+#[allow(clippy::result_large_err)]
+fn verify_sha256(path: &str, expected: &[u8], digest: ring::digest::Context) -> Result<()> {
+    let actual = digest.finish();
+    if actual.as_ref() != expected {
+        return Err(crate::error::TalosError::Validation(format!(
+            "SHA-256 mismatch for {}: expected {}, got {}",
+            path,
+            hex(expected),
+            hex(actual.as_ref())
+        )));
+    }
+    Ok(())
+}
+
+/// Overall deadline and idle timeout applied while draining a server stream.
+struct StreamTimer<'a> {
+    method: &'a str,
+    deadline: Option<std::time::Instant>,
+    idle: Option<Duration>,
+}
+
+impl StreamTimer<'_> {
+    /// Next stream item, or `TalosError::Timeout` once the deadline passes or
+    /// the stream stays quiet for longer than the idle timeout.
+    async fn next<S>(&self, stream: &mut S) -> Result<Option<S::Item>>
+    where
+        S: Stream + Unpin,
+    {
+        use tonic::codegen::tokio_stream::StreamExt;
+
+        let remaining = self
+            .deadline
+            .map(|deadline| deadline.saturating_duration_since(std::time::Instant::now()));
+        let (limit, reason) = match (remaining, self.idle) {
+            (Some(remaining), Some(idle)) if idle < remaining => (idle, "idle timeout"),
+            (Some(remaining), _) => (remaining, "deadline"),
+            (None, Some(idle)) => (idle, "idle timeout"),
+            (None, None) => return Ok(stream.next().await),
+        };
+
+        tokio::time::timeout(limit, stream.next())
+            .await
+            .map_err(|_| {
+                crate::error::TalosError::Timeout(format!(
+                    "{} stream exceeded its {}",
+                    self.method, reason
+                ))
+            })
+    }
+}
+
+/// Open-ended stream that fails with `TalosError::Timeout` once `inner`
+/// stays quiet for longer than `idle`, then ends.
+struct IdleTimeout<S> {
+    inner: S,
+    method: &'static str,
+    idle: Option<Duration>,
+    timer: Option<std::pin::Pin<Box<tokio::time::Sleep>>>,
+    expired: bool,
+}
+
+impl<S, T> Stream for IdleTimeout<S>
+where
+    S: Stream<Item = Result<T>> + Unpin,
+{
+    type Item = Result<T>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::future::Future;
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        if this.expired {
+            return Poll::Ready(None);
+        }
+
+        if let Poll::Ready(item) = std::pin::Pin::new(&mut this.inner).poll_next(cx) {
+            if let (Some(timer), Some(idle)) = (this.timer.as_mut(), this.idle) {
+                timer.as_mut().reset(tokio::time::Instant::now() + idle);
+            }
+            return Poll::Ready(item);
+        }
+
+        let Some(idle) = this.idle else {
+            return Poll::Pending;
+        };
+        let timer = this
+            .timer
+            .get_or_insert_with(|| Box::pin(tokio::time::sleep(idle)));
+        match timer.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                this.expired = true;
+                Poll::Ready(Some(Err(crate::error::TalosError::Timeout(format!(
+                    "{} stream exceeded its idle timeout",
+                    this.method
+                )))))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// COSI watch events, one item per event.
+///
+/// The server batches events into a single `WatchResponse`; they are
+/// handed out one at a time as the stream is polled.
+struct WatchEvents {
+    responses: tonic::Streaming<ProtoCosiWatchResponse>,
+    pending: std::collections::VecDeque<crate::api::cosi::resource::Event>,
+}
+
+impl WatchEvents {
+    fn new(responses: tonic::Streaming<ProtoCosiWatchResponse>) -> Self {
+        Self {
+            responses,
+            pending: std::collections::VecDeque::new(),
+        }
+    }
+}
+
+impl Stream for WatchEvents {
+    type Item = Result<CosiEvent>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::{ready, Poll};
+
+        let this = self.get_mut();
+
+        while this.pending.is_empty() {
+            match ready!(std::pin::Pin::new(&mut this.responses).poll_next(cx)) {
+                Some(Ok(response)) => this.pending.extend(response.event),
+                Some(Err(status)) => return Poll::Ready(Some(Err(status.into()))),
+                None => return Poll::Ready(None),
+            }
+        }
+
+        let event = this.pending.pop_front().map(CosiEvent::try_from);
+        Poll::Ready(event)
+    }
+}
+
+/// COSI namespace of the machine status resource.
+const MACHINE_STATUS_NAMESPACE: &str = "runtime";
+/// COSI type of the resource read by `talosctl get machinestatus`.
+const MACHINE_STATUS_TYPE: &str = "MachineStatuses.runtime.talos.dev";
+/// ID of the single machine status resource on each node.
+const MACHINE_STATUS_ID: &str = "machine";
+
+/// Clock skew tolerated between client and node when matching events to an apply.
+const APPLY_EVENT_CLOCK_SKEW: Duration = Duration::from_secs(2);
+
+/// Check whether `event` can stem from the apply that returned `applied` at
+/// `applied_at`: it comes from a node that accepted the configuration (or
+/// carries no node, as on a direct connection) and was created after it.
+fn caused_by_apply(
+    event: &Event,
+    applied_at: std::time::SystemTime,
+    applied: &ApplyConfigurationResponse,
+) -> bool {
+    let from_applied_node = match &event.node {
+        Some(node) => applied
+            .results
+            .iter()
+            .any(|r| r.error.is_none() && r.node.as_ref() == Some(node)),
+        None => true,
+    };
+    let after_apply = event
+        .timestamp()
+        .is_some_and(|created| created + APPLY_EVENT_CLOCK_SKEW >= applied_at);
+    from_applied_node && after_apply
+}
+
+/// Why a rolling upgrade skips the remaining nodes, if it does.
+fn upgrade_halt_reason(
+    report: &RollingUpgradeReport,
+    options: &RollingUpgradeOptions,
+) -> Option<String> {
+    if options.continue_on_failure {
+        return None;
+    }
+    report
+        .failed()
+        .first()
+        .map(|failed| format!("stopped after {} failed", failed.node))
+}
+
+/// Why etcd is not healthy, or `None` if all `expected` members answered
+/// without errors and agree on a leader.
+fn etcd_health_problem(status: &EtcdStatusResponse, expected: usize) -> Option<String> {
+    let members: Vec<_> = status
+        .results
+        .iter()
+        .filter_map(|result| result.member_status.as_ref())
+        .collect();
+    if members.len() < expected {
+        return Some(format!(
+            "{} of {} members answered",
+            members.len(),
+            expected
+        ));
+    }
+    if let Some(member) = members.iter().find(|m| !m.errors.is_empty()) {
+        return Some(format!(
+            "member {:x} reports: {}",
+            member.member_id,
+            member.errors.join("; ")
+        ));
+    }
+    let leader = members.first().map_or(0, |m| m.leader);
+    if leader == 0 || members.iter().any(|m| m.leader != leader) {
+        return Some("members do not agree on a leader".to_string());
+    }
+    None
+}
+
+/// Interval between member list polls while confirming a member removal.
+const MEMBER_REMOVAL_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Poll a member list until `member_id` disappears or `timeout` elapses.
+async fn wait_for_member_removal<F, Fut>(
+    mut list_members: F,
+    member_id: u64,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<EtcdMemberListResponse>>,
+{
+    let start = std::time::Instant::now();
+
+    loop {
+        let members = list_members().await?;
+        if members.find_by_id(member_id).is_none() {
+            return Ok(());
+        }
+
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            return Err(crate::error::TalosError::Timeout(format!(
+                "etcd member {:x} still present after {:?}",
+                member_id, timeout
+            )));
+        }
+
+        tokio::time::sleep(poll_interval.min(remaining)).await;
+    }
+}
+
+/// Interval between etcd status polls while waiting for a bootstrapped cluster.
+const ETCD_READY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Poll etcd status until a healthy member with a leader is reported or `timeout` elapses.
+async fn wait_for_etcd_leader<F, Fut>(
+    mut etcd_status: F,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<EtcdStatusResponse>>,
+{
+    let start = std::time::Instant::now();
+
+    loop {
+        let last_error = match etcd_status().await {
+            Ok(status) if status.is_healthy() => return Ok(()),
+            Ok(_) => None,
+            Err(e) => Some(e),
+        };
+
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            let reason = last_error.map_or_else(
+                || "no leader elected".to_string(),
+                |e| format!("last error: {}", e),
+            );
+            return Err(crate::error::TalosError::Timeout(format!(
+                "etcd not healthy after {:?} ({})",
+                timeout, reason
+            )));
+        }
+
+        tokio::time::sleep(poll_interval.min(remaining)).await;
+    }
 }
 
 // Helper for insecure mode
@@ -1727,4 +5131,7 @@ impl rustls::client::danger::ServerCertVerifier for NoVerifier {
     }
 }
 
-pub use pool::{ConnectionPool, ConnectionPoolConfig, EndpointHealth, HealthStatus, LoadBalancer};
+pub use pool::{
+    ConnectionPool, ConnectionPoolConfig, EndpointHealth, EndpointStats, HealthStatus,
+    LoadBalancer, PoolStats, HEDGEABLE_METHODS,
+};
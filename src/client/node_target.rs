@@ -4,8 +4,8 @@
 //!
 //! This module provides functionality to target specific nodes when making
 //! Talos API calls. By default, API calls go to the endpoint you're connected to,
-//! but you can use the `x-talos-node` gRPC metadata header to route requests
-//! to specific nodes in the cluster.
+//! but you can use the `nodes` gRPC metadata header to have that endpoint
+//! proxy requests to specific nodes in the cluster.
 //!
 //! # Example
 //!
@@ -30,7 +30,10 @@ use tonic::metadata::{Ascii, MetadataValue};
 use tonic::Request;
 
 /// The gRPC metadata key for node targeting
-pub const NODE_METADATA_KEY: &str = "x-talos-node";
+///
+/// Talos' API proxy forwards a request carrying this key to each of the
+/// comma-separated nodes it lists.
+pub const NODE_METADATA_KEY: &str = "nodes";
 
 /// Represents a target node or set of nodes for API operations
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -107,6 +110,18 @@ impl NodeTarget {
         }
     }
 
+    /// Number of per-node results a call with this target should return
+    ///
+    /// The default target is the connected endpoint itself, so one result is expected.
+    #[must_use]
+    pub fn expected_responses(&self) -> usize {
+        match self {
+            Self::Default => 1,
+            Self::Single(_) => 1,
+            Self::Multiple(nodes) => nodes.len(),
+        }
+    }
+
     /// Get the first node, if any
     #[must_use]
     pub fn first(&self) -> Option<&str> {
@@ -190,6 +205,7 @@ mod tests {
         assert_eq!(target.nodes(), &[] as &[String]);
         assert_eq!(target.first(), None);
         assert_eq!(target.to_csv(), None);
+        assert_eq!(target.expected_responses(), 1);
     }
 
     #[test]
@@ -218,6 +234,7 @@ mod tests {
             target.to_csv(),
             Some("192.168.1.10,192.168.1.11".to_string())
         );
+        assert_eq!(target.expected_responses(), 2);
     }
 
     #[test]
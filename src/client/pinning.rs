@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Server certificate pinning.
+//!
+//! A pin is the SHA-256 fingerprint of the server's DER-encoded end-entity
+//! certificate, as printed by `openssl x509 -noout -fingerprint -sha256`.
+//! When pins are configured, connections are rejected unless the server
+//! presents one of the pinned certificates, guarding against a compromised
+//! or overly broad CA.
+
+use std::sync::Arc;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::WebPkiSupportedAlgorithms;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{CertificateError, DigitallySignedStruct, SignatureScheme};
+
+use crate::error::{Result, TalosError};
+
+/// Compute the SHA-256 fingerprint of a DER-encoded certificate.
+#[must_use]
+pub fn certificate_fingerprint(der: &[u8]) -> [u8; 32] {
+    let digest = ring::digest::digest(&ring::digest::SHA256, der);
+    let mut fingerprint = [0u8; 32];
+    fingerprint.copy_from_slice(digest.as_ref());
+    fingerprint
+}
+
+/// Parse a hex SHA-256 fingerprint, with or without `:` separators.
+///
+/// ```
+/// use talos_api_rs::client::parse_fingerprint;
+///
+/// let pin = parse_fingerprint(
+///     "DC:58:04:81:B5:F9:E0:F7:EF:80:41:42:6C:47:E9:72:4D:1B:EA:2E:45:3F:60:A6:62:EB:AB:F3:B3:E0:4E:40",
+/// )
+/// .unwrap();
+/// assert_eq!(pin[0], 0xdc);
+/// ```
+///
+/// # Errors
+///
+/// Returns `TalosError::Config` unless the input is exactly 32 hex bytes.
+#[allow(clippy::result_large_err)]
+pub fn parse_fingerprint(hex: &str) -> Result<[u8; 32]> {
+    let invalid = || TalosError::Config(format!("Invalid SHA-256 fingerprint: {hex}"));
+
+    let digits: Vec<u8> = hex.bytes().filter(|b| *b != b':').collect();
+    if digits.len() != 64 {
+        return Err(invalid());
+    }
+
+    let mut fingerprint = [0u8; 32];
+    for (byte, pair) in fingerprint.iter_mut().zip(digits.chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+    }
+    Ok(fingerprint)
+}
+
+/// Verifier accepting only servers whose certificate matches a pin.
+///
+/// With a CA verifier, the chain must also validate against the CA; without
+/// one (insecure mode) the pin alone authenticates the server. Handshake
+/// signatures are always checked, so the server must hold the pinned
+/// certificate's private key.
+#[derive(Debug)]
+pub(crate) struct PinnedCertVerifier {
+    pins: Vec<[u8; 32]>,
+    ca: Option<Arc<WebPkiServerVerifier>>,
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl PinnedCertVerifier {
+    pub(crate) fn new(pins: Vec<[u8; 32]>, ca: Option<Arc<WebPkiServerVerifier>>) -> Self {
+        Self {
+            pins,
+            ca,
+            algorithms: rustls::crypto::ring::default_provider().signature_verification_algorithms,
+        }
+    }
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        if let Some(ca) = &self.ca {
+            ca.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)?;
+        }
+
+        if self.pins.contains(&certificate_fingerprint(end_entity)) {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVER_CRT: &[u8] = include_bytes!("../../tests/fixtures/server.crt");
+    const SERVER_FINGERPRINT: &str = "DC:58:04:81:B5:F9:E0:F7:EF:80:41:42:6C:47:E9:72:\
+                                      4D:1B:EA:2E:45:3F:60:A6:62:EB:AB:F3:B3:E0:4E:40";
+
+    fn server_cert() -> CertificateDer<'static> {
+        rustls_pemfile::certs(&mut &SERVER_CRT[..])
+            .next()
+            .unwrap()
+            .unwrap()
+    }
+
+    fn verify(verifier: &PinnedCertVerifier) -> std::result::Result<(), rustls::Error> {
+        verifier
+            .verify_server_cert(
+                &server_cert(),
+                &[],
+                &ServerName::try_from("talos.example").unwrap(),
+                &[],
+                UnixTime::now(),
+            )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_fingerprint_matches_openssl() {
+        let expected = parse_fingerprint(SERVER_FINGERPRINT).unwrap();
+        assert_eq!(certificate_fingerprint(&server_cert()), expected);
+    }
+
+    #[test]
+    fn test_parse_fingerprint_rejects_malformed() {
+        assert!(parse_fingerprint("DC:58").is_err());
+        assert!(parse_fingerprint(&"zz".repeat(32)).is_err());
+        assert!(parse_fingerprint(&"ab".repeat(32)).is_ok());
+    }
+
+    #[test]
+    fn test_pinned_verifier_accepts_matching_cert() {
+        let pin = parse_fingerprint(SERVER_FINGERPRINT).unwrap();
+        let verifier = PinnedCertVerifier::new(vec![[0u8; 32], pin], None);
+        assert!(verify(&verifier).is_ok());
+    }
+
+    #[test]
+    fn test_pinned_verifier_rejects_other_cert() {
+        let verifier = PinnedCertVerifier::new(vec![[0u8; 32]], None);
+        assert!(matches!(
+            verify(&verifier),
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_pinned_verifier_still_requires_ca() {
+        // The pin matches, but the self-signed cert does not chain to the CA.
+        let mut roots = rustls::RootCertStore::empty();
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        let ca = WebPkiServerVerifier::builder_with_provider(
+            Arc::new(roots),
+            Arc::new(rustls::crypto::ring::default_provider()),
+        )
+        .build()
+        .unwrap();
+
+        let pin = parse_fingerprint(SERVER_FINGERPRINT).unwrap();
+        let verifier = PinnedCertVerifier::new(vec![pin], Some(ca));
+        assert!(verify(&verifier).is_err());
+    }
+}
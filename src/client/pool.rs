@@ -6,6 +6,7 @@
 //! - [`ConnectionPool`]: A pool of connections to multiple Talos endpoints
 //! - [`EndpointHealth`]: Health tracking for individual endpoints
 //! - [`LoadBalancer`]: Strategies for selecting endpoints
+//! - [`PoolStats`]: Point-in-time snapshot of pool health
 //!
 //! # Example
 //!
@@ -28,6 +29,8 @@
 
 use crate::client::{TalosClient, TalosClientConfig};
 use crate::error::{Result, TalosError};
+use crate::runtime::{CircuitBreaker, CircuitBreakerConfig};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -35,7 +38,7 @@ use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
 /// Health status of an endpoint.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum HealthStatus {
     /// Endpoint is healthy and accepting requests.
     Healthy,
@@ -60,6 +63,8 @@ pub struct EndpointHealth {
     total_requests: AtomicU64,
     /// Total number of failures.
     total_failures: AtomicU64,
+    /// Clients handed out by the pool that have not reported completion.
+    in_flight: AtomicUsize,
     /// Last successful request time.
     last_success: RwLock<Option<Instant>>,
     /// Last failure time.
@@ -79,6 +84,7 @@ impl EndpointHealth {
             consecutive_successes: AtomicUsize::new(0),
             total_requests: AtomicU64::new(0),
             total_failures: AtomicU64::new(0),
+            in_flight: AtomicUsize::new(0),
             last_success: RwLock::new(None),
             last_failure: RwLock::new(None),
             last_health_check: RwLock::new(None),
@@ -114,15 +120,22 @@ impl EndpointHealth {
     }
 
     /// Record a successful request.
-    pub async fn record_success(&self) {
+    ///
+    /// An unhealthy endpoint only becomes healthy again after
+    /// `recovery_threshold` consecutive successes; counters and timestamps
+    /// are updated either way.
+    pub async fn record_success(&self, recovery_threshold: usize) {
         self.total_requests.fetch_add(1, Ordering::Relaxed);
         self.consecutive_failures.store(0, Ordering::Relaxed);
-        self.consecutive_successes.fetch_add(1, Ordering::Relaxed);
+        let successes = self.consecutive_successes.fetch_add(1, Ordering::Relaxed) + 1;
         *self.last_success.write().await = Some(Instant::now());
-        self.status.store(
-            Self::status_to_u64(HealthStatus::Healthy),
-            Ordering::Release,
-        );
+
+        if self.status() != HealthStatus::Unhealthy || successes >= recovery_threshold {
+            self.status.store(
+                Self::status_to_u64(HealthStatus::Healthy),
+                Ordering::Release,
+            );
+        }
     }
 
     /// Record a failed request.
@@ -142,10 +155,15 @@ impl EndpointHealth {
     }
 
     /// Record a health check.
-    pub async fn record_health_check(&self, healthy: bool, failure_threshold: usize) {
+    pub async fn record_health_check(
+        &self,
+        healthy: bool,
+        failure_threshold: usize,
+        recovery_threshold: usize,
+    ) {
         *self.last_health_check.write().await = Some(Instant::now());
         if healthy {
-            self.record_success().await;
+            self.record_success(recovery_threshold).await;
         } else {
             self.record_failure(failure_threshold).await;
         }
@@ -179,6 +197,26 @@ impl EndpointHealth {
         self.total_failures.load(Ordering::Relaxed)
     }
 
+    /// Get the number of requests in flight.
+    ///
+    /// Counts clients handed out by [`ConnectionPool::get_client`] whose
+    /// outcome has not been reported yet.
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    fn start_request(&self) {
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn finish_request(&self) {
+        // Outcomes reported for requests the pool did not hand out must not underflow.
+        let _ = self
+            .in_flight
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1));
+    }
+
     /// Get the failure rate (0.0 to 1.0).
     #[must_use]
     pub fn failure_rate(&self) -> f64 {
@@ -199,6 +237,61 @@ impl EndpointHealth {
     pub async fn last_health_check(&self) -> Option<Instant> {
         *self.last_health_check.read().await
     }
+
+    /// Take a snapshot of this endpoint's health.
+    pub async fn stats(&self) -> EndpointStats {
+        EndpointStats {
+            endpoint: self.endpoint.clone(),
+            status: self.status(),
+            consecutive_failures: self.consecutive_failures(),
+            total_requests: self.total_requests(),
+            total_failures: self.total_failures(),
+            failure_rate: self.failure_rate(),
+            in_flight: self.in_flight(),
+            since_last_success: self.last_success().await.map(|t| t.elapsed()),
+            since_last_health_check: self.last_health_check().await.map(|t| t.elapsed()),
+        }
+    }
+}
+
+/// Snapshot of a single endpoint's health, as returned by [`EndpointHealth::stats`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EndpointStats {
+    /// The endpoint URL.
+    pub endpoint: String,
+    /// Health status at snapshot time.
+    pub status: HealthStatus,
+    /// Number of consecutive failures.
+    pub consecutive_failures: usize,
+    /// Total number of requests.
+    pub total_requests: u64,
+    /// Total number of failures.
+    pub total_failures: u64,
+    /// Failure rate (0.0 to 1.0).
+    pub failure_rate: f64,
+    /// Requests in flight.
+    pub in_flight: usize,
+    /// Time since the last successful request, if any.
+    pub since_last_success: Option<Duration>,
+    /// Time since the last health check, if any.
+    pub since_last_health_check: Option<Duration>,
+}
+
+/// Snapshot of a [`ConnectionPool`], as returned by [`ConnectionPool::stats`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PoolStats {
+    /// Total number of endpoints.
+    pub total: usize,
+    /// Endpoints currently healthy.
+    pub healthy: usize,
+    /// Endpoints currently unhealthy.
+    pub unhealthy: usize,
+    /// Endpoints whose health is not yet known.
+    pub unknown: usize,
+    /// Endpoints with an open client connection.
+    pub connected: usize,
+    /// Per-endpoint snapshots, sorted by endpoint URL.
+    pub endpoints: Vec<EndpointStats>,
 }
 
 /// Load balancing strategy for selecting endpoints.
@@ -213,8 +306,45 @@ pub enum LoadBalancer {
     LeastFailures,
     /// Always prefer the first healthy endpoint (failover mode).
     Failover,
+    /// Select the endpoint with the fewest requests in flight.
+    ///
+    /// Relies on callers reporting every client from
+    /// [`ConnectionPool::get_client`]; see there.
+    LeastConnections,
+    /// Round-robin, giving endpoints with a lower failure rate
+    /// proportionally more turns.
+    WeightedRoundRobin,
 }
 
+/// Turns per round for an endpoint without failures under
+/// [`LoadBalancer::WeightedRoundRobin`].
+const MAX_ENDPOINT_WEIGHT: u64 = 10;
+
+/// gRPC methods [`ConnectionPool::hedged_call`] may hedge: unary reads that
+/// are safe to send to two endpoints at once.
+pub const HEDGEABLE_METHODS: &[&str] = &[
+    "CPUFreqStats",
+    "CPUInfo",
+    "Containers",
+    "DiskStats",
+    "EtcdAlarmList",
+    "EtcdMemberList",
+    "EtcdStatus",
+    "Get",
+    "Hostname",
+    "ImageList",
+    "LoadAvg",
+    "Memory",
+    "Mounts",
+    "Netstat",
+    "NetworkDeviceStats",
+    "Processes",
+    "ServiceList",
+    "Stats",
+    "SystemStat",
+    "Version",
+];
+
 /// Configuration for the connection pool.
 #[derive(Debug, Clone)]
 pub struct ConnectionPoolConfig {
@@ -230,8 +360,15 @@ pub struct ConnectionPoolConfig {
     pub recovery_threshold: usize,
     /// Base client configuration (TLS, timeouts, etc.).
     pub base_config: Option<TalosClientConfig>,
-    /// Enable automatic health checks.
+    /// Check every endpoint in the background each `health_check_interval`.
     pub auto_health_check: bool,
+    /// Seed for the health-check jitter; `None` seeds from the OS.
+    pub health_check_jitter_seed: Option<u64>,
+    /// Seed for endpoint selection by [`LoadBalancer::Random`]; `None` seeds
+    /// from the OS.
+    pub rng_seed: Option<u64>,
+    /// Per-endpoint circuit breaker; `None` disables circuit breaking.
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 impl ConnectionPoolConfig {
@@ -246,6 +383,9 @@ impl ConnectionPoolConfig {
             recovery_threshold: 2,
             base_config: None,
             auto_health_check: true,
+            health_check_jitter_seed: None,
+            rng_seed: None,
+            circuit_breaker: None,
         }
     }
 
@@ -290,6 +430,111 @@ impl ConnectionPoolConfig {
         self.auto_health_check = false;
         self
     }
+
+    /// Seed the health-check jitter, making the schedule reproducible.
+    #[must_use]
+    pub fn with_health_check_jitter_seed(mut self, seed: u64) -> Self {
+        self.health_check_jitter_seed = Some(seed);
+        self
+    }
+
+    /// Seed endpoint selection, making the sequence picked by
+    /// [`LoadBalancer::Random`] reproducible.
+    #[must_use]
+    pub fn with_rng_seed(mut self, seed: u64) -> Self {
+        self.rng_seed = Some(seed);
+        self
+    }
+
+    /// Give each endpoint a circuit breaker.
+    ///
+    /// Breakers see the outcomes reported with
+    /// [`ConnectionPool::record_success`] and
+    /// [`ConnectionPool::record_failure`], and endpoints with an open breaker
+    /// are skipped like unhealthy ones until it half-opens.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Check the configuration for values that would misbehave at runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` if there are no endpoints, an endpoint is
+    /// blank, either threshold is zero, or automatic health checks are
+    /// enabled with a zero interval.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> Result<()> {
+        if self.endpoints.is_empty() {
+            return Err(TalosError::Config(
+                "At least one endpoint is required".to_string(),
+            ));
+        }
+        if self.endpoints.iter().any(|e| e.trim().is_empty()) {
+            return Err(TalosError::Config(
+                "Endpoints must not be blank".to_string(),
+            ));
+        }
+        if self.failure_threshold == 0 {
+            return Err(TalosError::Config(
+                "failure_threshold must be at least 1".to_string(),
+            ));
+        }
+        if self.recovery_threshold == 0 {
+            return Err(TalosError::Config(
+                "recovery_threshold must be at least 1".to_string(),
+            ));
+        }
+        if self.auto_health_check && self.health_check_interval.is_zero() {
+            return Err(TalosError::Config(
+                "health_check_interval must be non-zero when automatic health checks are enabled"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate and return the configuration.
+    ///
+    /// # Errors
+    ///
+    /// See [`validate`](Self::validate).
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> Result<Self> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Delay before each endpoint's first health check, in endpoint order.
+    ///
+    /// The interval is split into one slot per endpoint and each endpoint
+    /// gets a random offset within its own slot, so checks are spread over
+    /// the interval instead of probing every endpoint in lockstep. The
+    /// background health checks start each endpoint's schedule at its delay.
+    #[must_use]
+    pub fn initial_health_check_delays(&self) -> Vec<(String, Duration)> {
+        use rand::{RngExt, SeedableRng};
+
+        let seed = self
+            .health_check_jitter_seed
+            .unwrap_or_else(rand::random::<u64>);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let slots = self.endpoints.len() as f64;
+
+        self.endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, endpoint)| {
+                let position = (i as f64 + rng.random::<f64>()) / slots;
+                (
+                    endpoint.clone(),
+                    self.health_check_interval.mul_f64(position),
+                )
+            })
+            .collect()
+    }
 }
 
 /// A pool of connections to multiple Talos endpoints.
@@ -297,14 +542,26 @@ impl ConnectionPoolConfig {
 /// The pool maintains connections to multiple Talos nodes and routes
 /// requests to healthy endpoints based on the configured load balancing
 /// strategy.
+///
+/// With [`auto_health_check`](ConnectionPoolConfig::auto_health_check)
+/// enabled, a background task checks every endpoint each
+/// `health_check_interval` until the pool is shut down or dropped.
 pub struct ConnectionPool {
+    shared: Arc<PoolShared>,
+    health_check_handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+/// Pool state shared with the background health-check task.
+struct PoolShared {
     config: ConnectionPoolConfig,
     clients: RwLock<HashMap<String, TalosClient>>,
     health: HashMap<String, Arc<EndpointHealth>>,
+    /// Circuit breakers, if enabled.
+    breakers: HashMap<String, Arc<CircuitBreaker>>,
     round_robin_index: AtomicUsize,
+    /// Source for random endpoint selection.
+    rng: std::sync::Mutex<rand::rngs::StdRng>,
     shutdown: AtomicBool,
-    #[allow(dead_code)]
-    health_check_handle: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl ConnectionPool {
@@ -312,33 +569,24 @@ impl ConnectionPool {
     ///
     /// # Errors
     ///
-    /// Returns an error if no endpoints are provided or if initial connection fails.
+    /// Returns an error if the configuration is invalid (see
+    /// [`ConnectionPoolConfig::validate`]) or if initial connection fails.
     pub async fn new(config: ConnectionPoolConfig) -> Result<Self> {
-        if config.endpoints.is_empty() {
-            return Err(TalosError::Config(
-                "At least one endpoint is required".to_string(),
-            ));
-        }
-
-        // Initialize health tracking for all endpoints
-        let health: HashMap<String, Arc<EndpointHealth>> = config
-            .endpoints
-            .iter()
-            .map(|e| (e.clone(), Arc::new(EndpointHealth::new(e.clone()))))
-            .collect();
+        config.validate()?;
 
-        let pool = Self {
-            config,
-            clients: RwLock::new(HashMap::new()),
-            health,
-            round_robin_index: AtomicUsize::new(0),
-            shutdown: AtomicBool::new(false),
+        let mut pool = Self {
+            shared: Arc::new(PoolShared::new(config)),
             health_check_handle: None,
         };
 
         // Try to connect to at least one endpoint
         pool.connect_all().await?;
 
+        if pool.shared.config.auto_health_check {
+            let shared = Arc::clone(&pool.shared);
+            pool.health_check_handle = Some(tokio::spawn(shared.health_check_loop()));
+        }
+
         Ok(pool)
     }
 
@@ -347,18 +595,26 @@ impl ConnectionPool {
         let mut connected = false;
         let mut last_error = None;
 
-        for endpoint in &self.config.endpoints {
-            match self.connect_endpoint(endpoint).await {
+        for endpoint in &self.shared.config.endpoints {
+            match self.shared.connect_endpoint(endpoint).await {
                 Ok(client) => {
-                    self.clients.write().await.insert(endpoint.clone(), client);
-                    if let Some(health) = self.health.get(endpoint) {
-                        health.record_success().await;
+                    self.shared
+                        .clients
+                        .write()
+                        .await
+                        .insert(endpoint.clone(), client);
+                    if let Some(health) = self.shared.health.get(endpoint) {
+                        health
+                            .record_success(self.shared.config.recovery_threshold)
+                            .await;
                     }
                     connected = true;
                 }
                 Err(e) => {
-                    if let Some(health) = self.health.get(endpoint) {
-                        health.record_failure(self.config.failure_threshold).await;
+                    if let Some(health) = self.shared.health.get(endpoint) {
+                        health
+                            .record_failure(self.shared.config.failure_threshold)
+                            .await;
                     }
                     last_error = Some(e);
                 }
@@ -374,29 +630,14 @@ impl ConnectionPool {
         }
     }
 
-    /// Connect to a single endpoint.
-    async fn connect_endpoint(&self, endpoint: &str) -> Result<TalosClient> {
-        let config = if let Some(base) = &self.config.base_config {
-            TalosClientConfig {
-                endpoint: endpoint.to_string(),
-                crt_path: base.crt_path.clone(),
-                key_path: base.key_path.clone(),
-                ca_path: base.ca_path.clone(),
-                insecure: base.insecure,
-                connect_timeout: base.connect_timeout,
-                request_timeout: base.request_timeout,
-                keepalive_interval: base.keepalive_interval,
-                keepalive_timeout: base.keepalive_timeout,
-            }
-        } else {
-            TalosClientConfig::new(endpoint)
-        };
-
-        TalosClient::new(config).await
-    }
-
     /// Get a healthy client using the configured load balancing strategy.
     ///
+    /// The request counts as in flight on the client's endpoint until the
+    /// caller reports its outcome with [`record_success`](Self::record_success)
+    /// or [`record_failure`](Self::record_failure), or gives the client up with
+    /// [`release`](Self::release). [`LoadBalancer::LeastConnections`] relies
+    /// on this; unreported clients make their endpoint look busy.
+    ///
     /// # Errors
     ///
     /// Returns an error if no healthy endpoints are available.
@@ -414,34 +655,70 @@ impl ConnectionPool {
             }
         }
 
-        let endpoint = self.select_endpoint(&self.get_healthy_endpoints())?;
-        let clients = self.clients.read().await;
+        let available = self.get_available_endpoints().await;
+        if available.is_empty() {
+            // Every healthy endpoint has an open breaker.
+            let open = self.get_healthy_endpoints().len();
+            return Err(TalosError::CircuitOpen(format!(
+                "No endpoint available: {} with an open circuit breaker, {} unhealthy",
+                open,
+                self.endpoint_count() - open
+            )));
+        }
 
-        clients.get(&endpoint).cloned().ok_or_else(|| {
+        let endpoint = self.select_endpoint(&available)?;
+        let client = self.shared.clients.read().await.get(&endpoint).cloned();
+        let client = client.ok_or_else(|| {
             TalosError::Connection(format!("Client for endpoint {} not found", endpoint))
-        })
+        })?;
+        self.start_request(&endpoint);
+        Ok(client)
     }
 
-    /// Get a list of healthy endpoint URLs.
+    /// Get a list of healthy endpoint URLs, in configuration order.
     #[must_use]
     pub fn get_healthy_endpoints(&self) -> Vec<String> {
-        self.health
+        self.shared
+            .config
+            .endpoints
             .iter()
-            .filter(|(_, h)| h.is_healthy())
-            .map(|(e, _)| e.clone())
+            .filter(|e| self.shared.health.get(*e).is_some_and(|h| h.is_healthy()))
+            .cloned()
             .collect()
     }
 
+    /// Get the healthy endpoints whose circuit breaker (if any) lets requests
+    /// through, in configuration order.
+    pub async fn get_available_endpoints(&self) -> Vec<String> {
+        let mut available = self.get_healthy_endpoints();
+        let mut open = Vec::new();
+        for endpoint in &available {
+            if let Some(breaker) = self.shared.breakers.get(endpoint) {
+                if !breaker.can_execute().await {
+                    open.push(endpoint.clone());
+                }
+            }
+        }
+        available.retain(|e| !open.contains(e));
+        available
+    }
+
+    /// Get the circuit breaker for an endpoint, if circuit breaking is enabled.
+    #[must_use]
+    pub fn get_circuit_breaker(&self, endpoint: &str) -> Option<&Arc<CircuitBreaker>> {
+        self.shared.breakers.get(endpoint)
+    }
+
     /// Get health information for an endpoint.
     #[must_use]
     pub fn get_endpoint_health(&self, endpoint: &str) -> Option<&Arc<EndpointHealth>> {
-        self.health.get(endpoint)
+        self.shared.health.get(endpoint)
     }
 
     /// Get health information for all endpoints.
     #[must_use]
     pub fn get_all_health(&self) -> &HashMap<String, Arc<EndpointHealth>> {
-        &self.health
+        &self.shared.health
     }
 
     /// Select an endpoint based on the load balancing strategy.
@@ -453,24 +730,32 @@ impl ConnectionPool {
             ));
         }
 
-        let endpoint = match self.config.load_balancer {
+        let endpoint = match self.shared.config.load_balancer {
             LoadBalancer::RoundRobin => {
-                let idx = self.round_robin_index.fetch_add(1, Ordering::Relaxed) % healthy.len();
+                let idx = self
+                    .shared
+                    .round_robin_index
+                    .fetch_add(1, Ordering::Relaxed)
+                    % healthy.len();
                 healthy[idx].clone()
             }
             LoadBalancer::Random => {
-                // rand 0.10 dropped Distribution<usize> for StandardUniform, so
-                // rand::random::<usize>() no longer compiles. The top-level
-                // rand::random_range fn is both the supported replacement and
-                // avoids the modulo bias we previously had against healthy.len().
-                let idx = rand::random_range(0..healthy.len());
+                use rand::RngExt;
+
+                // random_range avoids the modulo bias of `random::<usize>() % len`.
+                let idx = self
+                    .shared
+                    .rng
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .random_range(0..healthy.len());
                 healthy[idx].clone()
             }
             LoadBalancer::LeastFailures => {
                 let mut best = healthy[0].clone();
                 let mut best_rate = f64::MAX;
                 for e in healthy {
-                    if let Some(health) = self.health.get(e) {
+                    if let Some(health) = self.shared.health.get(e) {
                         let rate = health.failure_rate();
                         if rate < best_rate {
                             best_rate = rate;
@@ -481,23 +766,308 @@ impl ConnectionPool {
                 best
             }
             LoadBalancer::Failover => healthy[0].clone(),
+            LoadBalancer::LeastConnections => healthy
+                .iter()
+                .min_by_key(|e| self.shared.health.get(*e).map_or(0, |h| h.in_flight()))
+                .expect("healthy is not empty")
+                .clone(),
+            LoadBalancer::WeightedRoundRobin => {
+                let weights: Vec<u64> = healthy
+                    .iter()
+                    .map(|e| {
+                        let rate = self.shared.health.get(e).map_or(0.0, |h| h.failure_rate());
+                        ((1.0 - rate) * MAX_ENDPOINT_WEIGHT as f64).round().max(1.0) as u64
+                    })
+                    .collect();
+                let mut turn = self
+                    .shared
+                    .round_robin_index
+                    .fetch_add(1, Ordering::Relaxed) as u64
+                    % weights.iter().sum::<u64>();
+                let mut picked = healthy.len() - 1;
+                for (i, weight) in weights.iter().enumerate() {
+                    if turn < *weight {
+                        picked = i;
+                        break;
+                    }
+                    turn -= weight;
+                }
+                healthy[picked].clone()
+            }
         };
 
         Ok(endpoint)
     }
 
+    /// Run a read-only call, hedging it to a second endpoint if it is slow.
+    ///
+    /// `op` is started on a client picked by the load balancer. If it has
+    /// not finished after `delay` (or fails before then), it is also started
+    /// on another healthy endpoint, and the first successful response wins;
+    /// the other call is dropped. With a single healthy endpoint this is a
+    /// plain call. Outcomes are recorded in the endpoints' health.
+    ///
+    /// `method` is the gRPC method `op` performs (e.g. `"Version"`).
+    /// Hedging sends the request twice, so only the read-only methods in
+    /// [`HEDGEABLE_METHODS`] are accepted; anything else, including a
+    /// misspelt name, is refused before `op` runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TalosError::Validation`] for a `method` not in
+    /// [`HEDGEABLE_METHODS`], a connection error if no endpoint is healthy,
+    /// or the last error of `op` if every attempt fails.
+    pub async fn hedged_call<T, F, Fut>(&self, method: &str, delay: Duration, op: F) -> Result<T>
+    where
+        F: Fn(TalosClient) -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        if !HEDGEABLE_METHODS.contains(&method) {
+            return Err(TalosError::Validation(format!(
+                "{method} is not a known read-only method and cannot be hedged"
+            )));
+        }
+
+        let primary = self.get_client().await?;
+        let primary_endpoint = primary.endpoint().to_string();
+        let Some((hedge_endpoint, hedge)) = self.hedge_client(&primary_endpoint).await else {
+            let result = op(primary).await;
+            return self.track(&primary_endpoint, result).await;
+        };
+
+        let first = op(primary);
+        tokio::pin!(first);
+        tokio::select! {
+            result = &mut first => match result {
+                Ok(value) => return self.track(&primary_endpoint, Ok(value)).await,
+                Err(_) => {
+                    // A fast failure triggers the hedge right away.
+                    self.record_failure(&primary_endpoint).await;
+                    self.start_request(&hedge_endpoint);
+                    let result = op(hedge).await;
+                    return self.track(&hedge_endpoint, result).await;
+                }
+            },
+            () = tokio::time::sleep(delay) => {}
+        }
+
+        tracing::debug!(
+            "{} on {} exceeded {:?}, hedging to {}",
+            method,
+            primary_endpoint,
+            delay,
+            hedge_endpoint
+        );
+        self.start_request(&hedge_endpoint);
+        let second = op(hedge);
+        tokio::pin!(second);
+        tokio::select! {
+            result = &mut first => match result {
+                Ok(value) => {
+                    self.release(&hedge_endpoint);
+                    self.track(&primary_endpoint, Ok(value)).await
+                }
+                Err(_) => {
+                    self.record_failure(&primary_endpoint).await;
+                    let result = second.await;
+                    self.track(&hedge_endpoint, result).await
+                }
+            },
+            result = &mut second => match result {
+                Ok(value) => {
+                    self.release(&primary_endpoint);
+                    self.track(&hedge_endpoint, Ok(value)).await
+                }
+                Err(_) => {
+                    self.record_failure(&hedge_endpoint).await;
+                    let result = first.await;
+                    self.track(&primary_endpoint, result).await
+                }
+            },
+        }
+    }
+
+    /// Client for a healthy endpoint other than `exclude`, to hedge to.
+    async fn hedge_client(&self, exclude: &str) -> Option<(String, TalosClient)> {
+        let others: Vec<String> = self
+            .get_available_endpoints()
+            .await
+            .into_iter()
+            .filter(|e| e != exclude)
+            .collect();
+        let endpoint = self.select_endpoint(&others).ok()?;
+        let client = self.shared.clients.read().await.get(&endpoint).cloned()?;
+        Some((endpoint, client))
+    }
+
+    /// Record the outcome of a call on `endpoint` and pass it through.
+    async fn track<T>(&self, endpoint: &str, result: Result<T>) -> Result<T> {
+        match &result {
+            Ok(_) => self.record_success(endpoint).await,
+            Err(_) => self.record_failure(endpoint).await,
+        }
+        result
+    }
+
     /// Perform a health check on a specific endpoint.
     ///
     /// # Errors
     ///
     /// Returns an error if the health check fails.
     pub async fn health_check(&self, endpoint: &str) -> Result<bool> {
+        self.shared.health_check(endpoint).await
+    }
+
+    /// Perform health checks on all endpoints.
+    pub async fn health_check_all(&self) {
+        self.shared.health_check_all().await;
+    }
+
+    /// Record a successful operation for an endpoint.
+    ///
+    /// Also ends one request in flight on it; see [`get_client`](Self::get_client).
+    pub async fn record_success(&self, endpoint: &str) {
+        if let Some(health) = self.shared.health.get(endpoint) {
+            health.finish_request();
+            health
+                .record_success(self.shared.config.recovery_threshold)
+                .await;
+        }
+        if let Some(breaker) = self.shared.breakers.get(endpoint) {
+            breaker.record_success().await;
+        }
+    }
+
+    /// Record a failed operation for an endpoint.
+    ///
+    /// Also ends one request in flight on it; see [`get_client`](Self::get_client).
+    pub async fn record_failure(&self, endpoint: &str) {
+        if let Some(health) = self.shared.health.get(endpoint) {
+            health.finish_request();
+            health
+                .record_failure(self.shared.config.failure_threshold)
+                .await;
+        }
+        if let Some(breaker) = self.shared.breakers.get(endpoint) {
+            breaker.record_failure().await;
+        }
+    }
+
+    /// End a request in flight on `endpoint` without recording its outcome,
+    /// e.g. when a client from [`get_client`](Self::get_client) goes unused.
+    pub fn release(&self, endpoint: &str) {
+        if let Some(health) = self.shared.health.get(endpoint) {
+            health.finish_request();
+        }
+    }
+
+    fn start_request(&self, endpoint: &str) {
+        if let Some(health) = self.shared.health.get(endpoint) {
+            health.start_request();
+        }
+    }
+
+    /// Shutdown the connection pool.
+    pub fn shutdown(&self) {
+        self.shared.shutdown.store(true, Ordering::Release);
+    }
+
+    /// Check if the pool is shut down.
+    #[must_use]
+    pub fn is_shutdown(&self) -> bool {
+        self.shared.shutdown.load(Ordering::Acquire)
+    }
+
+    /// Get the number of connected clients.
+    pub async fn connected_count(&self) -> usize {
+        self.shared.clients.read().await.len()
+    }
+
+    /// Get the total number of endpoints.
+    #[must_use]
+    pub fn endpoint_count(&self) -> usize {
+        self.shared.config.endpoints.len()
+    }
+
+    /// Take a snapshot of the pool's health, e.g. to serve a status endpoint.
+    pub async fn stats(&self) -> PoolStats {
+        let mut endpoints = Vec::with_capacity(self.shared.health.len());
+        for health in self.shared.health.values() {
+            endpoints.push(health.stats().await);
+        }
+        endpoints.sort_by(|a, b| a.endpoint.cmp(&b.endpoint));
+
+        let count = |status| endpoints.iter().filter(|e| e.status == status).count();
+        PoolStats {
+            total: endpoints.len(),
+            healthy: count(HealthStatus::Healthy),
+            unhealthy: count(HealthStatus::Unhealthy),
+            unknown: count(HealthStatus::Unknown),
+            connected: self.connected_count().await,
+            endpoints,
+        }
+    }
+}
+
+impl PoolShared {
+    fn new(config: ConnectionPoolConfig) -> Self {
+        use rand::SeedableRng;
+
+        // Initialize health tracking for all endpoints
+        let health = config
+            .endpoints
+            .iter()
+            .map(|e| (e.clone(), Arc::new(EndpointHealth::new(e.clone()))))
+            .collect();
+        let breakers = match &config.circuit_breaker {
+            Some(breaker) => config
+                .endpoints
+                .iter()
+                .map(|e| (e.clone(), Arc::new(CircuitBreaker::new(breaker.clone()))))
+                .collect(),
+            None => HashMap::new(),
+        };
+        let seed = config.rng_seed.unwrap_or_else(rand::random::<u64>);
+
+        Self {
+            config,
+            clients: RwLock::new(HashMap::new()),
+            health,
+            breakers,
+            round_robin_index: AtomicUsize::new(0),
+            rng: std::sync::Mutex::new(rand::rngs::StdRng::seed_from_u64(seed)),
+            shutdown: AtomicBool::new(false),
+        }
+    }
+
+    /// Connect to a single endpoint.
+    async fn connect_endpoint(&self, endpoint: &str) -> Result<TalosClient> {
+        let config = match &self.config.base_config {
+            Some(base) => base.with_endpoint(endpoint),
+            None => TalosClientConfig::new(endpoint),
+        };
+
+        TalosClient::new(config)
+            .await
+            .map_err(|e| e.at_endpoint(endpoint))
+    }
+
+    /// Perform a health check on a specific endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the health check fails.
+    async fn health_check(&self, endpoint: &str) -> Result<bool> {
         let client = match self.connect_endpoint(endpoint).await {
             Ok(c) => c,
             Err(e) => {
                 if let Some(health) = self.health.get(endpoint) {
                     health
-                        .record_health_check(false, self.config.failure_threshold)
+                        .record_health_check(
+                            false,
+                            self.config.failure_threshold,
+                            self.config.recovery_threshold,
+                        )
                         .await;
                 }
                 return Err(e);
@@ -511,7 +1081,11 @@ impl ConnectionPool {
             Ok(_) => {
                 if let Some(health) = self.health.get(endpoint) {
                     health
-                        .record_health_check(true, self.config.failure_threshold)
+                        .record_health_check(
+                            true,
+                            self.config.failure_threshold,
+                            self.config.recovery_threshold,
+                        )
                         .await;
                 }
                 // Update client in pool
@@ -524,7 +1098,11 @@ impl ConnectionPool {
             Err(e) => {
                 if let Some(health) = self.health.get(endpoint) {
                     health
-                        .record_health_check(false, self.config.failure_threshold)
+                        .record_health_check(
+                            false,
+                            self.config.failure_threshold,
+                            self.config.recovery_threshold,
+                        )
                         .await;
                 }
                 Err(TalosError::Api(e))
@@ -533,52 +1111,48 @@ impl ConnectionPool {
     }
 
     /// Perform health checks on all endpoints.
-    pub async fn health_check_all(&self) {
+    async fn health_check_all(&self) {
         for endpoint in &self.config.endpoints {
             let _ = self.health_check(endpoint).await;
         }
     }
 
-    /// Record a successful operation for an endpoint.
-    pub async fn record_success(&self, endpoint: &str) {
-        if let Some(health) = self.health.get(endpoint) {
-            health.record_success().await;
-        }
-    }
-
-    /// Record a failed operation for an endpoint.
-    pub async fn record_failure(&self, endpoint: &str) {
-        if let Some(health) = self.health.get(endpoint) {
-            health.record_failure(self.config.failure_threshold).await;
+    /// Check every endpoint each `health_check_interval` until the pool
+    /// shuts down.
+    ///
+    /// Each endpoint runs on its own schedule, starting after its delay from
+    /// [`initial_health_check_delays`](ConnectionPoolConfig::initial_health_check_delays),
+    /// so the endpoints are not probed in lockstep.
+    async fn health_check_loop(self: Arc<Self>) {
+        let interval = self.config.health_check_interval;
+        let start = tokio::time::Instant::now();
+
+        let mut checks = tokio::task::JoinSet::new();
+        for (endpoint, delay) in self.config.initial_health_check_delays() {
+            let shared = Arc::clone(&self);
+            checks.spawn(async move {
+                let mut ticker = tokio::time::interval_at(start + delay, interval);
+                ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+                loop {
+                    ticker.tick().await;
+                    if shared.shutdown.load(Ordering::Acquire) {
+                        break;
+                    }
+                    let _ = shared.health_check(&endpoint).await;
+                }
+            });
         }
-    }
-
-    /// Shutdown the connection pool.
-    pub fn shutdown(&self) {
-        self.shutdown.store(true, Ordering::Release);
-    }
-
-    /// Check if the pool is shut down.
-    #[must_use]
-    pub fn is_shutdown(&self) -> bool {
-        self.shutdown.load(Ordering::Acquire)
-    }
-
-    /// Get the number of connected clients.
-    pub async fn connected_count(&self) -> usize {
-        self.clients.read().await.len()
-    }
-
-    /// Get the total number of endpoints.
-    #[must_use]
-    pub fn endpoint_count(&self) -> usize {
-        self.config.endpoints.len()
+        while checks.join_next().await.is_some() {}
     }
 }
 
 impl Drop for ConnectionPool {
     fn drop(&mut self) {
         self.shutdown();
+        if let Some(handle) = self.health_check_handle.take() {
+            handle.abort();
+        }
     }
 }
 
@@ -597,7 +1171,7 @@ mod tests {
     #[tokio::test]
     async fn test_endpoint_health_record_success() {
         let health = EndpointHealth::new("https://test:50000".to_string());
-        health.record_success().await;
+        health.record_success(2).await;
         assert_eq!(health.status(), HealthStatus::Healthy);
         assert_eq!(health.total_requests(), 1);
         assert!(health.last_success().await.is_some());
@@ -624,8 +1198,18 @@ mod tests {
         }
         assert_eq!(health.status(), HealthStatus::Unhealthy);
 
+        // A single success is not enough to recover
+        health.record_success(2).await;
+        assert_eq!(health.status(), HealthStatus::Unhealthy);
+        assert!(health.last_success().await.is_some());
+
+        // A failure resets the streak
+        health.record_failure(3).await;
+        health.record_success(2).await;
+        assert_eq!(health.status(), HealthStatus::Unhealthy);
+
         // Recover
-        health.record_success().await;
+        health.record_success(2).await;
         assert_eq!(health.status(), HealthStatus::Healthy);
     }
 
@@ -673,6 +1257,33 @@ mod tests {
         assert!(!config.auto_health_check);
     }
 
+    #[test]
+    fn test_pool_config_validate() {
+        let valid = || ConnectionPoolConfig::new(vec!["https://node1:50000".to_string()]);
+        assert!(valid().build().is_ok());
+
+        let invalid = [
+            ConnectionPoolConfig::new(vec![]),
+            ConnectionPoolConfig::new(vec![" ".to_string()]),
+            valid().with_failure_threshold(0),
+            valid().with_recovery_threshold(0),
+            valid().with_health_check_interval(Duration::ZERO),
+        ];
+        for config in invalid {
+            assert!(
+                matches!(config.validate(), Err(TalosError::Config(_))),
+                "{config:?}"
+            );
+        }
+
+        // A zero interval is fine when nothing runs on it.
+        assert!(valid()
+            .with_health_check_interval(Duration::ZERO)
+            .disable_auto_health_check()
+            .build()
+            .is_ok());
+    }
+
     #[tokio::test]
     async fn test_connection_pool_empty_endpoints() {
         let config = ConnectionPoolConfig::new(vec![]);
@@ -710,4 +1321,222 @@ mod tests {
         assert_eq!(health.status(), HealthStatus::Unknown);
         assert_eq!(health.consecutive_failures(), 0);
     }
+
+    #[tokio::test]
+    async fn test_connection_pool_stats() {
+        let endpoints = vec![
+            "https://node1:50000".to_string(),
+            "https://node2:50000".to_string(),
+            "https://node3:50000".to_string(),
+        ];
+        let pool = unconnected_pool(ConnectionPoolConfig::new(endpoints));
+
+        let node1 = pool.get_endpoint_health("https://node1:50000").unwrap();
+        node1.record_health_check(true, 3, 2).await;
+        let node2 = pool.get_endpoint_health("https://node2:50000").unwrap();
+        node2.record_success(2).await;
+        for _ in 0..3 {
+            node2.record_failure(3).await;
+        }
+
+        let stats = pool.stats().await;
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.healthy, 1);
+        assert_eq!(stats.unhealthy, 1);
+        assert_eq!(stats.unknown, 1);
+        assert_eq!(stats.connected, 0);
+
+        let names: Vec<_> = stats
+            .endpoints
+            .iter()
+            .map(|e| e.endpoint.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "https://node1:50000",
+                "https://node2:50000",
+                "https://node3:50000"
+            ]
+        );
+        assert!(stats.endpoints[0].since_last_health_check.is_some());
+        assert_eq!(stats.endpoints[1].status, HealthStatus::Unhealthy);
+        assert!((stats.endpoints[1].failure_rate - 0.75).abs() < f64::EPSILON);
+        assert!(stats.endpoints[1].since_last_success.is_some());
+        assert_eq!(stats.endpoints[2].total_requests, 0);
+        assert!(stats.endpoints[2].since_last_health_check.is_none());
+
+        let yaml = serde_yaml::to_string(&stats).unwrap();
+        assert!(yaml.contains("unhealthy: 1"));
+    }
+
+    /// Pool without connections or a health-check task.
+    fn unconnected_pool(config: ConnectionPoolConfig) -> ConnectionPool {
+        ConnectionPool {
+            shared: Arc::new(PoolShared::new(config)),
+            health_check_handle: None,
+        }
+    }
+
+    #[test]
+    fn test_random_selection_seeded() {
+        let endpoints: Vec<String> = (1..=4).map(|i| format!("https://node{i}:50000")).collect();
+        let picks = |seed| {
+            let pool = unconnected_pool(
+                ConnectionPoolConfig::new(endpoints.clone())
+                    .with_load_balancer(LoadBalancer::Random)
+                    .with_rng_seed(seed),
+            );
+            (0..32)
+                .map(|_| pool.select_endpoint(&endpoints).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let sequence = picks(42);
+        assert_eq!(picks(42), sequence);
+        assert_ne!(picks(7), sequence);
+        assert!(sequence.iter().all(|e| endpoints.contains(e)));
+        // Not stuck on a single endpoint.
+        assert!(sequence.iter().any(|e| *e != sequence[0]));
+    }
+
+    #[tokio::test]
+    async fn test_least_connections_selection() {
+        let endpoints: Vec<String> = (1..=3).map(|i| format!("https://node{i}:50000")).collect();
+        let pool = unconnected_pool(
+            ConnectionPoolConfig::new(endpoints.clone())
+                .with_load_balancer(LoadBalancer::LeastConnections),
+        );
+        let load = |endpoint: &str, n| {
+            for _ in 0..n {
+                pool.start_request(endpoint);
+            }
+        };
+
+        load(&endpoints[0], 3);
+        load(&endpoints[1], 1);
+        assert_eq!(pool.select_endpoint(&endpoints).unwrap(), endpoints[2]);
+
+        load(&endpoints[2], 2);
+        assert_eq!(pool.select_endpoint(&endpoints).unwrap(), endpoints[1]);
+
+        // Reported outcomes free their slot; extra reports do not underflow.
+        pool.record_success(&endpoints[0]).await;
+        pool.record_failure(&endpoints[0]).await;
+        pool.release(&endpoints[0]);
+        assert_eq!(pool.select_endpoint(&endpoints).unwrap(), endpoints[0]);
+        pool.release(&endpoints[0]);
+        pool.release(&endpoints[0]);
+        assert_eq!(
+            pool.get_endpoint_health(&endpoints[0]).unwrap().in_flight(),
+            0
+        );
+        assert_eq!(pool.stats().await.endpoints[1].in_flight, 1);
+    }
+
+    #[tokio::test]
+    async fn test_weighted_round_robin_favors_reliable_endpoints() {
+        let endpoints: Vec<String> = (1..=2).map(|i| format!("https://node{i}:50000")).collect();
+        let pool = unconnected_pool(
+            ConnectionPoolConfig::new(endpoints.clone())
+                .with_load_balancer(LoadBalancer::WeightedRoundRobin),
+        );
+        // node1 never fails, node2 fails half of the time.
+        let node2 = pool.get_endpoint_health(&endpoints[1]).unwrap();
+        node2.record_success(2).await;
+        node2.record_failure(3).await;
+
+        let picks: Vec<String> = (0..30)
+            .map(|_| pool.select_endpoint(&endpoints).unwrap())
+            .collect();
+        let node1_picks = picks.iter().filter(|e| **e == endpoints[0]).count();
+        assert_eq!(node1_picks, 20);
+        assert_eq!(picks.len() - node1_picks, 10);
+
+        // Even a constantly failing endpoint keeps a turn per round.
+        for _ in 0..10 {
+            node2.record_failure(100).await;
+        }
+        let picks: Vec<String> = (0..11)
+            .map(|_| pool.select_endpoint(&endpoints).unwrap())
+            .collect();
+        assert_eq!(picks.iter().filter(|e| **e == endpoints[1]).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_selection_skips_open_circuit_breakers() {
+        let endpoints: Vec<String> = (1..=3).map(|i| format!("https://node{i}:50000")).collect();
+        let pool = unconnected_pool(
+            ConnectionPoolConfig::new(endpoints.clone())
+                .with_load_balancer(LoadBalancer::Failover)
+                .with_circuit_breaker(
+                    CircuitBreakerConfig::new()
+                        .with_failure_threshold(1)
+                        .with_reset_timeout(Duration::from_secs(60)),
+                ),
+        );
+        for endpoint in &endpoints {
+            pool.record_success(endpoint).await;
+        }
+        assert_eq!(pool.get_available_endpoints().await, endpoints);
+
+        // One failure opens node1's breaker but leaves it healthy.
+        pool.record_failure(&endpoints[0]).await;
+        assert!(pool
+            .get_endpoint_health(&endpoints[0])
+            .unwrap()
+            .is_healthy());
+        assert_eq!(
+            pool.get_circuit_breaker(&endpoints[0])
+                .unwrap()
+                .state()
+                .await,
+            crate::runtime::CircuitState::Open
+        );
+        let available = pool.get_available_endpoints().await;
+        assert_eq!(available, &endpoints[1..]);
+        assert_eq!(pool.select_endpoint(&available).unwrap(), endpoints[1]);
+
+        // node2 is unhealthy, node3's breaker is open: nothing is left.
+        for _ in 0..3 {
+            pool.get_endpoint_health(&endpoints[1])
+                .unwrap()
+                .record_failure(3)
+                .await;
+        }
+        pool.record_failure(&endpoints[2]).await;
+        match pool.get_client().await {
+            Err(TalosError::CircuitOpen(msg)) => {
+                assert!(
+                    msg.contains("2 with an open circuit breaker, 1 unhealthy"),
+                    "{msg}"
+                );
+            }
+            other => panic!("expected CircuitOpen, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_initial_health_check_delays_jittered() {
+        let interval = Duration::from_secs(30);
+        let config = ConnectionPoolConfig::new(vec![
+            "https://node1:50000".to_string(),
+            "https://node2:50000".to_string(),
+        ])
+        .with_health_check_interval(interval)
+        .with_health_check_jitter_seed(42);
+
+        let delays = config.initial_health_check_delays();
+        assert_eq!(delays.len(), 2);
+        assert_eq!(delays[0].0, "https://node1:50000");
+        assert_ne!(delays[0].1, delays[1].1);
+        assert!(delays.iter().all(|(_, d)| *d < interval));
+        // Each endpoint stays within its own half of the interval.
+        assert!(delays[0].1 < interval / 2 && delays[1].1 >= interval / 2);
+
+        // The same seed yields the same schedule; another seed does not.
+        assert_eq!(config.initial_health_check_delays(), delays);
+        let reseeded = config.with_health_check_jitter_seed(7);
+        assert_ne!(reseeded.initial_health_check_delays(), delays);
+    }
 }
@@ -0,0 +1,426 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Wire transports used by [`TalosClient`](super::TalosClient).
+//!
+//! The default [`Transport::Native`] speaks gRPC over HTTP/2 through a tonic
+//! [`Channel`]. With the `grpc-web` feature enabled, [`Transport::GrpcWeb`]
+//! speaks gRPC-web over HTTP/1.1, for environments where a proxy in front of
+//! the Talos API does not pass HTTP/2 through.
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tonic::body::Body;
+use tonic::codegen::BoxFuture;
+use tonic::transport::Channel;
+
+use super::capabilities::CapabilityCache;
+use super::node_target::{NodeTarget, NODE_METADATA_KEY};
+use crate::runtime::MetricsInterceptor;
+
+/// Error type returned by [`TalosChannel`].
+type ChannelError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Wire transport used to reach the Talos API.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Transport {
+    /// gRPC over HTTP/2 (default).
+    #[default]
+    Native,
+    /// gRPC-web over HTTP/1.1. Requires the `grpc-web` feature.
+    GrpcWeb,
+}
+
+impl fmt::Display for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Native => write!(f, "grpc"),
+            Self::GrpcWeb => write!(f, "grpc-web"),
+        }
+    }
+}
+
+/// Channel carrying gRPC requests over the configured [`Transport`].
+///
+/// This is the service type of the clients returned by
+/// [`TalosClient::machine`](super::TalosClient::machine) and
+/// [`TalosClient::version`](super::TalosClient::version).
+///
+/// Clones share the underlying connection, so
+/// [`TalosClient::reconnect`](super::TalosClient::reconnect) swaps it for
+/// every client built from the same channel.
+///
+/// A channel taken from a client built with
+/// [`TalosClient::with_node`](super::TalosClient::with_node) adds the
+/// [`NODE_METADATA_KEY`] header to every request that does not set it.
+///
+/// With [`TalosClientConfig::metrics`](super::TalosClientConfig::metrics)
+/// set, every call is recorded through a [`MetricsInterceptor`].
+///
+/// Each call waits at most
+/// [`TalosClientConfig::request_timeout`](super::TalosClientConfig::request_timeout)
+/// for the response headers, or the override of a client built with
+/// [`TalosClient::with_call_timeout`](super::TalosClient::with_call_timeout).
+pub struct TalosChannel {
+    shared: Arc<RwLock<Inner>>,
+    /// Connection reserved by `poll_ready` for the next `call`.
+    ready: Option<Inner>,
+    capabilities: CapabilityCache,
+    /// Node metadata added to outgoing requests.
+    nodes: Option<http::HeaderValue>,
+    /// Records each call, if metrics are enabled.
+    metrics: Option<MetricsInterceptor>,
+    /// Default limit on the wait for response headers.
+    request_timeout: Option<Duration>,
+    /// Per-call deadline replacing `request_timeout`, sent as `grpc-timeout`.
+    call_timeout: Option<Duration>,
+}
+
+#[derive(Clone)]
+enum Inner {
+    Native(Channel),
+    #[cfg(feature = "grpc-web")]
+    GrpcWeb(Box<grpc_web::GrpcWebChannel>),
+}
+
+impl TalosChannel {
+    /// Transport this channel speaks.
+    #[must_use]
+    pub fn transport(&self) -> Transport {
+        match &self.current() {
+            Inner::Native(_) => Transport::Native,
+            #[cfg(feature = "grpc-web")]
+            Inner::GrpcWeb(_) => Transport::GrpcWeb,
+        }
+    }
+
+    /// Which methods this channel has seen implemented or not.
+    pub(crate) fn capabilities(&self) -> &CapabilityCache {
+        &self.capabilities
+    }
+
+    /// Use `other`'s connection from now on, here and in every clone.
+    pub(crate) fn replace_connection(&self, other: &TalosChannel) {
+        let fresh = other.current();
+        match self.shared.write() {
+            Ok(mut inner) => *inner = fresh,
+            Err(poisoned) => *poisoned.into_inner() = fresh,
+        }
+    }
+
+    /// Clone this channel, routing its requests to `target`.
+    ///
+    /// The default target removes the routing, so requests go to the
+    /// endpoint itself.
+    pub(crate) fn with_node_target(&self, target: &NodeTarget) -> Self {
+        let mut channel = self.clone();
+        channel.nodes = target
+            .to_csv()
+            .and_then(|nodes| http::HeaderValue::from_str(&nodes).ok());
+        channel
+    }
+
+    /// Record every call made through this channel and its clones.
+    pub(crate) fn with_metrics(mut self, metrics: MetricsInterceptor) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Limit the wait for response headers of every call made through this
+    /// channel and its clones.
+    pub(crate) fn with_request_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Clone this channel, giving its calls a deadline of `timeout` instead
+    /// of the request timeout.
+    ///
+    /// The deadline is sent to the server as `grpc-timeout` metadata, so it
+    /// bounds whole streams too, not just the wait for headers.
+    pub(crate) fn with_call_timeout(&self, timeout: Duration) -> Self {
+        let mut channel = self.clone();
+        channel.call_timeout = Some(timeout);
+        channel
+    }
+
+    /// The per-call deadline set by [`with_call_timeout`](Self::with_call_timeout), if any.
+    pub(crate) fn call_timeout(&self) -> Option<Duration> {
+        self.call_timeout
+    }
+
+    fn current(&self) -> Inner {
+        match self.shared.read() {
+            Ok(inner) => inner.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    fn new(inner: Inner) -> Self {
+        Self {
+            shared: Arc::new(RwLock::new(inner)),
+            ready: None,
+            capabilities: CapabilityCache::default(),
+            nodes: None,
+            metrics: None,
+            request_timeout: None,
+            call_timeout: None,
+        }
+    }
+}
+
+impl Clone for TalosChannel {
+    fn clone(&self) -> Self {
+        // A reserved slot belongs to this handle only; clones start unpolled.
+        Self {
+            shared: self.shared.clone(),
+            ready: None,
+            capabilities: self.capabilities.clone(),
+            nodes: self.nodes.clone(),
+            metrics: self.metrics.clone(),
+            request_timeout: self.request_timeout,
+            call_timeout: self.call_timeout,
+        }
+    }
+}
+
+impl From<Channel> for TalosChannel {
+    fn from(channel: Channel) -> Self {
+        Self::new(Inner::Native(channel))
+    }
+}
+
+impl fmt::Debug for TalosChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TalosChannel")
+            .field(&self.transport())
+            .finish()
+    }
+}
+
+impl tower::Service<http::Request<Body>> for TalosChannel {
+    type Response = http::Response<Body>;
+    type Error = ChannelError;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.ready.is_none() {
+            self.ready = Some(self.current());
+        }
+        match self.ready.as_mut().expect("connection reserved above") {
+            Inner::Native(channel) => {
+                tower::Service::poll_ready(channel, cx).map_err(ChannelError::from)
+            }
+            #[cfg(feature = "grpc-web")]
+            Inner::GrpcWeb(channel) => channel.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, mut request: http::Request<Body>) -> Self::Future {
+        if let Some(nodes) = &self.nodes {
+            // Metadata set on the request itself takes precedence.
+            request
+                .headers_mut()
+                .entry(NODE_METADATA_KEY)
+                .or_insert_with(|| nodes.clone());
+        }
+        if let Some(timeout) = self.call_timeout {
+            request
+                .headers_mut()
+                .entry(GRPC_TIMEOUT_HEADER)
+                .or_insert_with(|| grpc_timeout(timeout));
+        }
+        let timeout = self.call_timeout.or(self.request_timeout);
+        let is_deadline = self.call_timeout.is_some();
+        let path = request.uri().path().to_string();
+        let capabilities = self.capabilities.clone();
+        let mut inner = self.ready.take().unwrap_or_else(|| self.current());
+
+        let future: Self::Future = match &mut inner {
+            Inner::Native(channel) => {
+                let future = tower::Service::call(channel, request);
+                Box::pin(async move {
+                    let response = match timeout {
+                        // Fails the same way as tonic's own `Endpoint::timeout`.
+                        Some(timeout) => match tokio::time::timeout(timeout, future).await {
+                            Ok(response) => response.map_err(ChannelError::from),
+                            Err(_) => Err(ChannelError::from(tonic::TimeoutExpired(()))),
+                        },
+                        None => future.await.map_err(ChannelError::from),
+                    };
+                    if is_deadline {
+                        response.map_err(deadline_exceeded)
+                    } else {
+                        response
+                    }
+                })
+            }
+            #[cfg(feature = "grpc-web")]
+            Inner::GrpcWeb(channel) => channel.call(request, timeout),
+        };
+
+        let metrics = self.metrics.clone().map(|metrics| (metrics, path.clone()));
+        let future: Self::Future = Box::pin(async move {
+            let response = future.await?;
+            capabilities.record(&path, response.headers());
+            Ok(response)
+        });
+        match metrics {
+            Some((metrics, path)) => metrics.instrument(&path, future),
+            None => future,
+        }
+    }
+}
+
+/// gRPC metadata key carrying the call deadline.
+const GRPC_TIMEOUT_HEADER: &str = "grpc-timeout";
+
+/// Encode `timeout` as a `grpc-timeout` value, rounded up to whole
+/// milliseconds (seconds beyond the 8-digit limit).
+fn grpc_timeout(timeout: Duration) -> http::HeaderValue {
+    let millis = timeout.as_nanos().div_ceil(1_000_000);
+    let value = if millis < 100_000_000 {
+        format!("{millis}m")
+    } else {
+        format!("{}S", timeout.as_secs().min(99_999_999))
+    };
+    http::HeaderValue::from_str(&value).expect("digits and a unit are a valid header value")
+}
+
+/// Report an expired call timeout as `DeadlineExceeded`, as gRPC does for
+/// a missed deadline, whichever timer noticed first: ours or the one tonic
+/// sets from the `grpc-timeout` header.
+fn deadline_exceeded(err: ChannelError) -> ChannelError {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(err.as_ref());
+    while let Some(e) = source {
+        if e.is::<tonic::TimeoutExpired>() {
+            return ChannelError::from(tonic::Status::deadline_exceeded("call timeout expired"));
+        }
+        source = e.source();
+    }
+    err
+}
+
+#[cfg(feature = "grpc-web")]
+pub(crate) use grpc_web::connect as connect_grpc_web;
+
+#[cfg(feature = "grpc-web")]
+mod grpc_web {
+    use super::{ChannelError, Inner, TalosChannel};
+    use crate::client::TalosClientConfig;
+    use crate::error::{Result, TalosError};
+    use hyper_rustls::HttpsConnector;
+    use hyper_util::client::legacy::connect::HttpConnector;
+    use hyper_util::client::legacy::Client;
+    use hyper_util::rt::TokioExecutor;
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+    use tonic::body::Body;
+    use tonic::codegen::BoxFuture;
+    use tonic_web::{GrpcWebCall, GrpcWebClientService};
+
+    type HttpClient = Client<HttpsConnector<HttpConnector>, GrpcWebCall<Body>>;
+
+    /// gRPC-web channel over a pooled HTTP/1.1 client.
+    #[derive(Clone)]
+    pub(super) struct GrpcWebChannel {
+        service: GrpcWebClientService<HttpClient>,
+        origin: http::Uri,
+    }
+
+    impl GrpcWebChannel {
+        pub(super) fn poll_ready(
+            &mut self,
+            cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), ChannelError>> {
+            tower::Service::poll_ready(&mut self.service, cx).map_err(ChannelError::from)
+        }
+
+        pub(super) fn call(
+            &mut self,
+            mut request: http::Request<Body>,
+            request_timeout: Option<Duration>,
+        ) -> BoxFuture<http::Response<Body>, ChannelError> {
+            // Generated clients only set the path; point it at the endpoint.
+            let mut parts = self.origin.clone().into_parts();
+            parts.path_and_query = request.uri().path_and_query().cloned();
+            match http::Uri::from_parts(parts) {
+                Ok(uri) => *request.uri_mut() = uri,
+                Err(e) => return Box::pin(async move { Err(ChannelError::from(e)) }),
+            }
+
+            let future = tower::Service::call(&mut self.service, request);
+            Box::pin(async move {
+                let response = match request_timeout {
+                    Some(timeout) => tokio::time::timeout(timeout, future)
+                        .await
+                        .map_err(|_| tonic::Status::deadline_exceeded("request timed out"))?,
+                    None => future.await,
+                }?;
+                Ok(response.map(Body::new))
+            })
+        }
+    }
+
+    /// Build a gRPC-web channel for `config`.
+    ///
+    /// TLS settings (CA, client identity, insecure mode, protocol versions)
+    /// are the same as for the native transport.
+    pub(crate) fn connect(
+        config: &TalosClientConfig,
+        tls_config: Option<rustls::ClientConfig>,
+    ) -> Result<TalosChannel> {
+        let endpoint = if config.endpoint.contains("://") {
+            config.endpoint.clone()
+        } else {
+            format!("https://{}", config.endpoint)
+        };
+        let origin: http::Uri = endpoint
+            .parse()
+            .map_err(|e| TalosError::Config(format!("Invalid endpoint URL: {e}")))?;
+        if origin.host().is_none() {
+            return Err(TalosError::Config("No host in endpoint".to_string()));
+        }
+
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(config.connect_timeout);
+        http.set_nodelay(config.tcp_nodelay);
+        if let Some(interval) = config.keepalive_interval {
+            http.set_keepalive(Some(interval));
+        }
+
+        let builder = hyper_rustls::HttpsConnectorBuilder::new();
+        let connector = match tls_config {
+            Some(tls_config) => builder
+                .with_tls_config(tls_config)
+                .https_or_http()
+                .enable_http1()
+                .wrap_connector(http),
+            None => builder
+                .with_tls_config(
+                    rustls::ClientConfig::builder()
+                        .with_root_certificates(rustls::RootCertStore::empty())
+                        .with_no_client_auth(),
+                )
+                .https_or_http()
+                .enable_http1()
+                .wrap_connector(http),
+        };
+
+        let mut client = Client::builder(TokioExecutor::new());
+        // Prior knowledge only matters for plaintext; TLS keeps ALPN (HTTP/1.1).
+        let is_plaintext = origin.scheme_str() == Some("http");
+        client.http2_only(is_plaintext && config.uses_http2_prior_knowledge());
+        let client = client.build(connector);
+
+        Ok(TalosChannel::new(Inner::GrpcWeb(Box::new(
+            GrpcWebChannel {
+                service: GrpcWebClientService::new(client),
+                origin,
+            },
+        ))))
+    }
+}
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Chunked uploads for client-streaming RPCs.
+//!
+//! Client-streaming RPCs such as `EtcdRecover` take a stream of
+//! `common.Data` messages. [`ChunkedReader`] and [`Rechunk`] cut an
+//! `AsyncRead` or a byte stream into bounded chunks, and [`UploadStream`]
+//! turns those chunks into request messages. Everything is pull-based: the
+//! next chunk is only read once tonic has sent the previous one, so large
+//! uploads never buffer more than one chunk.
+
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+
+use bytes::Bytes;
+use tokio::io::{AsyncRead, ReadBuf};
+use tonic::codegen::tokio_stream::Stream;
+
+use crate::api::common::Data;
+
+/// Default upload chunk size (1 MiB), well below gRPC's 4 MiB message limit.
+pub const DEFAULT_UPLOAD_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Stream of `chunk_size` chunks read from an [`AsyncRead`].
+///
+/// Every chunk but the last is exactly `chunk_size` bytes.
+pub struct ChunkedReader<R> {
+    reader: R,
+    buf: Vec<u8>,
+    filled: usize,
+    done: bool,
+}
+
+impl<R: AsyncRead + Unpin> ChunkedReader<R> {
+    /// Read `reader` in chunks of `chunk_size` bytes (at least 1).
+    #[must_use]
+    pub fn new(reader: R, chunk_size: usize) -> Self {
+        Self {
+            reader,
+            buf: vec![0; chunk_size.max(1)],
+            filled: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for ChunkedReader<R> {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        while !this.done && this.filled < this.buf.len() {
+            let mut read_buf = ReadBuf::new(&mut this.buf[this.filled..]);
+            if let Err(e) = ready!(Pin::new(&mut this.reader).poll_read(cx, &mut read_buf)) {
+                this.done = true;
+                return Poll::Ready(Some(Err(e)));
+            }
+            match read_buf.filled().len() {
+                0 => this.done = true,
+                n => this.filled += n,
+            }
+        }
+
+        if this.filled == 0 {
+            return Poll::Ready(None);
+        }
+        let chunk = Bytes::copy_from_slice(&this.buf[..this.filled]);
+        this.filled = 0;
+        Poll::Ready(Some(Ok(chunk)))
+    }
+}
+
+/// Stream splitting oversized items of a byte stream into `chunk_size` pieces.
+///
+/// Items at or below `chunk_size` pass through unchanged; larger ones are
+/// split without copying.
+pub struct Rechunk<S> {
+    inner: S,
+    chunk_size: usize,
+    pending: Bytes,
+}
+
+impl<S> Rechunk<S>
+where
+    S: Stream<Item = Bytes> + Unpin,
+{
+    /// Split items of `inner` larger than `chunk_size` bytes (at least 1).
+    #[must_use]
+    pub fn new(inner: S, chunk_size: usize) -> Self {
+        Self {
+            inner,
+            chunk_size: chunk_size.max(1),
+            pending: Bytes::new(),
+        }
+    }
+}
+
+impl<S> Stream for Rechunk<S>
+where
+    S: Stream<Item = Bytes> + Unpin,
+{
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        while this.pending.is_empty() {
+            match ready!(Pin::new(&mut this.inner).poll_next(cx)) {
+                Some(bytes) => this.pending = bytes,
+                None => return Poll::Ready(None),
+            }
+        }
+
+        let len = this.pending.len().min(this.chunk_size);
+        Poll::Ready(Some(Ok(this.pending.split_to(len))))
+    }
+}
+
+/// Request stream of `common.Data` messages for a client-streaming RPC.
+///
+/// tonic request streams cannot fail, so a read error ends the stream early
+/// and is kept in the [`UploadError`] handle returned by [`new`](Self::new).
+/// Check it after the call: the server may well accept a truncated upload.
+pub struct UploadStream<S> {
+    chunks: S,
+    error: UploadError,
+}
+
+/// Read error that ended an [`UploadStream`], if any.
+#[derive(Debug, Clone, Default)]
+pub struct UploadError(Arc<Mutex<Option<io::Error>>>);
+
+impl UploadError {
+    /// Take the read error, if the upload stopped because of one.
+    #[must_use]
+    pub fn take(&self) -> Option<io::Error> {
+        self.0.lock().ok().and_then(|mut error| error.take())
+    }
+}
+
+impl<S> UploadStream<S>
+where
+    S: Stream<Item = io::Result<Bytes>> + Unpin,
+{
+    /// Wrap a chunk stream, returning it with its error handle.
+    #[must_use]
+    pub fn new(chunks: S) -> (Self, UploadError) {
+        let error = UploadError::default();
+        (
+            Self {
+                chunks,
+                error: error.clone(),
+            },
+            error,
+        )
+    }
+}
+
+impl<S> Stream for UploadStream<S>
+where
+    S: Stream<Item = io::Result<Bytes>> + Unpin,
+{
+    type Item = Data;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        match ready!(Pin::new(&mut this.chunks).poll_next(cx)) {
+            Some(Ok(bytes)) => Poll::Ready(Some(Data {
+                metadata: None,
+                bytes: bytes.into(),
+            })),
+            Some(Err(e)) => {
+                if let Ok(mut error) = this.error.0.lock() {
+                    *error = Some(e);
+                }
+                Poll::Ready(None)
+            }
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_stream::StreamExt;
+
+    #[tokio::test]
+    async fn test_chunked_reader_splits_and_reassembles() {
+        let input: Vec<u8> = (0..3 * 1024 * 1024 + 512 * 1024)
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        let chunks: Vec<Bytes> = ChunkedReader::new(std::io::Cursor::new(input.clone()), 1 << 20)
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks[..3].iter().all(|c| c.len() == 1 << 20));
+        assert_eq!(chunks[3].len(), 512 * 1024);
+        assert_eq!(chunks.concat(), input);
+    }
+
+    #[tokio::test]
+    async fn test_rechunk_splits_large_items() {
+        let items = tokio_stream::iter(vec![
+            Bytes::from_static(b"abcdefg"),
+            Bytes::from_static(b"hi"),
+        ]);
+
+        let chunks: Vec<Bytes> = Rechunk::new(items, 3)
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(chunks, vec!["abc", "def", "g", "hi"]);
+    }
+
+    #[tokio::test]
+    async fn test_upload_stream_keeps_read_error() {
+        let chunks = tokio_stream::iter(vec![
+            Ok(Bytes::from_static(b"part")),
+            Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated")),
+            Ok(Bytes::from_static(b"never sent")),
+        ]);
+
+        let (upload, error) = UploadStream::new(chunks);
+        let messages: Vec<Data> = upload.collect().await;
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].bytes, b"part");
+        assert_eq!(error.take().unwrap().kind(), io::ErrorKind::UnexpectedEof);
+        assert!(error.take().is_none());
+    }
+}
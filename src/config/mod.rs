@@ -14,6 +14,15 @@
 //! - `TALOS_ENDPOINTS` - Override endpoints (comma-separated)
 //! - `TALOS_NODES` - Target specific nodes (comma-separated)
 //!
+//! [`TalosClient::from_env`](crate::TalosClient::from_env) additionally applies
+//! the client overrides read by
+//! [`TalosClientConfig::apply_env_overrides`](crate::TalosClientConfig::apply_env_overrides):
+//!
+//! - `TALOS_INSECURE` - Disable TLS verification (`1`/`true`, debugging only)
+//! - `TALOS_CONNECT_TIMEOUT` - Connect timeout (e.g. `10s`, `off`)
+//! - `TALOS_REQUEST_TIMEOUT` - Request timeout (e.g. `60s`, `off`)
+//! - `TALOS_STREAM_IDLE_TIMEOUT` - Stream idle timeout (e.g. `30s`, `off`)
+//!
 //! # Example
 //!
 //! ```no_run
@@ -33,6 +42,7 @@
 mod talosconfig;
 
 pub use talosconfig::{
-    TalosConfig, TalosContext, ENV_TALOSCONFIG, ENV_TALOS_CONTEXT, ENV_TALOS_ENDPOINTS,
-    ENV_TALOS_NODES,
+    ContextCredentials, TalosConfig, TalosContext, ENV_TALOSCONFIG, ENV_TALOS_CONNECT_TIMEOUT,
+    ENV_TALOS_CONTEXT, ENV_TALOS_ENDPOINTS, ENV_TALOS_INSECURE, ENV_TALOS_NODES,
+    ENV_TALOS_REQUEST_TIMEOUT, ENV_TALOS_STREAM_IDLE_TIMEOUT,
 };
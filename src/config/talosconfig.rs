@@ -25,11 +25,13 @@
 //! # }
 //! ```
 
+use base64::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::client::{NodeTarget, TalosClientConfig};
 use crate::error::{Result, TalosError};
 
 /// Environment variable for overriding the config file path
@@ -44,6 +46,18 @@ pub const ENV_TALOS_ENDPOINTS: &str = "TALOS_ENDPOINTS";
 /// Environment variable for specifying target nodes (comma-separated)
 pub const ENV_TALOS_NODES: &str = "TALOS_NODES";
 
+/// Environment variable disabling TLS verification (`1`/`true` or `0`/`false`)
+pub const ENV_TALOS_INSECURE: &str = "TALOS_INSECURE";
+
+/// Environment variable overriding the connect timeout (e.g. `10s`)
+pub const ENV_TALOS_CONNECT_TIMEOUT: &str = "TALOS_CONNECT_TIMEOUT";
+
+/// Environment variable overriding the request timeout (e.g. `60s`)
+pub const ENV_TALOS_REQUEST_TIMEOUT: &str = "TALOS_REQUEST_TIMEOUT";
+
+/// Environment variable overriding the stream idle timeout (e.g. `30s`)
+pub const ENV_TALOS_STREAM_IDLE_TIMEOUT: &str = "TALOS_STREAM_IDLE_TIMEOUT";
+
 /// Represents the entire talosctl configuration file structure
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TalosConfig {
@@ -65,19 +79,56 @@ pub struct TalosContext {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nodes: Option<Vec<String>>,
 
-    /// CA certificate in PEM format
+    /// CA certificate, base64-encoded PEM as written by talosctl (plain PEM
+    /// is accepted too; see [`credentials`](Self::credentials))
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ca: Option<String>,
 
-    /// Client certificate in PEM format
+    /// Client certificate, in the same format as `ca`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub crt: Option<String>,
 
-    /// Client private key in PEM format
+    /// Client private key, in the same format as `ca`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
 }
 
+/// Decoded credentials of a [`TalosContext`], as PEM bytes
+#[derive(Clone, PartialEq, Eq)]
+pub struct ContextCredentials {
+    /// CA certificate
+    pub ca: Vec<u8>,
+    /// Client certificate
+    pub crt: Vec<u8>,
+    /// Client private key
+    pub key: Vec<u8>,
+}
+
+impl std::fmt::Debug for ContextCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextCredentials")
+            .field("ca", &String::from_utf8_lossy(&self.ca))
+            .field("crt", &String::from_utf8_lossy(&self.crt))
+            .field("key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Decode a talosconfig certificate field into PEM bytes.
+///
+/// talosctl stores base64-encoded PEM; plain PEM is passed through.
+#[allow(clippy::result_large_err)]
+fn decode_pem(field: &str, value: &str) -> Result<Vec<u8>> {
+    let value = value.trim();
+    if value.starts_with("-----BEGIN") {
+        return Ok(value.as_bytes().to_vec());
+    }
+    let compact: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    BASE64_STANDARD
+        .decode(compact)
+        .map_err(|e| TalosError::Config(format!("Invalid base64 in context {}: {}", field, e)))
+}
+
 impl TalosConfig {
     /// Load configuration from the default location (~/.talos/config)
     ///
@@ -170,6 +221,23 @@ impl TalosConfig {
             .and_then(|name| self.contexts.get(name))
     }
 
+    /// Nodes requests should be proxied to
+    ///
+    /// A non-empty `TALOS_NODES` takes precedence over the active context's
+    /// `nodes`; with neither, requests go to the endpoint itself.
+    #[must_use]
+    pub fn node_target(&self) -> NodeTarget {
+        if let Ok(nodes) = std::env::var(ENV_TALOS_NODES) {
+            let target = NodeTarget::from_csv(&nodes);
+            if !target.is_default() {
+                return target;
+            }
+        }
+        self.active_context()
+            .map(TalosContext::node_target)
+            .unwrap_or_default()
+    }
+
     /// Get a context by name
     ///
     /// # Arguments
@@ -310,6 +378,91 @@ impl TalosContext {
     pub fn first_node(&self) -> Option<&String> {
         self.nodes.as_ref().and_then(|nodes| nodes.first())
     }
+
+    /// Endpoint URLs, in order, with the default scheme and port filled in
+    ///
+    /// `10.0.0.2` becomes `https://10.0.0.2:50000`; entries with a port or
+    /// scheme keep them.
+    #[must_use]
+    pub fn endpoint_urls(&self) -> Vec<String> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| {
+                if endpoint.contains("://") {
+                    endpoint.clone()
+                } else if endpoint.contains(':') {
+                    format!("https://{}", endpoint)
+                } else {
+                    format!("https://{}:50000", endpoint)
+                }
+            })
+            .collect()
+    }
+
+    /// Nodes requests are proxied to through the endpoint
+    ///
+    /// Like talosctl, without `nodes` requests go to the endpoint itself.
+    #[must_use]
+    pub fn node_target(&self) -> NodeTarget {
+        match &self.nodes {
+            Some(nodes) if !nodes.is_empty() => NodeTarget::from(nodes.clone()),
+            _ => NodeTarget::Default,
+        }
+    }
+
+    /// Decode the context's CA, client certificate and key
+    ///
+    /// Returns `None` unless all three are set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` if a field is neither PEM nor valid base64.
+    #[allow(clippy::result_large_err)]
+    pub fn credentials(&self) -> Result<Option<ContextCredentials>> {
+        let (Some(ca), Some(crt), Some(key)) = (&self.ca, &self.crt, &self.key) else {
+            return Ok(None);
+        };
+        Ok(Some(ContextCredentials {
+            ca: decode_pem("ca", ca)?,
+            crt: decode_pem("crt", crt)?,
+            key: decode_pem("key", key)?,
+        }))
+    }
+
+    /// Build a client configuration for this context's first endpoint
+    ///
+    /// The decoded [`credentials`](Self::credentials) are passed in memory
+    /// via [`with_ca_pem`](TalosClientConfig::with_ca_pem) and
+    /// [`with_client_pem`](TalosClientConfig::with_client_pem); nothing is
+    /// written to disk. `nodes` are not part of the connection; apply
+    /// [`node_target`](Self::node_target) to the client, or use
+    /// [`TalosClient::from_context`](crate::TalosClient::from_context).
+    ///
+    /// A context from [`TalosConfig::load_with_env`] already reflects
+    /// `TALOS_ENDPOINTS` and `TALOS_NODES`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` if the context has no endpoints, or the
+    /// certificates cannot be decoded.
+    #[allow(clippy::result_large_err)]
+    pub fn to_client_config(&self) -> Result<TalosClientConfig> {
+        let endpoint_url = self
+            .endpoint_urls()
+            .into_iter()
+            .next()
+            .ok_or_else(|| TalosError::Config("No endpoints in context".to_string()))?;
+
+        let mut client_config = TalosClientConfig::new(endpoint_url);
+
+        if let Some(credentials) = self.credentials()? {
+            client_config = client_config
+                .with_ca_pem(credentials.ca)
+                .with_client_pem(credentials.crt, credentials.key);
+        }
+
+        Ok(client_config)
+    }
 }
 
 #[cfg(test)]
@@ -400,6 +553,97 @@ contexts:
         assert_eq!(ctx.first_node(), Some(&"192.168.1.11".to_string()));
     }
 
+    #[test]
+    fn test_endpoints_and_nodes_are_distinct() {
+        let config = TalosConfig::from_yaml(SAMPLE_CONFIG).unwrap();
+        let ctx = config.get_context("another-cluster").unwrap();
+
+        assert_eq!(
+            ctx.endpoint_urls(),
+            vec!["https://192.168.1.10:50000".to_string()]
+        );
+        assert_eq!(
+            ctx.node_target(),
+            NodeTarget::multiple(["192.168.1.11", "192.168.1.12"])
+        );
+
+        // Endpoints only: requests go to the endpoint itself.
+        let ctx = config.get_context("my-cluster").unwrap();
+        assert!(ctx.node_target().is_default());
+    }
+
+    #[test]
+    fn test_to_client_config_uses_first_endpoint() {
+        let ctx = TalosContext {
+            endpoints: vec!["10.0.0.2:50001".to_string(), "10.0.0.3".to_string()],
+            nodes: Some(vec!["10.0.0.5".to_string()]),
+            ca: None,
+            crt: None,
+            key: None,
+        };
+        let client_config = ctx.to_client_config().unwrap();
+        assert_eq!(client_config.endpoint, "https://10.0.0.2:50001");
+        assert!(client_config.ca_path.is_none());
+
+        let empty = TalosContext {
+            endpoints: vec![],
+            ..ctx
+        };
+        assert!(matches!(
+            empty.to_client_config(),
+            Err(TalosError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_credentials_decode_base64_and_pem() {
+        let ca = "-----BEGIN CERTIFICATE-----\nY2E=\n-----END CERTIFICATE-----";
+        let crt = "-----BEGIN CERTIFICATE-----\nY3J0\n-----END CERTIFICATE-----";
+        let key = "-----BEGIN ED25519 PRIVATE KEY-----\na2V5\n-----END ED25519 PRIVATE KEY-----";
+        let encoded = BASE64_STANDARD.encode(crt);
+        let ctx = TalosContext {
+            endpoints: vec!["10.0.0.2".to_string()],
+            nodes: None,
+            // Wrapped base64, as some tools write it
+            ca: Some(format!(
+                "{}\n{}",
+                &BASE64_STANDARD.encode(ca)[..20],
+                &BASE64_STANDARD.encode(ca)[20..]
+            )),
+            crt: Some(encoded),
+            key: Some(key.to_string()),
+        };
+
+        let credentials = ctx.credentials().unwrap().unwrap();
+        assert_eq!(credentials.ca, ca.as_bytes());
+        assert_eq!(credentials.crt, crt.as_bytes());
+        assert_eq!(credentials.key, key.as_bytes());
+        assert!(!format!("{:?}", credentials).contains("a2V5"));
+
+        let client_config = ctx.to_client_config().unwrap();
+        assert!(client_config.ca_path.is_none());
+        assert!(client_config.key_path.is_none());
+        assert_eq!(client_config.ca_pem.as_deref(), Some(ca.as_bytes()));
+        let identity = client_config.client_pem.unwrap();
+        assert_eq!(identity.crt, crt.as_bytes());
+        assert_eq!(identity.key, key.as_bytes());
+
+        let partial = TalosContext {
+            key: None,
+            ..ctx.clone()
+        };
+        assert!(partial.credentials().unwrap().is_none());
+
+        let invalid = TalosContext {
+            crt: Some("not base64!".to_string()),
+            ..ctx
+        };
+        assert!(matches!(
+            invalid.credentials(),
+            Err(TalosError::Config(msg)) if msg.contains("crt")
+        ));
+    }
+
     #[test]
     fn test_missing_context() {
         let config = TalosConfig::from_yaml(SAMPLE_CONFIG).unwrap();
@@ -8,12 +8,23 @@ pub enum TalosError {
     #[error("Configuration error: {0}")]
     Config(String),
 
-    #[error("API request failed: {0}")]
+    #[error("API request failed: {}", status_summary(.0))]
     Api(#[from] tonic::Status),
 
     #[error("Transport error: {0}")]
     Transport(#[from] tonic::transport::Error),
 
+    #[error("Transport error for {endpoint}: {source}")]
+    EndpointTransport {
+        endpoint: String,
+        #[source]
+        source: tonic::transport::Error,
+    },
+
+    /// The TLS handshake failed: fix the certificates rather than retry.
+    #[error("TLS handshake failed: {0}")]
+    Tls(String),
+
     #[error("Validation error: {0}")]
     Validation(String),
 
@@ -23,8 +34,407 @@ pub enum TalosError {
     #[error("Circuit breaker is open: {0}")]
     CircuitOpen(String),
 
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
 
+/// Discriminant of a [`TalosError`], without its payload.
+///
+/// Unlike `TalosError` itself this is `PartialEq`, so tests and callers can
+/// write `assert_eq!(err.kind(), TalosErrorKind::Timeout)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TalosErrorKind {
+    /// [`TalosError::Config`].
+    Config,
+    /// [`TalosError::Api`].
+    Api,
+    /// [`TalosError::Transport`] or [`TalosError::EndpointTransport`].
+    Transport,
+    /// [`TalosError::Tls`].
+    Tls,
+    /// [`TalosError::Validation`].
+    Validation,
+    /// [`TalosError::Connection`].
+    Connection,
+    /// [`TalosError::CircuitOpen`].
+    CircuitOpen,
+    /// [`TalosError::Timeout`].
+    Timeout,
+    /// [`TalosError::NotFound`].
+    NotFound,
+    /// [`TalosError::Unknown`].
+    Unknown,
+}
+
+impl TalosError {
+    /// Transport error annotated with the endpoint it occurred on.
+    ///
+    /// The `From` conversion cannot know the endpoint; connection paths that
+    /// juggle several endpoints (pool, discovery, `new_multi`) use this so the
+    /// failing node shows up in the message. The original error stays
+    /// available as the source.
+    #[must_use]
+    pub fn transport_with_endpoint(
+        source: tonic::transport::Error,
+        endpoint: impl Into<String>,
+    ) -> Self {
+        Self::EndpointTransport {
+            endpoint: endpoint.into(),
+            source,
+        }
+    }
+
+    /// Error for a failed connection attempt.
+    ///
+    /// A failed TLS handshake becomes [`Tls`](Self::Tls) with a readable
+    /// reason; anything else, such as a refused connection, stays a
+    /// [`Transport`](Self::Transport) error.
+    #[must_use]
+    pub fn from_connect_error(source: tonic::transport::Error) -> Self {
+        match find_tls_error(&source).map(describe_tls_error) {
+            Some(reason) => Self::Tls(reason),
+            None => Self::Transport(source),
+        }
+    }
+
+    /// Attach `endpoint` to a plain [`Transport`](Self::Transport) or
+    /// [`Tls`](Self::Tls) error; other errors are returned unchanged.
+    #[must_use]
+    pub(crate) fn at_endpoint(self, endpoint: &str) -> Self {
+        match self {
+            Self::Transport(source) => Self::transport_with_endpoint(source, endpoint),
+            Self::Tls(reason) => Self::Tls(format!("{reason} ({endpoint})")),
+            other => other,
+        }
+    }
+
+    /// Return the kind of this error.
+    ///
+    /// ```
+    /// use talos_api_rs::{TalosError, TalosErrorKind};
+    ///
+    /// let err = TalosError::Timeout("etcd leader".to_string());
+    /// assert_eq!(err.kind(), TalosErrorKind::Timeout);
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> TalosErrorKind {
+        match self {
+            Self::Config(_) => TalosErrorKind::Config,
+            Self::Api(_) => TalosErrorKind::Api,
+            Self::Transport(_) | Self::EndpointTransport { .. } => TalosErrorKind::Transport,
+            Self::Tls(_) => TalosErrorKind::Tls,
+            Self::Validation(_) => TalosErrorKind::Validation,
+            Self::Connection(_) => TalosErrorKind::Connection,
+            Self::CircuitOpen(_) => TalosErrorKind::CircuitOpen,
+            Self::Timeout(_) => TalosErrorKind::Timeout,
+            Self::NotFound(_) => TalosErrorKind::NotFound,
+            Self::Unknown(_) => TalosErrorKind::Unknown,
+        }
+    }
+
+    /// Iterate over this error and every error in its `source()` chain.
+    fn chain(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> {
+        std::iter::successors(Some(self as &(dyn std::error::Error + 'static)), |e| {
+            e.source()
+        })
+    }
+
+    /// Return `true` if this error or any error in its source chain is a `T`.
+    ///
+    /// ```
+    /// use talos_api_rs::TalosError;
+    ///
+    /// let io = std::io::Error::from(std::io::ErrorKind::ConnectionReset);
+    /// let err = TalosError::Api(tonic::Status::from_error(Box::new(io)));
+    /// assert!(err.source_is::<std::io::Error>());
+    /// ```
+    #[must_use]
+    pub fn source_is<T: std::error::Error + 'static>(&self) -> bool {
+        self.find_source::<T>().is_some()
+    }
+
+    /// Return the first error of type `T` in the source chain, if any.
+    #[must_use]
+    pub fn find_source<T: std::error::Error + 'static>(&self) -> Option<&T> {
+        self.chain().find_map(|e| e.downcast_ref::<T>())
+    }
+
+    /// Return the kind of the underlying I/O error, if the chain contains one.
+    ///
+    /// Useful to tell a node that is down (`ConnectionRefused`) apart from a
+    /// transient network failure (`ConnectionReset`) worth retrying.
+    #[must_use]
+    pub fn io_kind(&self) -> Option<std::io::ErrorKind> {
+        self.find_source::<std::io::Error>()
+            .map(std::io::Error::kind)
+    }
+
+    /// Return the gRPC status the node answered with, if this error is one.
+    ///
+    /// Gives access to the status details and trailer metadata.
+    #[must_use]
+    pub fn grpc_status(&self) -> Option<&tonic::Status> {
+        self.find_source::<tonic::Status>()
+    }
+
+    /// Return the gRPC status code the node answered with, if any.
+    ///
+    /// `None` for errors that did not come from a gRPC status, such as
+    /// timeouts or transport failures; match on the code instead of
+    /// scraping the message.
+    ///
+    /// ```
+    /// use talos_api_rs::TalosError;
+    ///
+    /// let err = TalosError::Api(tonic::Status::already_exists("cluster is already bootstrapped"));
+    /// assert_eq!(err.grpc_code(), Some(tonic::Code::AlreadyExists));
+    /// assert_eq!(err.to_string(), "API request failed: AlreadyExists: cluster is already bootstrapped");
+    /// ```
+    #[must_use]
+    pub fn grpc_code(&self) -> Option<tonic::Code> {
+        self.grpc_status().map(tonic::Status::code)
+    }
+
+    /// Return the gRPC status message the node answered with, if any.
+    #[must_use]
+    pub fn grpc_message(&self) -> Option<&str> {
+        self.grpc_status().map(tonic::Status::message)
+    }
+
+    /// Return `true` if the endpoint refused the connection.
+    ///
+    /// The node is down or not listening yet, so retrying may help.
+    #[must_use]
+    pub fn is_connection_refused(&self) -> bool {
+        self.io_kind() == Some(std::io::ErrorKind::ConnectionRefused)
+    }
+
+    /// Return `true` if the TLS handshake failed.
+    ///
+    /// Covers [`Tls`](Self::Tls) as well as handshake failures surfacing
+    /// later, e.g. as an `Unavailable` status once a lost connection is
+    /// re-established. Retrying won't help; check the CA, client
+    /// certificate and endpoint instead.
+    #[must_use]
+    pub fn is_tls_failure(&self) -> bool {
+        matches!(self, Self::Tls(_)) || find_tls_error(self).is_some()
+    }
+}
+
+/// `Code: message`, or just the code if the message is empty.
+fn status_summary(status: &tonic::Status) -> String {
+    match status.message() {
+        "" => format!("{:?}", status.code()),
+        message => format!("{:?}: {}", status.code(), message),
+    }
+}
+
+/// Return the first rustls error in the source chain of `err`.
+///
+/// tokio-rustls reports handshake failures as an `io::Error` wrapping the
+/// rustls error, which `io::Error::source` skips, so look inside those too.
+fn find_tls_error<'a>(err: &'a (dyn std::error::Error + 'static)) -> Option<&'a rustls::Error> {
+    std::iter::successors(Some(err), |e| e.source()).find_map(|e| {
+        e.downcast_ref::<rustls::Error>().or_else(|| {
+            e.downcast_ref::<std::io::Error>()
+                .and_then(std::io::Error::get_ref)
+                .and_then(|inner| inner.downcast_ref::<rustls::Error>())
+        })
+    })
+}
+
+/// Explain a handshake failure in terms of what to check.
+fn describe_tls_error(err: &rustls::Error) -> String {
+    use rustls::{AlertDescription, CertificateError};
+
+    match err {
+        rustls::Error::InvalidCertificate(CertificateError::UnknownIssuer) => {
+            "certificate verification failed: unknown CA".to_string()
+        }
+        rustls::Error::InvalidCertificate(cert) => {
+            format!("certificate verification failed: {cert}")
+        }
+        rustls::Error::AlertReceived(
+            alert @ (AlertDescription::BadCertificate
+            | AlertDescription::UnsupportedCertificate
+            | AlertDescription::CertificateUnknown
+            | AlertDescription::CertificateRequired
+            | AlertDescription::UnknownCA
+            | AlertDescription::AccessDenied),
+        ) => format!("server rejected the client certificate ({alert:?})"),
+        rustls::Error::NoCertificatesPresented => "server presented no certificate".to_string(),
+        rustls::Error::NoApplicationProtocol => {
+            "server does not support HTTP/2 (ALPN h2)".to_string()
+        }
+        rustls::Error::InvalidMessage(_) | rustls::Error::InappropriateMessage { .. } => {
+            format!("endpoint does not speak TLS ({err})")
+        }
+        other => other.to_string(),
+    }
+}
+
 pub type Result<T> = std::result::Result<T, TalosError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_io_kind_through_status() {
+        let refused = io::Error::new(io::ErrorKind::ConnectionRefused, "connection refused");
+        let err = TalosError::Api(tonic::Status::from_error(Box::new(refused)));
+
+        assert_eq!(err.io_kind(), Some(io::ErrorKind::ConnectionRefused));
+        assert!(err.source_is::<io::Error>());
+        assert!(err.source_is::<tonic::Status>());
+    }
+
+    #[test]
+    fn test_io_kind_absent() {
+        let err = TalosError::Connection("no endpoints".to_string());
+        assert_eq!(err.io_kind(), None);
+        assert!(!err.source_is::<io::Error>());
+        assert!(err.source_is::<TalosError>());
+    }
+
+    #[test]
+    fn test_transport_with_endpoint() {
+        let source = tonic::transport::Endpoint::from_shared("not a uri\n").unwrap_err();
+        let err = TalosError::transport_with_endpoint(source, "https://10.0.0.7:50000");
+
+        assert!(err.to_string().contains("https://10.0.0.7:50000"));
+        assert_eq!(err.kind(), TalosErrorKind::Transport);
+        assert!(err.source_is::<tonic::transport::Error>());
+
+        let plain = TalosError::from(tonic::transport::Endpoint::from_shared("\n").unwrap_err());
+        assert!(matches!(
+            plain.at_endpoint("10.0.0.8:50000"),
+            TalosError::EndpointTransport { endpoint, .. } if endpoint == "10.0.0.8:50000"
+        ));
+        assert!(matches!(
+            TalosError::Timeout("x".to_string()).at_endpoint("10.0.0.8:50000"),
+            TalosError::Timeout(_)
+        ));
+    }
+
+    #[test]
+    fn test_grpc_status_accessors() {
+        let err = TalosError::Api(tonic::Status::not_found("file does not exist"));
+        assert_eq!(err.grpc_code(), Some(tonic::Code::NotFound));
+        assert_eq!(err.grpc_message(), Some("file does not exist"));
+        assert_eq!(
+            err.to_string(),
+            "API request failed: NotFound: file does not exist"
+        );
+
+        let mut status = tonic::Status::unavailable("");
+        status
+            .metadata_mut()
+            .insert("x-talos-node", "10.0.0.2".parse().unwrap());
+        let err = TalosError::from(status);
+        assert_eq!(err.to_string(), "API request failed: Unavailable");
+        assert_eq!(
+            err.grpc_status()
+                .unwrap()
+                .metadata()
+                .get("x-talos-node")
+                .unwrap(),
+            "10.0.0.2"
+        );
+
+        let timeout = TalosError::Timeout("etcd leader".to_string());
+        assert_eq!(timeout.grpc_code(), None);
+        assert_eq!(timeout.grpc_message(), None);
+        assert!(timeout.grpc_status().is_none());
+    }
+
+    #[test]
+    fn test_tls_failure_classification() {
+        let wrapped = |err: rustls::Error| {
+            let io = io::Error::new(io::ErrorKind::InvalidData, err);
+            TalosError::Api(tonic::Status::from_error(Box::new(io)))
+        };
+
+        let unknown_ca = wrapped(rustls::Error::InvalidCertificate(
+            rustls::CertificateError::UnknownIssuer,
+        ));
+        assert!(unknown_ca.is_tls_failure());
+        assert!(!unknown_ca.is_connection_refused());
+        assert_eq!(
+            describe_tls_error(find_tls_error(&unknown_ca).unwrap()),
+            "certificate verification failed: unknown CA"
+        );
+        assert_eq!(
+            describe_tls_error(&rustls::Error::AlertReceived(
+                rustls::AlertDescription::CertificateRequired
+            )),
+            "server rejected the client certificate (CertificateRequired)"
+        );
+
+        let tls = TalosError::Tls("certificate verification failed: unknown CA".to_string());
+        assert!(tls.is_tls_failure());
+        assert_eq!(tls.kind(), TalosErrorKind::Tls);
+        assert_eq!(
+            tls.at_endpoint("10.0.0.2:50000").to_string(),
+            "TLS handshake failed: certificate verification failed: unknown CA (10.0.0.2:50000)"
+        );
+
+        let refused = io::Error::new(io::ErrorKind::ConnectionRefused, "connection refused");
+        let refused = TalosError::Api(tonic::Status::from_error(Box::new(refused)));
+        assert!(refused.is_connection_refused());
+        assert!(!refused.is_tls_failure());
+
+        let plain = TalosError::from_connect_error(
+            tonic::transport::Endpoint::from_shared("\n").unwrap_err(),
+        );
+        assert!(matches!(plain, TalosError::Transport(_)));
+    }
+
+    #[test]
+    fn test_kind() {
+        let cases = [
+            (
+                TalosError::Config("bad".to_string()),
+                TalosErrorKind::Config,
+            ),
+            (
+                TalosError::Api(tonic::Status::unavailable("down")),
+                TalosErrorKind::Api,
+            ),
+            (
+                TalosError::Tls("unknown CA".to_string()),
+                TalosErrorKind::Tls,
+            ),
+            (
+                TalosError::Validation("too big".to_string()),
+                TalosErrorKind::Validation,
+            ),
+            (
+                TalosError::CircuitOpen("node-1".to_string()),
+                TalosErrorKind::CircuitOpen,
+            ),
+            (
+                TalosError::Timeout("ready".to_string()),
+                TalosErrorKind::Timeout,
+            ),
+            (
+                TalosError::NotFound("service".to_string()),
+                TalosErrorKind::NotFound,
+            ),
+        ];
+        for (err, kind) in cases {
+            assert_eq!(err.kind(), kind, "{err}");
+        }
+        assert_ne!(
+            TalosError::Connection("x".to_string()).kind(),
+            TalosErrorKind::Unknown
+        );
+    }
+}
@@ -104,36 +104,50 @@ pub mod runtime;
 pub mod testkit;
 
 pub use client::{
-    ConnectionPool, ConnectionPoolConfig, EndpointHealth, HealthStatus, LoadBalancer, NodeTarget,
-    TalosClient, TalosClientConfig, TalosClientConfigBuilder, NODE_METADATA_KEY,
+    Capabilities, ConnectionPool, ConnectionPoolConfig, EndpointHealth, EndpointStats,
+    HealthStatus, LoadBalancer, NodeTarget, PemIdentity, Pkcs12Identity, PoolStats, TalosChannel,
+    TalosClient, TalosClientConfig, TalosClientConfigBuilder, TlsVersion, Transport,
+    HEDGEABLE_METHODS, NODE_METADATA_KEY, NON_RETRYABLE_METHODS,
 };
 pub use config::{
-    TalosConfig, TalosContext, ENV_TALOSCONFIG, ENV_TALOS_CONTEXT, ENV_TALOS_ENDPOINTS,
-    ENV_TALOS_NODES,
+    ContextCredentials, TalosConfig, TalosContext, ENV_TALOSCONFIG, ENV_TALOS_CONNECT_TIMEOUT,
+    ENV_TALOS_CONTEXT, ENV_TALOS_ENDPOINTS, ENV_TALOS_INSECURE, ENV_TALOS_NODES,
+    ENV_TALOS_REQUEST_TIMEOUT, ENV_TALOS_STREAM_IDLE_TIMEOUT,
 };
-pub use error::TalosError;
+pub use error::{TalosError, TalosErrorKind};
 pub use resources::{
     ApplyConfigurationRequest, ApplyConfigurationResponse, ApplyConfigurationResult, ApplyMode,
-    BootstrapRequest, BootstrapResponse, BootstrapResult, ConnectionRecord, ConnectionState,
-    ContainerDriver, ContainerdNamespace, CopyRequest, CopyResponse, CpuInfo, CpuInfoResponse,
-    CpuInfoResult, DiskStat, DiskStatsResponse, DiskStatsResult, DiskUsageInfo, DiskUsageRequest,
-    DiskUsageResponse, DmesgRequest, DmesgResponse, EtcdAlarmDisarmResponse, EtcdAlarmListResponse,
-    EtcdAlarmType, EtcdDefragmentResponse, EtcdForfeitLeadershipRequest,
-    EtcdForfeitLeadershipResponse, EtcdLeaveClusterRequest, EtcdLeaveClusterResponse, EtcdMember,
-    EtcdMemberAlarm, EtcdMemberListRequest, EtcdMemberListResponse, EtcdMemberStatus,
-    EtcdRemoveMemberByIdRequest, EtcdRemoveMemberByIdResponse, EtcdStatusResponse, FileInfo,
-    FileType, GenerateClientConfigurationRequest, GenerateClientConfigurationResponse,
-    GenerateClientConfigurationResult, ImageInfo, ImageListRequest, ImagePullRequest,
-    ImagePullResponse, ImagePullResult, KubeconfigResponse, L4ProtoFilter, ListRequest,
-    ListResponse, LoadAvgResponse, LoadAvgResult, LogsRequest, LogsResponse, MemoryResponse,
-    MemoryResult, MountStat, MountsResponse, MountsResult, NetDevStat, NetstatFilter,
-    NetstatRequest, NetstatResponse, NetstatResult, NetworkDeviceStatsResponse,
-    NetworkDeviceStatsResult, PacketCaptureRequest, PacketCaptureResponse, ProcessInfo,
-    ProcessesResponse, ProcessesResult, ReadRequest, ReadResponse, ResetPartitionSpec,
-    ResetRequest, ResetResponse, ResetResult, RollbackResponse, RollbackResult,
-    ServiceRestartRequest, ServiceRestartResponse, ServiceStartRequest, ServiceStartResponse,
-    ServiceStopRequest, ServiceStopResponse, UpgradeRebootMode, UpgradeRequest, UpgradeResponse,
-    UpgradeResult, WipeMode,
+    ApplyModeDetails, ApplyRollbackOutcome, ApplyRollbackPolicy, ApplyWarning, BootstrapRequest,
+    BootstrapResponse, BootstrapResult, BpfFilter, BpfInstruction, ClusterHealthCheckRequest,
+    ConnectionRecord, ConnectionState, ContainerDriver, ContainerInfo, ContainerStat,
+    ContainerStatsResponse, ContainerStatsResult, ContainerdNamespace, ContainersRequest,
+    ContainersResponse, ContainersResult, CopyRequest, CopyResponse, CosiEvent, CosiEventType,
+    CosiMetadata, CosiResource, CosiWatchRequest, CpuInfo, CpuInfoResponse, CpuInfoResult, CpuStat,
+    DataChunk, DiskStat, DiskStatsResponse, DiskStatsResult, DiskUsageInfo, DiskUsageRequest,
+    DiskUsageResponse, DmesgChunk, DmesgRequest, DmesgResponse, DrainReport, DrainStep, DuNode,
+    EtcdAlarmDisarmResponse, EtcdAlarmListResponse, EtcdAlarmType, EtcdDefragmentResponse,
+    EtcdForfeitLeadershipRequest, EtcdForfeitLeadershipResponse, EtcdLeaveClusterRequest,
+    EtcdLeaveClusterResponse, EtcdMember, EtcdMemberAlarm, EtcdMemberListRequest,
+    EtcdMemberListResponse, EtcdMemberStatus, EtcdRemoveMemberByIdRequest,
+    EtcdRemoveMemberByIdResponse, EtcdStatusResponse, FileInfo, FileType,
+    GenerateClientConfigurationRequest, GenerateClientConfigurationResponse,
+    GenerateClientConfigurationResult, HealthCheckProgress, HostnameResponse, HostnameResult,
+    ImageInfo, ImageListRequest, ImagePullRequest, ImagePullResponse, ImagePullResult,
+    KubeconfigResponse, L4ProtoFilter, ListRequest, ListResponse, LoadAvgResponse, LoadAvgResult,
+    LogChunk, LogsRequest, LogsResponse, MemoryResponse, MemoryResult, MetaDeleteRequest,
+    MetaDeleteResponse, MetaDeleteResult, MetaWriteRequest, MetaWriteResponse, MetaWriteResult,
+    MountStat, MountsResponse, MountsResult, NetDevStat, NetstatFilter, NetstatRequest,
+    NetstatResponse, NetstatResult, NetworkDeviceStatsResponse, NetworkDeviceStatsResult,
+    NodeApplyWarnings, NodeUpgradeOutcome, NodeUpgradeStatus, PacketCaptureRequest,
+    PacketCaptureResponse, ProcessInfo, ProcessNode, ProcessesResponse, ProcessesResult,
+    ReadRequest, ReadResponse, RebootMode, RebootRequest, RebootResponse, RebootResult,
+    ResetPartitionSpec, ResetRequest, ResetResponse, ResetResult, RollbackResponse, RollbackResult,
+    RollingUpgradeOptions, RollingUpgradeOrder, RollingUpgradeReport, ServiceEvent, ServiceInfo,
+    ServiceListResponse, ServiceListResult, ServiceRestartRequest, ServiceRestartResponse,
+    ServiceStartRequest, ServiceStartResponse, ServiceStopRequest, ServiceStopResponse,
+    ShutdownRequest, ShutdownResponse, ShutdownResult, SystemStatResponse, SystemStatResult,
+    UpgradeRebootMode, UpgradeRequest, UpgradeResponse, UpgradeResult, VersionResponse,
+    VersionResult, WarningKind, WarningSeverity, WipeMode,
 };
 pub use runtime::{
     BackoffStrategy, CircuitBreaker, CircuitBreakerConfig, CircuitState, CustomRetryPolicy,
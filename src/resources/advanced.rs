@@ -4,6 +4,10 @@
 //!
 //! Provides Rollback, GenerateClientConfiguration, PacketCapture, and Netstat operations.
 
+use std::collections::HashMap;
+
+use super::bpf::BpfFilter;
+use super::system::{ProcessInfo, ProcessesResponse};
 use crate::api::generated::machine::{
     ConnectRecord as ProtoConnectRecord, GenerateClientConfiguration as ProtoGenerateClientConfig,
     GenerateClientConfigurationRequest as ProtoGenerateClientConfigRequest,
@@ -12,6 +16,7 @@ use crate::api::generated::machine::{
     NetstatResponse as ProtoNetstatResponse, PacketCaptureRequest as ProtoPacketCaptureRequest,
     RollbackResponse as ProtoRollbackResponse,
 };
+use crate::error::{Result, TalosError};
 
 // =============================================================================
 // Rollback
@@ -63,13 +68,27 @@ impl RollbackResponse {
 // GenerateClientConfiguration
 // =============================================================================
 
+/// Roles understood by Talos' role-based access control.
+pub const KNOWN_ROLES: &[&str] = &[
+    "os:admin",
+    "os:operator",
+    "os:reader",
+    "os:etcd:backup",
+    "os:impersonator",
+];
+
+/// Certificate TTL used when a request does not set one (24 hours).
+pub const DEFAULT_CRT_TTL_SECONDS: i64 = 24 * 3600;
+
 /// Request to generate client configuration (talosconfig).
 #[derive(Debug, Clone, Default)]
 pub struct GenerateClientConfigurationRequest {
     /// Roles for the generated client certificate.
     pub roles: Vec<String>,
-    /// Certificate TTL in seconds.
+    /// Certificate TTL in seconds; [`DEFAULT_CRT_TTL_SECONDS`] when unset.
     pub crt_ttl_seconds: Option<i64>,
+    /// Accept roles outside [`KNOWN_ROLES`], e.g. ones added by newer Talos releases.
+    pub allow_unknown_roles: bool,
 }
 
 impl GenerateClientConfigurationRequest {
@@ -84,7 +103,7 @@ impl GenerateClientConfigurationRequest {
     pub fn with_roles(roles: Vec<String>) -> Self {
         Self {
             roles,
-            crt_ttl_seconds: None,
+            ..Self::default()
         }
     }
 
@@ -93,16 +112,52 @@ impl GenerateClientConfigurationRequest {
     pub fn builder() -> GenerateClientConfigurationRequestBuilder {
         GenerateClientConfigurationRequestBuilder::default()
     }
+
+    /// Certificate TTL in seconds that will be requested.
+    #[must_use]
+    pub fn effective_crt_ttl_seconds(&self) -> i64 {
+        self.crt_ttl_seconds.unwrap_or(DEFAULT_CRT_TTL_SECONDS)
+    }
+
+    /// Check roles and TTL before sending the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` for a role outside [`KNOWN_ROLES`]
+    /// (unless `allow_unknown_roles` is set) or a non-positive TTL.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> Result<()> {
+        if !self.allow_unknown_roles {
+            if let Some(role) = self
+                .roles
+                .iter()
+                .find(|role| !KNOWN_ROLES.contains(&role.as_str()))
+            {
+                return Err(TalosError::Validation(format!(
+                    "unknown role '{}' (expected one of {})",
+                    role,
+                    KNOWN_ROLES.join(", ")
+                )));
+            }
+        }
+        if self.effective_crt_ttl_seconds() <= 0 {
+            return Err(TalosError::Validation(format!(
+                "certificate TTL must be positive, got {}s",
+                self.effective_crt_ttl_seconds()
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl From<GenerateClientConfigurationRequest> for ProtoGenerateClientConfigRequest {
     fn from(req: GenerateClientConfigurationRequest) -> Self {
         Self {
-            roles: req.roles,
-            crt_ttl: req.crt_ttl_seconds.map(|s| prost_types::Duration {
-                seconds: s,
+            crt_ttl: Some(prost_types::Duration {
+                seconds: req.effective_crt_ttl_seconds(),
                 nanos: 0,
             }),
+            roles: req.roles,
         }
     }
 }
@@ -112,6 +167,7 @@ impl From<GenerateClientConfigurationRequest> for ProtoGenerateClientConfigReque
 pub struct GenerateClientConfigurationRequestBuilder {
     roles: Vec<String>,
     crt_ttl_seconds: Option<i64>,
+    allow_unknown_roles: bool,
 }
 
 impl GenerateClientConfigurationRequestBuilder {
@@ -150,12 +206,20 @@ impl GenerateClientConfigurationRequestBuilder {
         self
     }
 
+    /// Accept roles outside [`KNOWN_ROLES`].
+    #[must_use]
+    pub fn allow_unknown_roles(mut self, allow: bool) -> Self {
+        self.allow_unknown_roles = allow;
+        self
+    }
+
     /// Build the request.
     #[must_use]
     pub fn build(self) -> GenerateClientConfigurationRequest {
         GenerateClientConfigurationRequest {
             roles: self.roles,
             crt_ttl_seconds: self.crt_ttl_seconds,
+            allow_unknown_roles: self.allow_unknown_roles,
         }
     }
 }
@@ -253,6 +317,8 @@ pub struct PacketCaptureRequest {
     pub promiscuous: bool,
     /// Snap length in bytes.
     pub snap_len: u32,
+    /// Kernel filter; `None` captures every packet.
+    pub bpf_filter: Option<BpfFilter>,
 }
 
 impl PacketCaptureRequest {
@@ -262,10 +328,18 @@ impl PacketCaptureRequest {
         Self {
             interface: interface.into(),
             promiscuous: false,
-            snap_len: 65535,
+            snap_len: DEFAULT_SNAP_LEN,
+            bpf_filter: None,
         }
     }
 
+    /// Only capture packets matching `filter`.
+    #[must_use]
+    pub fn with_bpf_filter(mut self, filter: BpfFilter) -> Self {
+        self.bpf_filter = Some(filter);
+        self
+    }
+
     /// Create a builder.
     #[must_use]
     pub fn builder(interface: impl Into<String>) -> PacketCaptureRequestBuilder {
@@ -279,7 +353,10 @@ impl From<PacketCaptureRequest> for ProtoPacketCaptureRequest {
             interface: req.interface,
             promiscuous: req.promiscuous,
             snap_len: req.snap_len,
-            bpf_filter: Vec::new(), // BPF filters not exposed for simplicity
+            bpf_filter: req
+                .bpf_filter
+                .map(|filter| filter.instructions().iter().map(|&i| i.into()).collect())
+                .unwrap_or_default(),
         }
     }
 }
@@ -290,6 +367,7 @@ pub struct PacketCaptureRequestBuilder {
     interface: String,
     promiscuous: bool,
     snap_len: u32,
+    bpf_filter: Option<BpfFilter>,
 }
 
 impl PacketCaptureRequestBuilder {
@@ -299,7 +377,8 @@ impl PacketCaptureRequestBuilder {
         Self {
             interface: interface.into(),
             promiscuous: false,
-            snap_len: 65535,
+            snap_len: DEFAULT_SNAP_LEN,
+            bpf_filter: None,
         }
     }
 
@@ -317,6 +396,14 @@ impl PacketCaptureRequestBuilder {
         self
     }
 
+    /// Only capture packets matching `filter`, e.g.
+    /// `"tcp port 50000".parse()?`.
+    #[must_use]
+    pub fn bpf_filter(mut self, filter: BpfFilter) -> Self {
+        self.bpf_filter = Some(filter);
+        self
+    }
+
     /// Build the request.
     #[must_use]
     pub fn build(self) -> PacketCaptureRequest {
@@ -324,6 +411,7 @@ impl PacketCaptureRequestBuilder {
             interface: self.interface,
             promiscuous: self.promiscuous,
             snap_len: self.snap_len,
+            bpf_filter: self.bpf_filter,
         }
     }
 }
@@ -331,7 +419,9 @@ impl PacketCaptureRequestBuilder {
 /// Response from packet capture (streaming pcap data).
 #[derive(Debug, Clone, Default)]
 pub struct PacketCaptureResponse {
-    /// PCAP data.
+    /// PCAP data exactly as streamed by the node: a classic pcap file,
+    /// global header first. It may end in a partial record if the capture
+    /// was cut short; see [`to_pcap_bytes`](Self::to_pcap_bytes).
     pub data: Vec<u8>,
     /// Node that returned this data.
     pub node: Option<String>,
@@ -355,6 +445,131 @@ impl PacketCaptureResponse {
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
+
+    /// The capture as a well-formed pcap file that Wireshark and tcpdump
+    /// can open.
+    ///
+    /// Talos already frames the stream as classic pcap, so the data is kept
+    /// as-is apart from dropping a trailing partial record. A capture that
+    /// ended before the global header arrived becomes an empty Ethernet
+    /// capture.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the data does not start with a
+    /// pcap global header.
+    #[allow(clippy::result_large_err)]
+    pub fn to_pcap_bytes(&self) -> Result<Vec<u8>> {
+        let mut framer = PcapFramer::new();
+        let framed = framer.push(&self.data)?;
+        if framer.has_header() {
+            Ok(framed)
+        } else {
+            Ok(PcapFramer::empty_header(DEFAULT_SNAP_LEN))
+        }
+    }
+}
+
+/// Snap length of new capture requests, and of synthesized empty captures.
+const DEFAULT_SNAP_LEN: u32 = 65535;
+/// Length of the classic pcap global header.
+const PCAP_GLOBAL_HEADER_LEN: usize = 24;
+/// Length of a classic pcap per-packet record header.
+const PCAP_RECORD_HEADER_LEN: usize = 16;
+/// `LINKTYPE_ETHERNET`, assumed when a capture ends before any header arrived.
+const PCAP_LINKTYPE_ETHERNET: u32 = 1;
+
+/// Splits a pcap byte stream into whole frames.
+///
+/// Talos streams a classic pcap file in arbitrarily sized chunks. The framer
+/// holds back partial data and only releases the global header and complete
+/// packet records, so everything released so far is a readable capture.
+#[derive(Debug, Default)]
+pub(crate) struct PcapFramer {
+    pending: Vec<u8>,
+    big_endian: Option<bool>,
+    packets: u64,
+}
+
+impl PcapFramer {
+    /// Create a framer expecting the pcap global header first.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a chunk, returning the bytes that now form complete frames.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Result<Vec<u8>> {
+        self.pending.extend_from_slice(chunk);
+
+        let mut complete = 0;
+        let big_endian = match self.big_endian {
+            Some(big_endian) => big_endian,
+            None => {
+                if self.pending.len() < PCAP_GLOBAL_HEADER_LEN {
+                    return Ok(Vec::new());
+                }
+                let big_endian = pcap_is_big_endian(&self.pending[..4])?;
+                self.big_endian = Some(big_endian);
+                complete = PCAP_GLOBAL_HEADER_LEN;
+                big_endian
+            }
+        };
+
+        loop {
+            let rest = &self.pending[complete..];
+            if rest.len() < PCAP_RECORD_HEADER_LEN {
+                break;
+            }
+            let incl_len = [rest[8], rest[9], rest[10], rest[11]];
+            let incl_len = if big_endian {
+                u32::from_be_bytes(incl_len)
+            } else {
+                u32::from_le_bytes(incl_len)
+            } as usize;
+            if rest.len() < PCAP_RECORD_HEADER_LEN + incl_len {
+                break;
+            }
+            complete += PCAP_RECORD_HEADER_LEN + incl_len;
+            self.packets += 1;
+        }
+
+        Ok(self.pending.drain(..complete).collect())
+    }
+
+    /// Whether the global header has been released.
+    pub(crate) fn has_header(&self) -> bool {
+        self.big_endian.is_some()
+    }
+
+    /// Number of complete packet records released.
+    pub(crate) fn packets(&self) -> u64 {
+        self.packets
+    }
+
+    /// Global header for an empty Ethernet capture with `snap_len`.
+    pub(crate) fn empty_header(snap_len: u32) -> Vec<u8> {
+        let mut header = Vec::with_capacity(PCAP_GLOBAL_HEADER_LEN);
+        header.extend_from_slice(&0xa1b2_c3d4_u32.to_le_bytes());
+        header.extend_from_slice(&2_u16.to_le_bytes()); // version major
+        header.extend_from_slice(&4_u16.to_le_bytes()); // version minor
+        header.extend_from_slice(&0_i32.to_le_bytes()); // thiszone
+        header.extend_from_slice(&0_u32.to_le_bytes()); // sigfigs
+        header.extend_from_slice(&snap_len.to_le_bytes());
+        header.extend_from_slice(&PCAP_LINKTYPE_ETHERNET.to_le_bytes());
+        header
+    }
+}
+
+/// Byte order of a pcap file from its magic number (micro- or nanosecond).
+fn pcap_is_big_endian(magic: &[u8]) -> Result<bool> {
+    match magic {
+        [0xd4, 0xc3, 0xb2, 0xa1] | [0x4d, 0x3c, 0xb2, 0xa1] => Ok(false),
+        [0xa1, 0xb2, 0xc3, 0xd4] | [0xa1, 0xb2, 0x3c, 0x4d] => Ok(true),
+        _ => Err(TalosError::Validation(format!(
+            "packet capture is not a pcap stream (magic {:02x?})",
+            magic
+        ))),
+    }
 }
 
 // =============================================================================
@@ -566,7 +781,7 @@ impl NetstatRequestBuilder {
 }
 
 /// Connection state.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ConnectionState {
     /// Reserved/unknown.
     Reserved,
@@ -736,6 +951,89 @@ impl NetstatResponse {
             .filter(|c| c.state == ConnectionState::Established)
             .collect()
     }
+
+    /// Count connections per state across all nodes.
+    #[must_use]
+    pub fn state_counts(&self) -> HashMap<ConnectionState, usize> {
+        let mut counts = HashMap::new();
+        for conn in self.results.iter().flat_map(|r| &r.connections) {
+            *counts.entry(conn.state).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Count TCP connections (IPv4 and IPv6).
+    #[must_use]
+    pub fn tcp_count(&self) -> usize {
+        self.count_by_proto(&["tcp", "tcp6"])
+    }
+
+    /// Count UDP connections (IPv4 and IPv6).
+    #[must_use]
+    pub fn udp_count(&self) -> usize {
+        self.count_by_proto(&["udp", "udp6"])
+    }
+
+    fn count_by_proto(&self, protos: &[&str]) -> usize {
+        self.results
+            .iter()
+            .flat_map(|r| &r.connections)
+            .filter(|c| protos.iter().any(|p| c.l4proto.eq_ignore_ascii_case(p)))
+            .count()
+    }
+
+    /// Attribute listening and established sockets to full process details.
+    ///
+    /// Joins each connection with the process of the same PID reported by the
+    /// same node in `processes`. Connections without a PID, or whose process is
+    /// not in `processes`, are kept with `process: None`.
+    #[must_use]
+    pub fn with_processes<'a>(
+        &'a self,
+        processes: &'a ProcessesResponse,
+    ) -> Vec<ProcessConnection<'a>> {
+        let by_node_pid: HashMap<(Option<&str>, i32), &ProcessInfo> = processes
+            .results
+            .iter()
+            .flat_map(|r| {
+                r.processes
+                    .iter()
+                    .map(move |p| ((r.node.as_deref(), p.pid), p))
+            })
+            .collect();
+
+        self.results
+            .iter()
+            .flat_map(|r| r.connections.iter().map(move |c| (r.node.as_deref(), c)))
+            .filter(|(_, c)| {
+                matches!(
+                    c.state,
+                    ConnectionState::Listen | ConnectionState::Established
+                )
+            })
+            .map(|(node, connection)| ProcessConnection {
+                node,
+                connection,
+                process: connection
+                    .pid
+                    .and_then(|pid| i32::try_from(pid).ok())
+                    .and_then(|pid| by_node_pid.get(&(node, pid)).copied()),
+            })
+            .collect()
+    }
+}
+
+/// A socket joined with the process that owns it.
+///
+/// Produced by [`NetstatResponse::with_processes`].
+#[derive(Debug, Clone)]
+pub struct ProcessConnection<'a> {
+    /// Node that reported the connection.
+    pub node: Option<&'a str>,
+    /// The connection record.
+    pub connection: &'a ConnectionRecord,
+    /// The owning process, if it could be matched by PID.
+    pub process: Option<&'a ProcessInfo>,
 }
 
 #[cfg(test)]
@@ -766,6 +1064,39 @@ mod tests {
 
         assert_eq!(req.roles, vec!["os:admin", "os:reader"]);
         assert_eq!(req.crt_ttl_seconds, Some(30 * 86400));
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_generate_client_config_unknown_role() {
+        let req = GenerateClientConfigurationRequest::builder()
+            .role("os:reader")
+            .role("os:superuser")
+            .build();
+        assert!(matches!(
+            req.validate(),
+            Err(TalosError::Validation(msg)) if msg.contains("os:superuser")
+        ));
+
+        let req = GenerateClientConfigurationRequest::builder()
+            .role("os:superuser")
+            .allow_unknown_roles(true)
+            .build();
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_generate_client_config_default_ttl() {
+        let req = GenerateClientConfigurationRequest::with_roles(vec!["os:operator".to_string()]);
+        assert_eq!(req.effective_crt_ttl_seconds(), DEFAULT_CRT_TTL_SECONDS);
+
+        let proto: ProtoGenerateClientConfigRequest = req.into();
+        assert_eq!(proto.crt_ttl.map(|d| d.seconds), Some(24 * 3600));
+
+        let req = GenerateClientConfigurationRequest::builder()
+            .crt_ttl_seconds(0)
+            .build();
+        assert!(req.validate().is_err());
     }
 
     #[test]
@@ -788,6 +1119,20 @@ mod tests {
         assert_eq!(req.snap_len, 1500);
     }
 
+    #[test]
+    fn test_packet_capture_bpf_filter_to_proto() {
+        let proto: ProtoPacketCaptureRequest = PacketCaptureRequest::new("eth0").into();
+        assert!(proto.bpf_filter.is_empty());
+
+        let proto: ProtoPacketCaptureRequest = PacketCaptureRequest::builder("eth0")
+            .bpf_filter(BpfFilter::tcp_port(50000))
+            .build()
+            .into();
+        assert_eq!(proto.bpf_filter.len(), 20);
+        assert_eq!(proto.bpf_filter[5].k, 50000);
+        assert_eq!(proto.bpf_filter[18].op, 0x06);
+    }
+
     #[test]
     fn test_netstat_request() {
         let req = NetstatRequest::listening();
@@ -824,4 +1169,244 @@ mod tests {
         let tcp = L4ProtoFilter::tcp_only();
         assert!(tcp.tcp && tcp.tcp6 && !tcp.udp && !tcp.udp6);
     }
+
+    fn connection(l4proto: &str, state: ConnectionState) -> ConnectionRecord {
+        ConnectionRecord {
+            l4proto: l4proto.to_string(),
+            local_ip: "10.0.0.1".to_string(),
+            local_port: 50000,
+            remote_ip: "10.0.0.2".to_string(),
+            remote_port: 40000,
+            state,
+            tx_queue: 0,
+            rx_queue: 0,
+            pid: None,
+            process_name: None,
+            netns: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_netstat_summary_counts() {
+        let response = NetstatResponse {
+            results: vec![
+                NetstatResult {
+                    node: Some("node1".to_string()),
+                    connections: vec![
+                        connection("tcp", ConnectionState::Listen),
+                        connection("tcp", ConnectionState::Established),
+                        connection("tcp6", ConnectionState::Established),
+                        connection("udp", ConnectionState::Close),
+                    ],
+                },
+                NetstatResult {
+                    node: Some("node2".to_string()),
+                    connections: vec![
+                        connection("tcp", ConnectionState::TimeWait),
+                        connection("tcp6", ConnectionState::Listen),
+                        connection("udp6", ConnectionState::Close),
+                        connection("raw", ConnectionState::Reserved),
+                    ],
+                },
+            ],
+        };
+
+        let counts = response.state_counts();
+        assert_eq!(counts.get(&ConnectionState::Established), Some(&2));
+        assert_eq!(counts.get(&ConnectionState::Listen), Some(&2));
+        assert_eq!(counts.get(&ConnectionState::TimeWait), Some(&1));
+        assert_eq!(counts.get(&ConnectionState::Close), Some(&2));
+        assert_eq!(counts.get(&ConnectionState::SynSent), None);
+        assert_eq!(counts.values().sum::<usize>(), response.total_connections());
+
+        assert_eq!(response.tcp_count(), 5);
+        assert_eq!(response.udp_count(), 2);
+    }
+
+    fn process(pid: i32, command: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            ppid: 1,
+            state: "S".to_string(),
+            threads: 4,
+            cpu_time: 1.5,
+            virtual_memory: 1 << 30,
+            resident_memory: 64 << 20,
+            command: command.to_string(),
+            executable: format!("/usr/bin/{}", command),
+            args: format!("/usr/bin/{} --flag", command),
+        }
+    }
+
+    #[test]
+    fn test_netstat_with_processes() {
+        use crate::resources::ProcessesResult;
+
+        let with_pid = |state, pid| ConnectionRecord {
+            pid,
+            ..connection("tcp", state)
+        };
+        let netstat = NetstatResponse {
+            results: vec![
+                NetstatResult {
+                    node: Some("node1".to_string()),
+                    connections: vec![
+                        with_pid(ConnectionState::Listen, Some(100)),
+                        with_pid(ConnectionState::Established, Some(200)),
+                        with_pid(ConnectionState::TimeWait, Some(100)),
+                        with_pid(ConnectionState::Established, None),
+                    ],
+                },
+                NetstatResult {
+                    node: Some("node2".to_string()),
+                    connections: vec![with_pid(ConnectionState::Listen, Some(100))],
+                },
+            ],
+        };
+        let processes = ProcessesResponse {
+            results: vec![
+                ProcessesResult {
+                    node: Some("node1".to_string()),
+                    processes: vec![process(100, "apid"), process(200, "etcd")],
+                },
+                ProcessesResult {
+                    node: Some("node2".to_string()),
+                    processes: vec![process(100, "kubelet")],
+                },
+            ],
+        };
+
+        let joined = netstat.with_processes(&processes);
+        assert_eq!(joined.len(), 4);
+
+        let commands: Vec<_> = joined
+            .iter()
+            .map(|j| (j.node, j.process.map(|p| p.command.as_str())))
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                (Some("node1"), Some("apid")),
+                (Some("node1"), Some("etcd")),
+                (Some("node1"), None),
+                (Some("node2"), Some("kubelet")),
+            ]
+        );
+        assert_eq!(joined[0].process.unwrap().resident_memory, 64 << 20);
+        assert_eq!(joined[1].connection.state, ConnectionState::Established);
+    }
+
+    fn pcap_record(payload: &[u8]) -> Vec<u8> {
+        let mut record = Vec::new();
+        record.extend_from_slice(&1_u32.to_le_bytes()); // ts_sec
+        record.extend_from_slice(&0_u32.to_le_bytes()); // ts_usec
+        record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        record.extend_from_slice(payload);
+        record
+    }
+
+    #[test]
+    fn test_pcap_framer_releases_only_complete_frames() {
+        let mut capture = PcapFramer::empty_header(65535);
+        capture.extend(pcap_record(b"first"));
+        capture.extend(pcap_record(b"second"));
+
+        let mut framer = PcapFramer::new();
+        let mut released = Vec::new();
+        for chunk in capture.chunks(7) {
+            released.extend(framer.push(chunk).unwrap());
+        }
+        assert_eq!(released, capture);
+        assert_eq!(framer.packets(), 2);
+
+        // A trailing partial record is held back.
+        let partial = pcap_record(b"third");
+        assert!(framer.push(&partial[..10]).unwrap().is_empty());
+        assert_eq!(framer.packets(), 2);
+    }
+
+    #[test]
+    fn test_pcap_framer_header_split_and_byte_order() {
+        let mut framer = PcapFramer::new();
+        let header = PcapFramer::empty_header(1500);
+        assert!(framer.push(&header[..20]).unwrap().is_empty());
+        assert!(!framer.has_header());
+        assert_eq!(framer.push(&header[20..]).unwrap(), header);
+        assert!(framer.has_header());
+
+        let mut big = Vec::new();
+        big.extend_from_slice(&0xa1b2_c3d4_u32.to_be_bytes());
+        big.extend_from_slice(&[0; 20]);
+        big.extend_from_slice(&[0; 8]);
+        big.extend_from_slice(&2_u32.to_be_bytes());
+        big.extend_from_slice(&2_u32.to_be_bytes());
+        big.extend_from_slice(b"ok");
+        let mut framer = PcapFramer::new();
+        assert_eq!(framer.push(&big).unwrap(), big);
+        assert_eq!(framer.packets(), 1);
+    }
+
+    /// Minimal reader for little-endian classic pcap: returns the snap
+    /// length and packet payloads, failing on any framing inconsistency.
+    fn read_pcap(bytes: &[u8]) -> (u32, Vec<Vec<u8>>) {
+        let u32_at = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        assert!(
+            bytes.len() >= PCAP_GLOBAL_HEADER_LEN,
+            "missing global header"
+        );
+        assert_eq!(u32_at(0), 0xa1b2_c3d4);
+        assert_eq!(&bytes[4..8], &[2, 0, 4, 0]);
+        let snap_len = u32_at(16);
+
+        let mut packets = Vec::new();
+        let mut at = PCAP_GLOBAL_HEADER_LEN;
+        while at < bytes.len() {
+            assert!(
+                at + PCAP_RECORD_HEADER_LEN <= bytes.len(),
+                "truncated header"
+            );
+            let incl_len = u32_at(at + 8) as usize;
+            assert!(incl_len as u32 <= u32_at(at + 12));
+            let start = at + PCAP_RECORD_HEADER_LEN;
+            assert!(start + incl_len <= bytes.len(), "truncated record");
+            packets.push(bytes[start..start + incl_len].to_vec());
+            at = start + incl_len;
+        }
+        (snap_len, packets)
+    }
+
+    #[test]
+    fn test_packet_capture_to_pcap_bytes() {
+        let mut data = PcapFramer::empty_header(1500);
+        data.extend(pcap_record(b"first"));
+        data.extend(pcap_record(b"second"));
+        let complete = data.clone();
+        data.extend(&pcap_record(b"cut short")[..12]);
+
+        let response = PacketCaptureResponse::new(data, None);
+        let pcap = response.to_pcap_bytes().unwrap();
+        assert_eq!(pcap, complete);
+        let (snap_len, packets) = read_pcap(&pcap);
+        assert_eq!(snap_len, 1500);
+        assert_eq!(packets, vec![b"first".to_vec(), b"second".to_vec()]);
+
+        let empty = PacketCaptureResponse::default().to_pcap_bytes().unwrap();
+        assert_eq!(read_pcap(&empty), (DEFAULT_SNAP_LEN, vec![]));
+
+        let garbage = PacketCaptureResponse::new(vec![0x0a; 64], None);
+        assert!(matches!(
+            garbage.to_pcap_bytes(),
+            Err(TalosError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_pcap_framer_rejects_unknown_magic() {
+        let mut framer = PcapFramer::new();
+        let err = framer
+            .push(&[0x0a, 0x0d, 0x0d, 0x0a].repeat(6))
+            .unwrap_err();
+        assert!(matches!(err, TalosError::Validation(_)));
+    }
 }
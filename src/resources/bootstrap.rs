@@ -31,7 +31,9 @@ use crate::api::machine::{
 #[derive(Debug, Clone, Copy, Default)]
 pub struct BootstrapRequest {
     /// Enable etcd recovery from a snapshot.
-    /// The snapshot must be uploaded via `EtcdRecover` RPC before calling bootstrap.
+    /// The snapshot must be uploaded via
+    /// [`TalosClient::etcd_recover`](crate::TalosClient::etcd_recover) before
+    /// calling bootstrap.
     pub recover_etcd: bool,
     /// Skip hash verification on the etcd snapshot.
     /// Enable this when recovering from a data directory copy.
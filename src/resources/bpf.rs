@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Classic BPF filters for packet captures.
+//!
+//! Talos applies the filter in the kernel, so only matching packets leave
+//! the node. Filters are classic BPF programs for Ethernet interfaces: build
+//! one for a TCP or UDP port, parse the output of `tcpdump -dd` for anything
+//! else, or pass instructions directly.
+//!
+//! # Example
+//!
+//! ```
+//! use talos_api_rs::resources::BpfFilter;
+//!
+//! // Talos API traffic
+//! let filter: BpfFilter = "tcp port 50000".parse().unwrap();
+//! assert_eq!(filter, BpfFilter::tcp_port(50000));
+//!
+//! // Output of `tcpdump -dd -y EN10MB 'icmp'`
+//! let filter = BpfFilter::from_tcpdump_dd(
+//!     "{ 0x28, 0, 0, 0x0000000c },
+//!      { 0x15, 0, 3, 0x00000800 },
+//!      { 0x30, 0, 0, 0x00000017 },
+//!      { 0x15, 0, 1, 0x00000001 },
+//!      { 0x6, 0, 0, 0x00040000 },
+//!      { 0x6, 0, 0, 0x00000000 },",
+//! )
+//! .unwrap();
+//! assert_eq!(filter.len(), 6);
+//! ```
+
+use crate::api::generated::machine::BpfInstruction as ProtoBpfInstruction;
+use crate::error::{Result, TalosError};
+
+// Opcodes used by the built-in filters (see linux/filter.h).
+const LDH_ABS: u16 = 0x28;
+const LDB_ABS: u16 = 0x30;
+const LDH_IND: u16 = 0x48;
+const LDXB_MSH: u16 = 0xb1;
+const JEQ_K: u16 = 0x15;
+const JSET_K: u16 = 0x45;
+const RET_K: u16 = 0x06;
+
+/// Bytes accepted per matching packet, as in tcpdump's compiled filters.
+const ACCEPT_LEN: u32 = 0x40000;
+
+const ETHERTYPE_IPV4: u32 = 0x0800;
+const ETHERTYPE_IPV6: u32 = 0x86dd;
+const IPPROTO_TCP: u8 = 6;
+const IPPROTO_UDP: u8 = 17;
+
+/// A single classic BPF instruction (`struct sock_filter`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BpfInstruction {
+    /// Opcode.
+    pub op: u16,
+    /// Instructions to skip if a jump condition holds.
+    pub jt: u8,
+    /// Instructions to skip if a jump condition does not hold.
+    pub jf: u8,
+    /// Generic operand.
+    pub k: u32,
+}
+
+impl BpfInstruction {
+    /// Create an instruction.
+    #[must_use]
+    pub fn new(op: u16, jt: u8, jf: u8, k: u32) -> Self {
+        Self { op, jt, jf, k }
+    }
+}
+
+impl From<BpfInstruction> for ProtoBpfInstruction {
+    fn from(insn: BpfInstruction) -> Self {
+        Self {
+            op: insn.op.into(),
+            jt: insn.jt.into(),
+            jf: insn.jf.into(),
+            k: insn.k,
+        }
+    }
+}
+
+/// A compiled classic BPF program applied to a packet capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BpfFilter {
+    instructions: Vec<BpfInstruction>,
+}
+
+impl BpfFilter {
+    /// Use pre-compiled instructions as-is.
+    #[must_use]
+    pub fn from_instructions(instructions: Vec<BpfInstruction>) -> Self {
+        Self { instructions }
+    }
+
+    /// Match TCP segments from or to `port`, over IPv4 or IPv6.
+    ///
+    /// Equivalent to `tcpdump 'tcp port <port>'`.
+    #[must_use]
+    pub fn tcp_port(port: u16) -> Self {
+        Self::transport_port(IPPROTO_TCP, port)
+    }
+
+    /// Match UDP datagrams from or to `port`, over IPv4 or IPv6.
+    ///
+    /// Equivalent to `tcpdump 'udp port <port>'`.
+    #[must_use]
+    pub fn udp_port(port: u16) -> Self {
+        Self::transport_port(IPPROTO_UDP, port)
+    }
+
+    /// Parse the C array printed by `tcpdump -dd`.
+    ///
+    /// Each instruction is a `{ op, jt, jf, k }` group; numbers may be
+    /// decimal or `0x` hex. Compile with `-y EN10MB` if the local default
+    /// link type is not Ethernet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the text contains no
+    /// instructions or a group is malformed.
+    #[allow(clippy::result_large_err)]
+    pub fn from_tcpdump_dd(text: &str) -> Result<Self> {
+        let invalid = |group: &str| {
+            TalosError::Validation(format!("invalid BPF instruction: {{{}}}", group.trim()))
+        };
+
+        let mut instructions = Vec::new();
+        for group in text.split('{').skip(1) {
+            let group = group.split('}').next().unwrap_or_default();
+            let fields: Vec<&str> = group.split(',').map(str::trim).collect();
+            let [op, jt, jf, k] = fields[..] else {
+                return Err(invalid(group));
+            };
+            let op = parse_u32(op).and_then(|v| u16::try_from(v).ok());
+            let jt = parse_u32(jt).and_then(|v| u8::try_from(v).ok());
+            let jf = parse_u32(jf).and_then(|v| u8::try_from(v).ok());
+            match (op, jt, jf, parse_u32(k)) {
+                (Some(op), Some(jt), Some(jf), Some(k)) => {
+                    instructions.push(BpfInstruction::new(op, jt, jf, k));
+                }
+                _ => return Err(invalid(group)),
+            }
+        }
+
+        if instructions.is_empty() {
+            return Err(TalosError::Validation(
+                "no BPF instructions found; expected `tcpdump -dd` output".to_string(),
+            ));
+        }
+        Ok(Self { instructions })
+    }
+
+    /// The compiled instructions.
+    #[must_use]
+    pub fn instructions(&self) -> &[BpfInstruction] {
+        &self.instructions
+    }
+
+    /// Number of instructions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    /// Check if the program is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// The program tcpdump generates for `<tcp|udp> port <port>`.
+    fn transport_port(protocol: u8, port: u16) -> Self {
+        let protocol = u32::from(protocol);
+        let port = u32::from(port);
+        let (accept, reject) = (18, 19);
+        // (op, jump target if true, jump target if false, k), with absolute
+        // targets (0 for non-jumps); converted to relative offsets below.
+        let program: [(u16, usize, usize, u32); 20] = [
+            (LDH_ABS, 0, 0, 12), // ethertype
+            (JEQ_K, 2, 8, ETHERTYPE_IPV6),
+            (LDB_ABS, 0, 0, 20), // IPv6 next header
+            (JEQ_K, 4, reject, protocol),
+            (LDH_ABS, 0, 0, 54), // source port
+            (JEQ_K, accept, 6, port),
+            (LDH_ABS, 0, 0, 56), // destination port
+            (JEQ_K, accept, reject, port),
+            (JEQ_K, 9, reject, ETHERTYPE_IPV4),
+            (LDB_ABS, 0, 0, 23), // IPv4 protocol
+            (JEQ_K, 11, reject, protocol),
+            (LDH_ABS, 0, 0, 20), // fragment offset
+            (JSET_K, reject, 13, 0x1fff),
+            (LDXB_MSH, 0, 0, 14), // IPv4 header length
+            (LDH_IND, 0, 0, 14),  // source port
+            (JEQ_K, accept, 16, port),
+            (LDH_IND, 0, 0, 16), // destination port
+            (JEQ_K, accept, reject, port),
+            (RET_K, 0, 0, ACCEPT_LEN),
+            (RET_K, 0, 0, 0),
+        ];
+
+        let instructions = program
+            .iter()
+            .enumerate()
+            .map(|(pc, &(op, jt, jf, k))| {
+                let offset = |target: usize| {
+                    if target == 0 {
+                        0
+                    } else {
+                        (target - pc - 1) as u8
+                    }
+                };
+                BpfInstruction::new(op, offset(jt), offset(jf), k)
+            })
+            .collect();
+        Self { instructions }
+    }
+}
+
+impl std::str::FromStr for BpfFilter {
+    type Err = TalosError;
+
+    /// Compile `tcp port <n>` or `udp port <n>`.
+    ///
+    /// Other expressions are rejected; compile them with `tcpdump -dd` and
+    /// use [`BpfFilter::from_tcpdump_dd`].
+    fn from_str(expression: &str) -> Result<Self> {
+        let words: Vec<&str> = expression.split_whitespace().collect();
+        let port = |port: &str| port.parse::<u16>().ok();
+        match words[..] {
+            ["tcp", "port", p] => port(p).map(Self::tcp_port),
+            ["udp", "port", p] => port(p).map(Self::udp_port),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            TalosError::Validation(format!(
+                "unsupported BPF expression '{}': only `tcp port N` and `udp port N` \
+                 are built in; compile others with `tcpdump -dd`",
+                expression
+            ))
+        })
+    }
+}
+
+fn parse_u32(value: &str) -> Option<u32> {
+    match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `tcpdump -dd 'tcp port 22'`
+    const TCP_PORT_22: &str = "
+        { 0x28, 0, 0, 0x0000000c },
+        { 0x15, 0, 6, 0x000086dd },
+        { 0x30, 0, 0, 0x00000014 },
+        { 0x15, 0, 15, 0x00000006 },
+        { 0x28, 0, 0, 0x00000036 },
+        { 0x15, 12, 0, 0x00000016 },
+        { 0x28, 0, 0, 0x00000038 },
+        { 0x15, 10, 11, 0x00000016 },
+        { 0x15, 0, 10, 0x00000800 },
+        { 0x30, 0, 0, 0x00000017 },
+        { 0x15, 0, 8, 0x00000006 },
+        { 0x28, 0, 0, 0x00000014 },
+        { 0x45, 6, 0, 0x00001fff },
+        { 0xb1, 0, 0, 0x0000000e },
+        { 0x48, 0, 0, 0x0000000e },
+        { 0x15, 2, 0, 0x00000016 },
+        { 0x48, 0, 0, 0x00000010 },
+        { 0x15, 0, 1, 0x00000016 },
+        { 0x6, 0, 0, 0x00040000 },
+        { 0x6, 0, 0, 0x00000000 },
+    ";
+
+    #[test]
+    fn test_tcp_port_matches_tcpdump() {
+        let parsed = BpfFilter::from_tcpdump_dd(TCP_PORT_22).unwrap();
+        assert_eq!(parsed.len(), 20);
+        assert_eq!(BpfFilter::tcp_port(22), parsed);
+        assert_eq!("tcp port 22".parse::<BpfFilter>().unwrap(), parsed);
+    }
+
+    #[test]
+    fn test_udp_port_filter() {
+        let filter: BpfFilter = " udp  port 53 ".parse().unwrap();
+        let insns = filter.instructions();
+        assert_eq!(insns[3].k, u32::from(IPPROTO_UDP));
+        assert_eq!(insns[5].k, 53);
+        assert_eq!(insns[17].k, 53);
+    }
+
+    #[test]
+    fn test_invalid_filters() {
+        for expression in ["host 10.0.0.1", "tcp port", "tcp port 70000", ""] {
+            assert!(matches!(
+                expression.parse::<BpfFilter>(),
+                Err(TalosError::Validation(_))
+            ));
+        }
+        assert!(BpfFilter::from_tcpdump_dd("").is_err());
+        assert!(BpfFilter::from_tcpdump_dd("{ 0x28, 0, 0 }").is_err());
+        assert!(BpfFilter::from_tcpdump_dd("{ 0x28, 0, 300, 0 }").is_err());
+    }
+
+    #[test]
+    fn test_instruction_to_proto() {
+        let proto = ProtoBpfInstruction::from(BpfInstruction::new(0x15, 2, 3, 0x800));
+        assert_eq!((proto.op, proto.jt, proto.jf, proto.k), (0x15, 2, 3, 0x800));
+    }
+}
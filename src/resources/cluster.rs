@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for the ClusterService HealthCheck API, plus the
+//! [`ClusterMember`] and [`NodeRole`] types shared by discovery and rolling
+//! upgrades.
+//!
+//! The node that receives the request runs the same checks as
+//! `talosctl health`: etcd, the Kubernetes API server, kubelets and node
+//! readiness, reporting progress as the cluster comes up. Unlike
+//! [`ClusterDiscovery::check_cluster_health`](crate::client::discovery::ClusterDiscovery::check_cluster_health),
+//! which only pings each node, this is the server-side view of whether the
+//! cluster is usable.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use talos_api_rs::resources::ClusterHealthCheckRequest;
+//!
+//! let request = ClusterHealthCheckRequest::new()
+//!     .with_control_plane_nodes(["10.0.0.2", "10.0.0.3", "10.0.0.4"])
+//!     .with_worker_nodes(["10.0.0.10"])
+//!     .with_wait_timeout(Duration::from_secs(600));
+//! ```
+
+use crate::api::generated::cluster::{
+    ClusterInfo as ProtoClusterInfo, HealthCheckProgress as ProtoHealthCheckProgress,
+    HealthCheckRequest as ProtoHealthCheckRequest,
+};
+use std::time::Duration;
+
+/// Default time the node waits for the cluster to become healthy, as in
+/// `talosctl health`.
+pub const DEFAULT_HEALTH_CHECK_WAIT_TIMEOUT: Duration = Duration::from_secs(20 * 60);
+
+/// Role of a node in the Talos cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeRole {
+    /// Control plane node (runs etcd, API server, etc.)
+    ControlPlane,
+    /// Worker node (runs workloads only)
+    Worker,
+    /// Unknown role
+    Unknown,
+}
+
+impl NodeRole {
+    /// Parse the `machineType` Talos reports for a member
+    ///
+    /// `init` is the legacy name of the first control plane node.
+    #[must_use]
+    pub fn from_machine_type(machine_type: &str) -> Self {
+        match machine_type {
+            "controlplane" | "init" => Self::ControlPlane,
+            "worker" => Self::Worker,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for NodeRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ControlPlane => write!(f, "controlplane"),
+            Self::Worker => write!(f, "worker"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Information about a discovered cluster member.
+#[derive(Debug, Clone)]
+pub struct ClusterMember {
+    /// Node name/hostname
+    pub name: String,
+    /// gRPC endpoint URL
+    pub endpoint: String,
+    /// Node role
+    pub role: NodeRole,
+    /// Whether this node is an etcd member
+    pub is_etcd_member: bool,
+}
+
+impl ClusterMember {
+    /// Create a new cluster member
+    #[must_use]
+    pub fn new(name: impl Into<String>, endpoint: impl Into<String>, role: NodeRole) -> Self {
+        Self {
+            name: name.into(),
+            endpoint: endpoint.into(),
+            role,
+            is_etcd_member: role == NodeRole::ControlPlane,
+        }
+    }
+
+    /// Check if this is a control plane node
+    #[must_use]
+    pub fn is_control_plane(&self) -> bool {
+        self.role == NodeRole::ControlPlane
+    }
+
+    /// Check if this is a worker node
+    #[must_use]
+    pub fn is_worker(&self) -> bool {
+        self.role == NodeRole::Worker
+    }
+}
+
+/// Request to run a cluster health check.
+///
+/// Send it to a single node; apid does not merge health checks from
+/// several nodes.
+#[derive(Debug, Clone)]
+pub struct ClusterHealthCheckRequest {
+    /// Addresses of the control plane nodes.
+    pub control_plane_nodes: Vec<String>,
+    /// Addresses of the worker nodes.
+    pub worker_nodes: Vec<String>,
+    /// Kubernetes API endpoint to check instead of the one from the
+    /// machine configuration.
+    pub k8s_endpoint: Option<String>,
+    /// How long to wait for the cluster to become healthy.
+    pub wait_timeout: Duration,
+}
+
+impl ClusterHealthCheckRequest {
+    /// Create a request with no nodes and the default timeout.
+    ///
+    /// Without a node list Talos discovers the members itself.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            control_plane_nodes: Vec::new(),
+            worker_nodes: Vec::new(),
+            k8s_endpoint: None,
+            wait_timeout: DEFAULT_HEALTH_CHECK_WAIT_TIMEOUT,
+        }
+    }
+
+    /// Set the control plane node addresses.
+    #[must_use]
+    pub fn with_control_plane_nodes<I, S>(mut self, nodes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.control_plane_nodes = nodes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the worker node addresses.
+    #[must_use]
+    pub fn with_worker_nodes<I, S>(mut self, nodes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.worker_nodes = nodes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check the Kubernetes API through `endpoint` (e.g. a load balancer
+    /// address), like `talosctl health --k8s-endpoint`.
+    #[must_use]
+    pub fn with_k8s_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.k8s_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set how long to wait for the cluster to become healthy.
+    #[must_use]
+    pub fn with_wait_timeout(mut self, timeout: Duration) -> Self {
+        self.wait_timeout = timeout;
+        self
+    }
+}
+
+impl Default for ClusterHealthCheckRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ClusterHealthCheckRequest> for ProtoHealthCheckRequest {
+    fn from(req: ClusterHealthCheckRequest) -> Self {
+        Self {
+            wait_timeout: Some(prost_types::Duration {
+                seconds: req.wait_timeout.as_secs() as i64,
+                nanos: req.wait_timeout.subsec_nanos() as i32,
+            }),
+            cluster_info: Some(ProtoClusterInfo {
+                control_plane_nodes: req.control_plane_nodes,
+                worker_nodes: req.worker_nodes,
+                force_endpoint: req.k8s_endpoint.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+/// A progress message from a running health check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthCheckProgress {
+    /// Node running the check.
+    pub node: Option<String>,
+    /// Progress message, e.g. `waiting for etcd to be healthy: OK`.
+    pub message: String,
+}
+
+impl From<ProtoHealthCheckProgress> for HealthCheckProgress {
+    fn from(proto: ProtoHealthCheckProgress) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            message: proto.message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+
+    #[test]
+    fn test_health_check_request_to_proto() {
+        let proto: ProtoHealthCheckRequest = ClusterHealthCheckRequest::new()
+            .with_control_plane_nodes(["10.0.0.2", "10.0.0.3"])
+            .with_worker_nodes(vec!["10.0.0.10".to_string()])
+            .with_k8s_endpoint("https://10.0.0.1:6443")
+            .with_wait_timeout(Duration::from_millis(90_500))
+            .into();
+
+        let timeout = proto.wait_timeout.unwrap();
+        assert_eq!((timeout.seconds, timeout.nanos), (90, 500_000_000));
+        let info = proto.cluster_info.unwrap();
+        assert_eq!(info.control_plane_nodes, vec!["10.0.0.2", "10.0.0.3"]);
+        assert_eq!(info.worker_nodes, vec!["10.0.0.10"]);
+        assert_eq!(info.force_endpoint, "https://10.0.0.1:6443");
+    }
+
+    #[test]
+    fn test_health_check_request_defaults() {
+        let proto: ProtoHealthCheckRequest = ClusterHealthCheckRequest::default().into();
+        // Talos treats a missing timeout as zero and fails at once.
+        assert_eq!(proto.wait_timeout.unwrap().seconds, 20 * 60);
+        let info = proto.cluster_info.unwrap();
+        assert!(info.control_plane_nodes.is_empty());
+        assert!(info.force_endpoint.is_empty());
+    }
+
+    #[test]
+    fn test_health_check_progress_from_proto() {
+        let progress = HealthCheckProgress::from(ProtoHealthCheckProgress {
+            metadata: Some(Metadata {
+                hostname: "cp-1".to_string(),
+                ..Default::default()
+            }),
+            message: "waiting for etcd to be healthy: OK".to_string(),
+        });
+        assert_eq!(progress.node.as_deref(), Some("cp-1"));
+        assert_eq!(progress.message, "waiting for etcd to be healthy: OK");
+    }
+}
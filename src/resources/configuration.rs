@@ -5,12 +5,20 @@
 //! This module provides ergonomic builders and types for working with
 //! Talos machine configuration.
 
+use super::advanced::RollbackResponse;
+use super::events::{Event, MachineEventKind};
 use crate::api::machine::{
     apply_configuration_request::Mode as ProtoMode, ApplyConfiguration as ProtoApplyConfiguration,
     ApplyConfigurationRequest as ProtoRequest, ApplyConfigurationResponse as ProtoResponse,
 };
+use crate::error::TalosError;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Default cap on the combined size of configuration and patch files (16 MiB).
+pub const DEFAULT_MAX_CONFIG_BYTES: usize = 16 * 1024 * 1024;
+
 /// Mode for applying configuration changes.
 ///
 /// Determines how the node handles configuration updates.
@@ -27,6 +35,8 @@ pub enum ApplyMode {
     Staged,
     /// Try the configuration temporarily; revert if not confirmed.
     Try,
+    /// Mode this client doesn't know, with its raw wire value.
+    Unknown(i32),
 }
 
 impl From<ApplyMode> for i32 {
@@ -37,6 +47,7 @@ impl From<ApplyMode> for i32 {
             ApplyMode::NoReboot => ProtoMode::NoReboot as i32,
             ApplyMode::Staged => ProtoMode::Staged as i32,
             ApplyMode::Try => ProtoMode::Try as i32,
+            ApplyMode::Unknown(value) => value,
         }
     }
 }
@@ -49,7 +60,7 @@ impl From<i32> for ApplyMode {
             2 => ApplyMode::NoReboot,
             3 => ApplyMode::Staged,
             4 => ApplyMode::Try,
-            _ => ApplyMode::Auto, // Default fallback
+            other => ApplyMode::Unknown(other),
         }
     }
 }
@@ -62,6 +73,27 @@ impl std::fmt::Display for ApplyMode {
             ApplyMode::NoReboot => write!(f, "no-reboot"),
             ApplyMode::Staged => write!(f, "staged"),
             ApplyMode::Try => write!(f, "try"),
+            ApplyMode::Unknown(value) => write!(f, "unknown({})", value),
+        }
+    }
+}
+
+impl std::str::FromStr for ApplyMode {
+    type Err = TalosError;
+
+    /// Parse a talosctl `--mode` string (`auto`, `no-reboot`, `reboot`,
+    /// `staged`, `try`), ignoring ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "reboot" => Ok(ApplyMode::Reboot),
+            "auto" => Ok(ApplyMode::Auto),
+            "no-reboot" => Ok(ApplyMode::NoReboot),
+            "staged" => Ok(ApplyMode::Staged),
+            "try" => Ok(ApplyMode::Try),
+            _ => Err(TalosError::Validation(format!(
+                "unknown apply mode '{}' (expected auto, no-reboot, reboot, staged or try)",
+                s
+            ))),
         }
     }
 }
@@ -74,11 +106,16 @@ impl std::fmt::Display for ApplyMode {
 /// use talos_api_rs::resources::ApplyConfigurationRequest;
 /// use talos_api_rs::resources::ApplyMode;
 ///
+/// # fn example() -> Result<(), talos_api_rs::TalosError> {
 /// let request = ApplyConfigurationRequest::builder()
-///     .config_yaml("machine:\n  type: worker")
+///     .config_file("controlplane.yaml")
+///     .patch_file("patches/network.yaml")
+///     .patch_file("patches/node-1.yaml")
 ///     .mode(ApplyMode::NoReboot)
 ///     .dry_run(true)
-///     .build();
+///     .build()?;
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Debug, Clone, Default)]
 pub struct ApplyConfigurationRequest {
@@ -137,9 +174,14 @@ impl From<ApplyConfigurationRequest> for ProtoRequest {
 }
 
 /// Builder for `ApplyConfigurationRequest`.
+///
+/// Configuration and patch files are only read by [`build`](Self::build).
 #[derive(Debug, Clone, Default)]
 pub struct ApplyConfigurationRequestBuilder {
     data: Vec<u8>,
+    config_path: Option<PathBuf>,
+    patch_paths: Vec<PathBuf>,
+    max_config_bytes: Option<usize>,
     mode: ApplyMode,
     dry_run: bool,
     try_mode_timeout: Option<Duration>,
@@ -160,14 +202,39 @@ impl ApplyConfigurationRequestBuilder {
         self
     }
 
-    /// Set the configuration from a file path.
+    /// Set the configuration from a file path, read at build time.
+    #[must_use]
+    pub fn config_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.config_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Add a configuration patch file, applied in the order added.
     ///
-    /// # Errors
+    /// Patches follow Talos' own merge rules. Each patch document is matched
+    /// to the configuration document with the same `kind` and `name`, or to
+    /// the v1alpha1 document when it has no `kind`; unmatched documents are
+    /// appended. Mappings merge key by key and lists are appended, except
+    /// network interfaces (keyed by `interface`/`deviceSelector`) and VLANs
+    /// (keyed by `vlanId`), which merge entry by entry, and pod/service
+    /// subnets and the audit policy, which are replaced. `$patch: delete`
+    /// removes a key, list entry or whole document.
     ///
-    /// Returns an error if the file cannot be read.
-    pub fn config_file(mut self, path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
-        self.data = std::fs::read(path)?;
-        Ok(self)
+    /// Patched documents are re-serialised, which drops their comments;
+    /// untouched documents are passed through verbatim. JSON6902 patches are
+    /// not supported.
+    #[must_use]
+    pub fn patch_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.patch_paths.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Cap the combined size of the configuration and patches
+    /// (defaults to [`DEFAULT_MAX_CONFIG_BYTES`]).
+    #[must_use]
+    pub fn max_config_bytes(mut self, max: usize) -> Self {
+        self.max_config_bytes = Some(max);
+        self
     }
 
     /// Set the apply mode.
@@ -191,15 +258,304 @@ impl ApplyConfigurationRequestBuilder {
         self
     }
 
-    /// Build the request.
-    #[must_use]
-    pub fn build(self) -> ApplyConfigurationRequest {
-        ApplyConfigurationRequest {
-            data: self.data,
+    /// Build the request, reading and merging any configuration and patch files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Config` if a file cannot be read, and
+    /// `TalosError::Validation` if the inputs exceed the size cap or a patch
+    /// cannot be parsed or applied.
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> crate::error::Result<ApplyConfigurationRequest> {
+        let limit = self.max_config_bytes.unwrap_or(DEFAULT_MAX_CONFIG_BYTES);
+        let mut total = 0;
+
+        let mut data = match &self.config_path {
+            Some(path) => read_limited(path, &mut total, limit)?,
+            None => {
+                total = self.data.len();
+                check_config_size(total, limit, "configuration")?;
+                self.data
+            }
+        };
+
+        if !self.patch_paths.is_empty() {
+            let mut documents = ConfigDocument::parse_all(&data, "configuration")?;
+            for path in &self.patch_paths {
+                let source = path.display().to_string();
+                let patch = read_limited(path, &mut total, limit)?;
+                for patch in ConfigDocument::parse_all(&patch, &source)? {
+                    apply_patch_document(&mut documents, patch.value, &source)?;
+                }
+            }
+            data = render_documents(&documents)?.into_bytes();
+            check_config_size(data.len(), limit, "patched configuration")?;
+        }
+
+        Ok(ApplyConfigurationRequest {
+            data,
             mode: self.mode,
             dry_run: self.dry_run,
             try_mode_timeout: self.try_mode_timeout,
+        })
+    }
+}
+
+/// Read `path`, adding its size to `total` and failing once `total` exceeds `limit`.
+#[allow(clippy::result_large_err)]
+fn read_limited(path: &Path, total: &mut usize, limit: usize) -> crate::error::Result<Vec<u8>> {
+    let read_error =
+        |e: std::io::Error| TalosError::Config(format!("Failed to read {}: {e}", path.display()));
+
+    let len = std::fs::metadata(path).map_err(read_error)?.len();
+    *total = total.saturating_add(usize::try_from(len).unwrap_or(usize::MAX));
+    check_config_size(*total, limit, &path.display().to_string())?;
+
+    std::fs::read(path).map_err(read_error)
+}
+
+#[allow(clippy::result_large_err)]
+fn check_config_size(size: usize, limit: usize, what: &str) -> crate::error::Result<()> {
+    if size > limit {
+        return Err(TalosError::Validation(format!(
+            "{} exceeds the configuration size cap ({} > {} bytes)",
+            what, size, limit
+        )));
+    }
+    Ok(())
+}
+
+/// Directive key Talos uses in patches to delete the value it appears in.
+const PATCH_DIRECTIVE: &str = "$patch";
+
+/// Lists that are replaced rather than appended when patched.
+const REPLACED_PATHS: &[&[&str]] = &[
+    &["cluster", "network", "podSubnets"],
+    &["cluster", "network", "serviceSubnets"],
+    &["cluster", "apiServer", "auditPolicy"],
+];
+
+/// Lists whose entries are merged by identifying fields when patched.
+const KEYED_LISTS: &[(&[&str], &[&str])] = &[
+    (
+        &["machine", "network", "interfaces"],
+        &["interface", "deviceSelector"],
+    ),
+    (&["machine", "network", "interfaces", "vlans"], &["vlanId"]),
+];
+
+/// Identity of a document in a multi-document machine configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DocumentKey {
+    /// The legacy `v1alpha1` document (`machine`/`cluster`), which has no `kind`.
+    V1Alpha1,
+    /// A typed document, identified by `kind` and optional `name`.
+    Typed { kind: String, name: Option<String> },
+}
+
+impl std::fmt::Display for DocumentKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocumentKey::V1Alpha1 => write!(f, "v1alpha1"),
+            DocumentKey::Typed { kind, name: None } => write!(f, "{}", kind),
+            DocumentKey::Typed {
+                kind,
+                name: Some(name),
+            } => write!(f, "{}/{}", kind, name),
+        }
+    }
+}
+
+impl DocumentKey {
+    fn of(value: &serde_yaml::Value) -> Option<Self> {
+        let mapping = value.as_mapping()?;
+        match mapping.get("kind").and_then(serde_yaml::Value::as_str) {
+            Some(kind) => Some(Self::Typed {
+                kind: kind.to_string(),
+                name: mapping
+                    .get("name")
+                    .and_then(serde_yaml::Value::as_str)
+                    .map(str::to_string),
+            }),
+            None => Some(Self::V1Alpha1),
+        }
+    }
+}
+
+/// One document of a configuration, kept verbatim until a patch touches it.
+#[derive(Debug)]
+struct ConfigDocument {
+    raw: String,
+    value: serde_yaml::Value,
+    patched: bool,
+}
+
+impl ConfigDocument {
+    /// Split `data` on `---` separators and parse each document.
+    #[allow(clippy::result_large_err)]
+    fn parse_all(data: &[u8], source: &str) -> crate::error::Result<Vec<Self>> {
+        let text = std::str::from_utf8(data)
+            .map_err(|e| TalosError::Validation(format!("{}: invalid UTF-8: {e}", source)))?;
+
+        let mut raws = vec![String::new()];
+        for line in text.split_inclusive('\n') {
+            match line.strip_prefix("---") {
+                Some(rest) if rest.trim().is_empty() => raws.push(String::new()),
+                Some(rest) if rest.starts_with([' ', '\t']) => {
+                    raws.push(rest.trim_start().to_string())
+                }
+                _ if line.trim_end() == "..." => {}
+                _ => raws
+                    .last_mut()
+                    .expect("at least one document")
+                    .push_str(line),
+            }
+        }
+
+        raws.into_iter()
+            .filter(|raw| !raw.trim().is_empty())
+            .map(|raw| {
+                let value = serde_yaml::from_str(&raw).map_err(|e| {
+                    TalosError::Validation(format!("{}: invalid YAML: {e}", source))
+                })?;
+                Ok(Self {
+                    raw,
+                    value,
+                    patched: false,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Merge one patch document into the configuration documents.
+#[allow(clippy::result_large_err)]
+fn apply_patch_document(
+    documents: &mut Vec<ConfigDocument>,
+    patch: serde_yaml::Value,
+    source: &str,
+) -> crate::error::Result<()> {
+    if patch.is_null() {
+        return Ok(());
+    }
+    let key = DocumentKey::of(&patch).ok_or_else(|| {
+        TalosError::Validation(format!(
+            "{}: only strategic merge patches (YAML mappings) are supported",
+            source
+        ))
+    })?;
+
+    let existing = documents
+        .iter()
+        .position(|doc| DocumentKey::of(&doc.value).as_ref() == Some(&key));
+    match (existing, is_delete(&patch)) {
+        (Some(index), true) => {
+            documents.remove(index);
+        }
+        (Some(index), false) => {
+            let doc = &mut documents[index];
+            merge_yaml(&mut doc.value, patch, &mut Vec::new());
+            doc.patched = true;
+        }
+        (None, true) => {
+            return Err(TalosError::Validation(format!(
+                "{}: cannot delete document {}, it is not in the configuration",
+                source, key
+            )));
+        }
+        (None, false) => documents.push(ConfigDocument {
+            raw: String::new(),
+            value: patch,
+            patched: true,
+        }),
+    }
+    Ok(())
+}
+
+#[allow(clippy::result_large_err)]
+fn render_documents(documents: &[ConfigDocument]) -> crate::error::Result<String> {
+    let mut rendered = Vec::with_capacity(documents.len());
+    for doc in documents {
+        let mut text = if doc.patched {
+            serde_yaml::to_string(&doc.value).map_err(|e| {
+                TalosError::Validation(format!("Failed to serialize patched configuration: {e}"))
+            })?
+        } else {
+            doc.raw.clone()
+        };
+        if !text.ends_with('\n') {
+            text.push('\n');
         }
+        rendered.push(text);
+    }
+    Ok(rendered.join("---\n"))
+}
+
+fn is_delete(value: &serde_yaml::Value) -> bool {
+    value
+        .as_mapping()
+        .and_then(|m| m.get(PATCH_DIRECTIVE))
+        .and_then(serde_yaml::Value::as_str)
+        == Some("delete")
+}
+
+/// Merge `patch` into `base` following Talos' rules; `path` holds the
+/// mapping keys leading to `base`.
+fn merge_yaml(base: &mut serde_yaml::Value, patch: serde_yaml::Value, path: &mut Vec<String>) {
+    if REPLACED_PATHS.iter().any(|p| *p == path.as_slice()) {
+        *base = patch;
+        return;
+    }
+    match (base, patch) {
+        (serde_yaml::Value::Mapping(base), serde_yaml::Value::Mapping(patch)) => {
+            for (key, value) in patch {
+                if key.as_str() == Some(PATCH_DIRECTIVE) {
+                    continue;
+                }
+                if is_delete(&value) {
+                    base.remove(&key);
+                    continue;
+                }
+                match base.get_mut(&key) {
+                    Some(existing) => {
+                        path.push(key.as_str().unwrap_or_default().to_string());
+                        merge_yaml(existing, value, path);
+                        path.pop();
+                    }
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (serde_yaml::Value::Sequence(base), serde_yaml::Value::Sequence(patch)) => {
+            let fields = KEYED_LISTS
+                .iter()
+                .find(|(p, _)| *p == path.as_slice())
+                .map(|(_, fields)| *fields);
+            for item in patch {
+                let existing = fields.and_then(|fields| {
+                    base.iter()
+                        .position(|b| fields.iter().any(|f| same_field(b, &item, f)))
+                });
+                match existing {
+                    Some(index) if is_delete(&item) => {
+                        base.remove(index);
+                    }
+                    Some(index) => merge_yaml(&mut base[index], item, path),
+                    None => base.push(item),
+                }
+            }
+        }
+        (base, patch) => *base = patch,
+    }
+}
+
+/// Check if both values are mappings with the same, present `field`.
+fn same_field(a: &serde_yaml::Value, b: &serde_yaml::Value, field: &str) -> bool {
+    match (a.get(field), b.get(field)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
     }
 }
 
@@ -210,23 +566,223 @@ pub struct ApplyConfigurationResult {
     pub node: Option<String>,
     /// Configuration validation warnings
     pub warnings: Vec<String>,
+    /// Error the node reported instead of applying the configuration
+    pub error: Option<String>,
     /// Mode that was actually applied
+    ///
+    /// Modes this client doesn't know are kept as [`ApplyMode::Unknown`].
     pub mode: ApplyMode,
+    /// Raw mode value as sent by the server
+    pub mode_raw: i32,
     /// Human-readable description of the result
     pub mode_details: String,
 }
 
 impl From<ProtoApplyConfiguration> for ApplyConfigurationResult {
     fn from(proto: ProtoApplyConfiguration) -> Self {
+        let (node, error) = match proto.metadata {
+            Some(m) => (Some(m.hostname), Some(m.error).filter(|e| !e.is_empty())),
+            None => (None, None),
+        };
         Self {
-            node: proto.metadata.map(|m| m.hostname),
+            node,
             warnings: proto.warnings,
+            error,
             mode: proto.mode.into(),
+            mode_raw: proto.mode,
             mode_details: proto.mode_details,
         }
     }
 }
 
+impl ApplyConfigurationResult {
+    /// Parse [`mode_details`](Self::mode_details).
+    #[must_use]
+    pub fn details(&self) -> ApplyModeDetails {
+        ApplyModeDetails::parse(&self.mode_details)
+    }
+
+    /// Get this node's error and warnings, classified.
+    ///
+    /// The node error, if any, comes first.
+    #[must_use]
+    pub fn classified_warnings(&self) -> Vec<ApplyWarning> {
+        let nodes: Vec<String> = self.node.iter().cloned().collect();
+        self.error
+            .iter()
+            .map(|e| (e, WarningSeverity::Error))
+            .chain(self.warnings.iter().map(|w| (w, WarningSeverity::Warning)))
+            .map(|(message, severity)| ApplyWarning::new(message, severity, nodes.clone()))
+            .collect()
+    }
+}
+
+/// Structured form of the `mode_details` text of an apply.
+///
+/// Talos only sends free text; this splits out the parts it always formats
+/// the same way. Anything unrecognised ends up in `summary` unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApplyModeDetails {
+    /// What the node did (or would have done), e.g. `Applied configuration
+    /// without a reboot`
+    pub summary: String,
+    /// Whether the response is a dry-run summary
+    pub dry_run: bool,
+    /// Configuration diff included in dry-run responses
+    pub config_diff: Option<String>,
+}
+
+impl ApplyModeDetails {
+    const DRY_RUN_PREFIX: &'static str = "Dry run summary:";
+    const DRY_RUN_SKIPPED: &'static str = " (skipped in dry-run).";
+    const DIFF_HEADER: &'static str = "Config diff:";
+
+    /// Parse the `mode_details` text of an apply result.
+    #[must_use]
+    pub fn parse(details: &str) -> Self {
+        let Some(rest) = details.trim_start().strip_prefix(Self::DRY_RUN_PREFIX) else {
+            return Self {
+                summary: details.trim().to_string(),
+                ..Default::default()
+            };
+        };
+
+        let (summary, config_diff) = match rest.split_once(Self::DIFF_HEADER) {
+            Some((summary, diff)) => (summary, Some(diff.trim_matches('\n').to_string())),
+            None => (rest, None),
+        };
+        let summary = summary.trim();
+        Self {
+            summary: summary
+                .strip_suffix(Self::DRY_RUN_SKIPPED)
+                .unwrap_or(summary)
+                .to_string(),
+            dry_run: true,
+            config_diff,
+        }
+    }
+}
+
+/// Severity of an [`ApplyWarning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningSeverity {
+    /// Validation warning; the configuration was still applied.
+    Warning,
+    /// Error the node reported instead of a result.
+    Error,
+}
+
+/// What an [`ApplyWarning`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    /// A deprecated field or value; safe to apply but worth migrating.
+    Deprecation,
+    /// A setting that is accepted but has no effect, e.g. because another
+    /// setting overrides it.
+    Ignored,
+    /// A problem with the configuration itself.
+    Validation,
+    /// Anything not recognised, such as a node that could not be reached or
+    /// a warning from a newer Talos release.
+    Other,
+}
+
+/// Wording Talos uses for deprecated fields and legacy values.
+const DEPRECATION_PATTERNS: &[&str] = &["deprecated", "instead of", "please use"];
+
+/// Wording Talos uses for settings without effect.
+const IGNORED_PATTERNS: &[&str] = &[
+    "is ignored",
+    "are ignored",
+    "will be ignored",
+    "has no effect",
+];
+
+/// Wording Talos uses for problems with the configuration.
+const VALIDATION_PATTERNS: &[&str] = &[
+    "invalid",
+    "not valid",
+    "not a valid",
+    "no such",
+    "is required",
+    "must be",
+    "should be",
+    "conflicts with",
+    "overlaps",
+    "not supported",
+    "unsupported",
+];
+
+impl WarningKind {
+    /// Classify a warning or error message.
+    ///
+    /// Talos only reports text, so this matches on the wording of its known
+    /// warnings: deprecations, settings that are ignored, and validation
+    /// problems. Anything else is [`Other`](Self::Other), which only blocks
+    /// a rollout when the node reported it as an error.
+    #[must_use]
+    pub fn classify(message: &str, severity: WarningSeverity) -> Self {
+        let message = message.to_ascii_lowercase();
+        let matches = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+        if matches(DEPRECATION_PATTERNS) {
+            Self::Deprecation
+        } else if matches(IGNORED_PATTERNS) {
+            Self::Ignored
+        } else if matches(VALIDATION_PATTERNS)
+            || (severity == WarningSeverity::Error && message.contains("config"))
+        {
+            Self::Validation
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// A distinct warning or error from an apply, with the nodes reporting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyWarning {
+    /// Warning or error message
+    pub message: String,
+    /// Whether the node marked this as an error
+    pub severity: WarningSeverity,
+    /// What the message is about
+    pub kind: WarningKind,
+    /// Nodes that reported it, in response order (results without a node
+    /// name are only counted)
+    pub nodes: Vec<String>,
+    /// Number of results that reported it
+    pub count: usize,
+}
+
+impl ApplyWarning {
+    fn new(message: &str, severity: WarningSeverity, nodes: Vec<String>) -> Self {
+        Self {
+            message: message.to_string(),
+            severity,
+            kind: WarningKind::classify(message, severity),
+            nodes,
+            count: 1,
+        }
+    }
+
+    /// Check if this should stop a rollout: any node error, or a warning
+    /// about the configuration itself. Deprecations, ignored settings and
+    /// unrecognised warnings are not blocking.
+    #[must_use]
+    pub fn is_blocking(&self) -> bool {
+        self.severity == WarningSeverity::Error || self.kind == WarningKind::Validation
+    }
+}
+
+/// Classified warnings reported by a single node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeApplyWarnings {
+    /// Node identifier (if available)
+    pub node: Option<String>,
+    /// The node's error and warnings
+    pub warnings: Vec<ApplyWarning>,
+}
+
 /// Response from applying configuration.
 #[derive(Debug, Clone)]
 pub struct ApplyConfigurationResponse {
@@ -243,10 +799,19 @@ impl From<ProtoResponse> for ApplyConfigurationResponse {
 }
 
 impl ApplyConfigurationResponse {
-    /// Check if all nodes applied the configuration successfully (no warnings).
+    /// Check if all nodes applied the configuration successfully (no warnings
+    /// or errors).
     #[must_use]
     pub fn is_success(&self) -> bool {
-        self.results.iter().all(|r| r.warnings.is_empty())
+        self.results
+            .iter()
+            .all(|r| r.warnings.is_empty() && r.error.is_none())
+    }
+
+    /// Check if any node reported an error.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.results.iter().any(|r| r.error.is_some())
     }
 
     /// Get all warnings from all nodes.
@@ -258,17 +823,245 @@ impl ApplyConfigurationResponse {
             .collect()
     }
 
+    /// Get each distinct warning and node error once, with the nodes that
+    /// reported it.
+    ///
+    /// Messages keep the order in which they were first seen. Node errors
+    /// (reported in the response metadata) are classified as
+    /// [`WarningSeverity::Error`] and kept apart from a warning with the
+    /// same text.
+    #[must_use]
+    pub fn unique_warnings(&self) -> Vec<ApplyWarning> {
+        let mut unique: Vec<ApplyWarning> = Vec::new();
+        for result in &self.results {
+            let messages = result
+                .error
+                .iter()
+                .map(|e| (e, WarningSeverity::Error))
+                .chain(
+                    result
+                        .warnings
+                        .iter()
+                        .map(|w| (w, WarningSeverity::Warning)),
+                );
+            for (message, severity) in messages {
+                let index = match unique
+                    .iter()
+                    .position(|w| w.severity == severity && w.message == *message)
+                {
+                    Some(index) => index,
+                    None => {
+                        let mut warning = ApplyWarning::new(message, severity, Vec::new());
+                        warning.count = 0;
+                        unique.push(warning);
+                        unique.len() - 1
+                    }
+                };
+                let warning = &mut unique[index];
+                warning.count += 1;
+                if let Some(node) = &result.node {
+                    if !warning.nodes.contains(node) {
+                        warning.nodes.push(node.clone());
+                    }
+                }
+            }
+        }
+        unique
+    }
+
+    /// Get the classified error and warnings of each node that reported
+    /// any, in response order.
+    #[must_use]
+    pub fn warnings_by_node(&self) -> Vec<NodeApplyWarnings> {
+        self.results
+            .iter()
+            .map(|r| NodeApplyWarnings {
+                node: r.node.clone(),
+                warnings: r.classified_warnings(),
+            })
+            .filter(|n| !n.warnings.is_empty())
+            .collect()
+    }
+
+    /// Check if any node reported an error or a validation warning.
+    ///
+    /// Use this to fail a pipeline on configuration problems while letting
+    /// deprecation notices and other harmless warnings through; see [`ApplyWarning::is_blocking`].
+    #[must_use]
+    pub fn has_blocking_warnings(&self) -> bool {
+        self.results
+            .iter()
+            .flat_map(ApplyConfigurationResult::classified_warnings)
+            .any(|w| w.is_blocking())
+    }
+
     /// Get the first result (useful for single-node operations).
     #[must_use]
     pub fn first(&self) -> Option<&ApplyConfigurationResult> {
         self.results.first()
     }
+
+    /// Check that at least `expected` nodes returned a result.
+    ///
+    /// [`is_success`](Self::is_success) is vacuously true when a node never
+    /// answered; pass
+    /// [`NodeTarget::expected_responses`](crate::client::NodeTarget::expected_responses)
+    /// here to confirm every targeted node applied the configuration.
+    #[must_use]
+    pub fn reached_all(&self, expected: usize) -> bool {
+        self.results.len() >= expected
+    }
+}
+
+/// Default time [`ApplyRollbackPolicy`] watches for a failure.
+pub const DEFAULT_ROLLBACK_WATCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// When [`TalosClient::apply_then_rollback_on_failure`](crate::TalosClient::apply_then_rollback_on_failure)
+/// gives up on an applied configuration and rolls the node back to its
+/// previous Talos installation.
+///
+/// The policy only sees events already correlated with the apply. By
+/// default a configuration load or validation error within
+/// [`DEFAULT_ROLLBACK_WATCH_TIMEOUT`] counts as a failure.
+#[derive(Clone)]
+pub struct ApplyRollbackPolicy {
+    /// How long to watch events after applying.
+    pub watch_timeout: Duration,
+    is_failure: Arc<dyn Fn(&Event) -> bool + Send + Sync>,
+}
+
+impl ApplyRollbackPolicy {
+    /// Watch for `watch_timeout` with the default failure signal.
+    #[must_use]
+    pub fn new(watch_timeout: Duration) -> Self {
+        Self {
+            watch_timeout,
+            is_failure: Arc::new(Self::default_failure),
+        }
+    }
+
+    /// Decide which events signal a failure.
+    #[must_use]
+    pub fn with_failure_signal(
+        mut self,
+        is_failure: impl Fn(&Event) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.is_failure = Arc::new(is_failure);
+        self
+    }
+
+    /// Check whether `event` signals a failure under this policy.
+    #[must_use]
+    pub fn is_failure(&self, event: &Event) -> bool {
+        (self.is_failure)(event)
+    }
+
+    /// The default failure signal: the node failed to load or validate its
+    /// configuration.
+    ///
+    /// Sequence errors are not included, since the sequences a node runs
+    /// (boot, upgrade, reset, ...) are not started by an apply.
+    #[must_use]
+    pub fn default_failure(event: &Event) -> bool {
+        matches!(
+            event.kind(),
+            Some(
+                MachineEventKind::ConfigLoadError { .. }
+                    | MachineEventKind::ConfigValidationError { .. }
+            )
+        )
+    }
+}
+
+impl Default for ApplyRollbackPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROLLBACK_WATCH_TIMEOUT)
+    }
+}
+
+impl std::fmt::Debug for ApplyRollbackPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApplyRollbackPolicy")
+            .field("watch_timeout", &self.watch_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Result of [`TalosClient::apply_then_rollback_on_failure`](crate::TalosClient::apply_then_rollback_on_failure).
+#[derive(Debug, Clone)]
+pub enum ApplyRollbackOutcome {
+    /// No failure was seen while watching; the configuration stays applied.
+    Kept {
+        /// Response from the apply.
+        applied: ApplyConfigurationResponse,
+    },
+    /// A failure was seen and the node was rolled back to its previous
+    /// Talos installation.
+    RolledBack {
+        /// Response from the apply.
+        applied: ApplyConfigurationResponse,
+        /// Event that signalled the failure.
+        failure: Box<Event>,
+        /// Response from the rollback.
+        rollback: RollbackResponse,
+    },
+}
+
+impl ApplyRollbackOutcome {
+    /// Check if the node was rolled back.
+    #[must_use]
+    pub fn is_rolled_back(&self) -> bool {
+        matches!(self, Self::RolledBack { .. })
+    }
+
+    /// Response from the apply.
+    #[must_use]
+    pub fn applied(&self) -> &ApplyConfigurationResponse {
+        match self {
+            Self::Kept { applied } | Self::RolledBack { applied, .. } => applied,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_result_mode_parsing() {
+        let cases = [
+            (ProtoMode::Reboot, ApplyMode::Reboot),
+            (ProtoMode::Auto, ApplyMode::Auto),
+            (ProtoMode::NoReboot, ApplyMode::NoReboot),
+            (ProtoMode::Staged, ApplyMode::Staged),
+            (ProtoMode::Try, ApplyMode::Try),
+        ];
+
+        for (proto_mode, expected) in cases {
+            let result = ApplyConfigurationResult::from(ProtoApplyConfiguration {
+                metadata: None,
+                warnings: vec![],
+                mode: proto_mode as i32,
+                mode_details: String::new(),
+            });
+            assert_eq!(result.mode, expected);
+            assert_eq!(result.mode_raw, proto_mode as i32);
+        }
+    }
+
+    #[test]
+    fn test_result_unknown_mode() {
+        let result = ApplyConfigurationResult::from(ProtoApplyConfiguration {
+            metadata: None,
+            warnings: vec![],
+            mode: 42,
+            mode_details: "Applied in a future mode".to_string(),
+        });
+        assert_eq!(result.mode, ApplyMode::Unknown(42));
+        assert_eq!(result.mode.to_string(), "unknown(42)");
+        assert_eq!(result.mode_raw, 42);
+    }
+
     #[test]
     fn test_apply_mode_conversion() {
         assert_eq!(i32::from(ApplyMode::Reboot), 0);
@@ -282,6 +1075,8 @@ mod tests {
         assert_eq!(ApplyMode::from(2), ApplyMode::NoReboot);
         assert_eq!(ApplyMode::from(3), ApplyMode::Staged);
         assert_eq!(ApplyMode::from(4), ApplyMode::Try);
+        assert_eq!(ApplyMode::from(42), ApplyMode::Unknown(42));
+        assert_eq!(i32::from(ApplyMode::Unknown(42)), 42);
     }
 
     #[test]
@@ -291,7 +1086,8 @@ mod tests {
             .mode(ApplyMode::NoReboot)
             .dry_run(true)
             .try_mode_timeout(Duration::from_secs(60))
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(request.data, b"machine:\n  type: worker");
         assert_eq!(request.mode, ApplyMode::NoReboot);
@@ -314,7 +1110,8 @@ mod tests {
             .mode(ApplyMode::Staged)
             .dry_run(true)
             .try_mode_timeout(Duration::from_secs(120))
-            .build();
+            .build()
+            .unwrap();
 
         let proto: ProtoRequest = request.into();
         assert_eq!(proto.data, b"test");
@@ -331,4 +1128,415 @@ mod tests {
         assert_eq!(ApplyMode::Staged.to_string(), "staged");
         assert_eq!(ApplyMode::Try.to_string(), "try");
     }
+
+    #[test]
+    fn test_response_reached_all() {
+        let result = |node: &str| ApplyConfigurationResult {
+            node: Some(node.to_string()),
+            warnings: vec![],
+            error: None,
+            mode: ApplyMode::NoReboot,
+            mode_raw: 2,
+            mode_details: String::new(),
+        };
+        let response = ApplyConfigurationResponse {
+            results: vec![result("node1")],
+        };
+
+        // Two nodes targeted, one answered: still "successful" but not complete.
+        assert!(response.is_success());
+        assert!(!response.reached_all(2));
+        assert!(response.reached_all(1));
+        assert!(!ApplyConfigurationResponse { results: vec![] }.reached_all(1));
+    }
+
+    #[test]
+    fn test_unique_warnings() {
+        let result =
+            |node: &str, warnings: &[&str], error: Option<&str>| ApplyConfigurationResult {
+                node: Some(node.to_string()),
+                warnings: warnings.iter().map(|w| w.to_string()).collect(),
+                error: error.map(str::to_string),
+                mode: ApplyMode::NoReboot,
+                mode_raw: 2,
+                mode_details: String::new(),
+            };
+        let deprecated = "machine.install.extensions is deprecated";
+        let response = ApplyConfigurationResponse {
+            results: vec![
+                result(
+                    "cp-1",
+                    &[deprecated, "cluster.network.cni is ignored"],
+                    None,
+                ),
+                result("cp-2", &[deprecated], None),
+                result("cp-3", &[], Some("configuration validation failed")),
+                result(
+                    "cp-4",
+                    &[deprecated],
+                    Some("configuration validation failed"),
+                ),
+            ],
+        };
+
+        assert_eq!(response.all_warnings().len(), 4);
+        assert!(response.has_errors());
+        assert!(!response.is_success());
+
+        let unique = response.unique_warnings();
+        assert_eq!(unique.len(), 3);
+
+        assert_eq!(unique[0].message, deprecated);
+        assert_eq!(unique[0].severity, WarningSeverity::Warning);
+        assert_eq!(unique[0].nodes, vec!["cp-1", "cp-2", "cp-4"]);
+        assert_eq!(unique[0].count, 3);
+
+        assert_eq!(unique[1].message, "cluster.network.cni is ignored");
+        assert_eq!(unique[1].nodes, vec!["cp-1"]);
+
+        assert_eq!(unique[2].severity, WarningSeverity::Error);
+        assert_eq!(unique[2].nodes, vec!["cp-3", "cp-4"]);
+    }
+
+    #[test]
+    fn test_warnings_by_node_and_blocking() {
+        let result =
+            |node: &str, warnings: &[&str], error: Option<&str>| ApplyConfigurationResult {
+                node: Some(node.to_string()),
+                warnings: warnings.iter().map(|w| w.to_string()).collect(),
+                error: error.map(str::to_string),
+                mode: ApplyMode::NoReboot,
+                mode_raw: 2,
+                mode_details: String::new(),
+            };
+        let deprecated =
+            "\".machine.install.extensions\" is deprecated, use ExtensionServiceConfig";
+        let response = ApplyConfigurationResponse {
+            results: vec![
+                result("cp-1", &[deprecated], None),
+                result("cp-2", &[], None),
+                result(
+                    "cp-3",
+                    &["machine.install.disk: no such disk"],
+                    Some("rpc error"),
+                ),
+            ],
+        };
+
+        let by_node = response.warnings_by_node();
+        assert_eq!(by_node.len(), 2);
+        assert_eq!(by_node[0].node.as_deref(), Some("cp-1"));
+        assert_eq!(by_node[0].warnings[0].kind, WarningKind::Deprecation);
+        assert!(!by_node[0].warnings[0].is_blocking());
+
+        assert_eq!(by_node[1].node.as_deref(), Some("cp-3"));
+        let [error, warning] = &by_node[1].warnings[..] else {
+            panic!("expected an error and a warning");
+        };
+        assert_eq!(error.severity, WarningSeverity::Error);
+        assert_eq!(error.kind, WarningKind::Other);
+        assert!(error.is_blocking());
+        assert_eq!(warning.kind, WarningKind::Validation);
+        assert_eq!(warning.nodes, vec!["cp-3"]);
+        assert!(response.has_blocking_warnings());
+
+        // Deprecations alone don't block.
+        let response = ApplyConfigurationResponse {
+            results: vec![result("cp-1", &[deprecated], None)],
+        };
+        assert!(!response.has_blocking_warnings());
+        assert_eq!(response.unique_warnings()[0].kind, WarningKind::Deprecation);
+    }
+
+    #[test]
+    fn test_warning_kind_classify() {
+        use WarningSeverity::{Error, Warning};
+        let cases = [
+            (
+                "use \"worker\" instead of \"\" for machine type",
+                Warning,
+                WarningKind::Deprecation,
+            ),
+            (
+                "\".machine.install.extensions\" is deprecated, please see https://www.talos.dev/",
+                Warning,
+                WarningKind::Deprecation,
+            ),
+            ("field is DEPRECATED", Error, WarningKind::Deprecation),
+            (
+                "cluster.network.cni is ignored",
+                Warning,
+                WarningKind::Ignored,
+            ),
+            (
+                "machine.install.disk: no such disk",
+                Warning,
+                WarningKind::Validation,
+            ),
+            (
+                "\"10.0.0.0/33\" is not a valid CIDR",
+                Warning,
+                WarningKind::Validation,
+            ),
+            (
+                "1 error occurred: invalid machine type",
+                Error,
+                WarningKind::Validation,
+            ),
+            ("failed to load config", Error, WarningKind::Validation),
+            (
+                "kubelet image tag differs from the Kubernetes version",
+                Warning,
+                WarningKind::Other,
+            ),
+            ("connection refused", Error, WarningKind::Other),
+        ];
+        for (message, severity, kind) in cases {
+            assert_eq!(WarningKind::classify(message, severity), kind, "{message}");
+        }
+
+        // Harmless and unknown warnings don't block; errors always do.
+        let warning = |message: &str, severity| ApplyWarning::new(message, severity, vec![]);
+        assert!(!warning("cluster.network.cni is ignored", Warning).is_blocking());
+        assert!(!warning("kubelet image tag differs", Warning).is_blocking());
+        assert!(warning("\"10.0.0.0/33\" is not a valid CIDR", Warning).is_blocking());
+        assert!(warning("connection refused", Error).is_blocking());
+    }
+
+    #[test]
+    fn test_mode_details_parse() {
+        let details = ApplyModeDetails::parse("Applied configuration without a reboot");
+        assert_eq!(details.summary, "Applied configuration without a reboot");
+        assert!(!details.dry_run);
+        assert_eq!(details.config_diff, None);
+
+        let details = ApplyModeDetails::parse(
+            "Dry run summary:\nApplied configuration without a reboot (skipped in dry-run).\n\n\
+             Config diff:\n\n--- a\n+++ b\n@@ -1 +1 @@\n-x\n+y\n",
+        );
+        assert!(details.dry_run);
+        assert_eq!(details.summary, "Applied configuration without a reboot");
+        assert_eq!(
+            details.config_diff.as_deref(),
+            Some("--- a\n+++ b\n@@ -1 +1 @@\n-x\n+y")
+        );
+
+        let result = ApplyConfigurationResult::from(ProtoApplyConfiguration {
+            mode: ProtoMode::Staged as i32,
+            mode_details: "Staged configuration to be applied after the next reboot".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(
+            result.details().summary,
+            "Staged configuration to be applied after the next reboot"
+        );
+    }
+
+    #[test]
+    fn test_result_error_from_metadata() {
+        let result = ApplyConfigurationResult::from(ProtoApplyConfiguration {
+            metadata: Some(crate::api::common::Metadata {
+                hostname: "cp-1".to_string(),
+                error: "connection refused".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        assert_eq!(result.error.as_deref(), Some("connection refused"));
+
+        let result = ApplyConfigurationResult::from(ProtoApplyConfiguration {
+            metadata: Some(crate::api::common::Metadata {
+                hostname: "cp-1".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        assert!(result.error.is_none());
+    }
+
+    #[test]
+    fn test_apply_mode_string_round_trip() {
+        let cases = [
+            (ApplyMode::Auto, "auto"),
+            (ApplyMode::NoReboot, "no-reboot"),
+            (ApplyMode::Reboot, "reboot"),
+            (ApplyMode::Staged, "staged"),
+            (ApplyMode::Try, "try"),
+        ];
+        for (mode, text) in cases {
+            assert_eq!(mode.to_string(), text);
+            assert_eq!(text.parse::<ApplyMode>().unwrap(), mode);
+        }
+        assert_eq!(
+            "No-Reboot".parse::<ApplyMode>().unwrap(),
+            ApplyMode::NoReboot
+        );
+    }
+
+    #[test]
+    fn test_apply_mode_parse_unknown() {
+        let err = "noreboot".parse::<ApplyMode>().unwrap_err();
+        assert!(matches!(err, TalosError::Validation(ref msg) if msg.contains("noreboot")));
+    }
+
+    #[test]
+    fn test_builder_applies_patch_files_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("worker.yaml");
+        let first = dir.path().join("01-hostname.yaml");
+        let second = dir.path().join("02-hostname.yaml");
+        std::fs::write(
+            &config,
+            "machine:\n  type: worker\n  network:\n    hostname: base\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &first,
+            "machine:\n  network:\n    hostname: first\n    nameservers: [1.1.1.1]\n",
+        )
+        .unwrap();
+        std::fs::write(&second, "machine:\n  network:\n    hostname: second\n").unwrap();
+
+        let request = ApplyConfigurationRequest::builder()
+            .config_file(&config)
+            .patch_file(&first)
+            .patch_file(&second)
+            .build()
+            .unwrap();
+
+        let merged: serde_yaml::Value = serde_yaml::from_slice(&request.data).unwrap();
+        assert_eq!(merged["machine"]["type"], "worker");
+        assert_eq!(merged["machine"]["network"]["hostname"], "second");
+        assert_eq!(merged["machine"]["network"]["nameservers"][0], "1.1.1.1");
+    }
+
+    #[test]
+    fn test_builder_patches_multi_document_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("controlplane.yaml");
+        let patch = dir.path().join("patch.yaml");
+        std::fs::write(
+            &config,
+            "version: v1alpha1\n\
+             machine:\n  type: controlplane\n  certSANs: [a.example]\n\
+             cluster:\n  network:\n    podSubnets: [10.244.0.0/16]\n\
+             ---\n\
+             # untouched\n\
+             apiVersion: v1alpha1\nkind: HostnameConfig\nhostname: cp-1\n\
+             ---\n\
+             apiVersion: v1alpha1\nkind: KmsgLogConfig\nname: remote\nurl: udp://10.0.0.1:514\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &patch,
+            "machine:\n  certSANs: [b.example]\n\
+             cluster:\n  network:\n    podSubnets: [10.100.0.0/16]\n\
+             ---\n\
+             apiVersion: v1alpha1\nkind: KmsgLogConfig\nname: remote\n$patch: delete\n\
+             ---\n\
+             apiVersion: v1alpha1\nkind: TimeSyncConfig\nbootTimeout: 2m\n",
+        )
+        .unwrap();
+
+        let request = ApplyConfigurationRequest::builder()
+            .config_file(&config)
+            .patch_file(&patch)
+            .build()
+            .unwrap();
+
+        let text = String::from_utf8(request.data).unwrap();
+        let docs: Vec<serde_yaml::Value> = serde_yaml::Deserializer::from_str(&text)
+            .map(|d| serde::Deserialize::deserialize(d).unwrap())
+            .collect();
+        assert_eq!(docs.len(), 3);
+        assert_eq!(
+            docs[0]["machine"]["certSANs"],
+            serde_yaml::from_str::<serde_yaml::Value>("[a.example, b.example]").unwrap()
+        );
+        assert_eq!(
+            docs[0]["cluster"]["network"]["podSubnets"],
+            serde_yaml::from_str::<serde_yaml::Value>("[10.100.0.0/16]").unwrap()
+        );
+        assert_eq!(docs[1]["kind"], "HostnameConfig");
+        assert_eq!(docs[2]["kind"], "TimeSyncConfig");
+        assert!(text.contains("# untouched\napiVersion: v1alpha1\nkind: HostnameConfig\n"));
+    }
+
+    #[test]
+    fn test_builder_patch_merges_interfaces_by_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("worker.yaml");
+        let patch = dir.path().join("patch.yaml");
+        std::fs::write(
+            &config,
+            "machine:\n  network:\n    interfaces:\n\
+             \x20     - interface: eth0\n        dhcp: true\n\
+             \x20     - interface: eth1\n        mtu: 1500\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &patch,
+            "machine:\n  network:\n    interfaces:\n\
+             \x20     - interface: eth0\n        mtu: 9000\n\
+             \x20     - interface: eth1\n        $patch: delete\n\
+             \x20     - interface: eth2\n        dhcp: true\n",
+        )
+        .unwrap();
+
+        let request = ApplyConfigurationRequest::builder()
+            .config_file(&config)
+            .patch_file(&patch)
+            .build()
+            .unwrap();
+
+        let merged: serde_yaml::Value = serde_yaml::from_slice(&request.data).unwrap();
+        let interfaces = merged["machine"]["network"]["interfaces"]
+            .as_sequence()
+            .unwrap();
+        assert_eq!(interfaces.len(), 2);
+        assert_eq!(interfaces[0]["interface"], "eth0");
+        assert_eq!(interfaces[0]["dhcp"], true);
+        assert_eq!(interfaces[0]["mtu"], 9000);
+        assert_eq!(interfaces[1]["interface"], "eth2");
+    }
+
+    #[test]
+    fn test_builder_config_file_without_patches_is_verbatim() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("worker.yaml");
+        std::fs::write(&config, "# keep me\nmachine:\n  type: worker\n").unwrap();
+
+        let request = ApplyConfigurationRequest::builder()
+            .config_file(&config)
+            .build()
+            .unwrap();
+        assert_eq!(request.data, b"# keep me\nmachine:\n  type: worker\n");
+    }
+
+    #[test]
+    fn test_builder_file_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("worker.yaml");
+        std::fs::write(&config, "machine:\n  type: worker\n").unwrap();
+
+        let missing = ApplyConfigurationRequest::builder()
+            .config_file(&config)
+            .patch_file(dir.path().join("missing.yaml"))
+            .build();
+        assert!(matches!(missing, Err(TalosError::Config(_))));
+
+        let oversized = ApplyConfigurationRequest::builder()
+            .config_file(&config)
+            .max_config_bytes(8)
+            .build();
+        assert!(matches!(oversized, Err(TalosError::Validation(msg)) if msg.contains("cap")));
+
+        let list_patch = dir.path().join("json6902.yaml");
+        std::fs::write(&list_patch, "- op: remove\n  path: /machine/type\n").unwrap();
+        let unsupported = ApplyConfigurationRequest::builder()
+            .config_file(&config)
+            .patch_file(&list_patch)
+            .build();
+        assert!(matches!(unsupported, Err(TalosError::Validation(_))));
+    }
 }
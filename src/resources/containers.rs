@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for the Containers and Stats APIs.
+//!
+//! Lists the containers running on a node and their resource usage, for
+//! debugging workloads without going through Kubernetes.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use talos_api_rs::resources::ContainersRequest;
+//!
+//! // Talos system containers
+//! let request = ContainersRequest::system();
+//!
+//! // Kubernetes pods, as seen by the CRI
+//! let request = ContainersRequest::kubernetes();
+//! ```
+
+use super::logs::ContainerDriver;
+use crate::api::generated::machine::{
+    Container as ProtoContainer, ContainerInfo as ProtoContainerInfo,
+    ContainersRequest as ProtoContainersRequest, ContainersResponse as ProtoContainersResponse,
+    Stat as ProtoStat, Stats as ProtoStats, StatsRequest as ProtoStatsRequest,
+    StatsResponse as ProtoStatsResponse,
+};
+
+/// Containerd namespace of Talos system containers.
+const SYSTEM_NAMESPACE: &str = "system";
+/// Containerd namespace of Kubernetes workloads.
+const KUBERNETES_NAMESPACE: &str = "k8s.io";
+
+// =============================================================================
+// ContainersRequest
+// =============================================================================
+
+/// Request to list containers or their stats.
+#[derive(Debug, Clone)]
+pub struct ContainersRequest {
+    /// Containerd namespace (e.g. `system` or `k8s.io`).
+    pub namespace: String,
+    /// Driver used to inspect the containers.
+    pub driver: ContainerDriver,
+}
+
+impl ContainersRequest {
+    /// Create a request for a namespace using the containerd driver.
+    #[must_use]
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            driver: ContainerDriver::Containerd,
+        }
+    }
+
+    /// Create a request for Talos system containers.
+    #[must_use]
+    pub fn system() -> Self {
+        Self::new(SYSTEM_NAMESPACE)
+    }
+
+    /// Create a request for Kubernetes workloads through the CRI, like
+    /// `talosctl containers -k`.
+    #[must_use]
+    pub fn kubernetes() -> Self {
+        Self::new(KUBERNETES_NAMESPACE).with_driver(ContainerDriver::Cri)
+    }
+
+    /// Set the driver.
+    #[must_use]
+    pub fn with_driver(mut self, driver: ContainerDriver) -> Self {
+        self.driver = driver;
+        self
+    }
+}
+
+impl Default for ContainersRequest {
+    fn default() -> Self {
+        Self::system()
+    }
+}
+
+impl From<ContainersRequest> for ProtoContainersRequest {
+    fn from(req: ContainersRequest) -> Self {
+        Self {
+            namespace: req.namespace,
+            driver: req.driver.into(),
+        }
+    }
+}
+
+impl From<ContainersRequest> for ProtoStatsRequest {
+    fn from(req: ContainersRequest) -> Self {
+        Self {
+            namespace: req.namespace,
+            driver: req.driver.into(),
+        }
+    }
+}
+
+// =============================================================================
+// Containers
+// =============================================================================
+
+/// A container running on a node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerInfo {
+    /// Containerd namespace.
+    pub namespace: String,
+    /// Container ID.
+    pub id: String,
+    /// Image reference.
+    pub image: String,
+    /// PID of the main process (0 if not running).
+    pub pid: u32,
+    /// Container status (e.g. `CONTAINER_RUNNING`).
+    pub status: String,
+    /// Pod sandbox ID (CRI containers only).
+    pub pod_id: String,
+    /// Container name.
+    pub name: String,
+}
+
+impl From<ProtoContainerInfo> for ContainerInfo {
+    fn from(proto: ProtoContainerInfo) -> Self {
+        Self {
+            namespace: proto.namespace,
+            id: proto.id,
+            image: proto.image,
+            pid: proto.pid,
+            status: proto.status,
+            pod_id: proto.pod_id,
+            name: proto.name,
+        }
+    }
+}
+
+/// Containers reported by a single node.
+#[derive(Debug, Clone)]
+pub struct ContainersResult {
+    /// Node that returned this result.
+    pub node: Option<String>,
+    /// Containers on the node.
+    pub containers: Vec<ContainerInfo>,
+}
+
+impl From<ProtoContainer> for ContainersResult {
+    fn from(proto: ProtoContainer) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            containers: proto
+                .containers
+                .into_iter()
+                .map(ContainerInfo::from)
+                .collect(),
+        }
+    }
+}
+
+/// Response from listing containers.
+#[derive(Debug, Clone)]
+pub struct ContainersResponse {
+    /// Results from each node.
+    pub results: Vec<ContainersResult>,
+}
+
+impl From<ProtoContainersResponse> for ContainersResponse {
+    fn from(proto: ProtoContainersResponse) -> Self {
+        Self {
+            results: proto
+                .messages
+                .into_iter()
+                .map(ContainersResult::from)
+                .collect(),
+        }
+    }
+}
+
+impl ContainersResponse {
+    /// Get the first result.
+    #[must_use]
+    pub fn first(&self) -> Option<&ContainersResult> {
+        self.results.first()
+    }
+}
+
+// =============================================================================
+// Stats
+// =============================================================================
+
+/// Resource usage of a container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerStat {
+    /// Containerd namespace.
+    pub namespace: String,
+    /// Container ID.
+    pub id: String,
+    /// Memory usage in bytes.
+    pub memory_usage: u64,
+    /// Cumulative CPU time in nanoseconds.
+    pub cpu_usage: u64,
+    /// Pod sandbox ID (CRI containers only).
+    pub pod_id: String,
+    /// Container name.
+    pub name: String,
+}
+
+impl From<ProtoStat> for ContainerStat {
+    fn from(proto: ProtoStat) -> Self {
+        Self {
+            namespace: proto.namespace,
+            id: proto.id,
+            memory_usage: proto.memory_usage,
+            cpu_usage: proto.cpu_usage,
+            pod_id: proto.pod_id,
+            name: proto.name,
+        }
+    }
+}
+
+/// Container stats reported by a single node.
+#[derive(Debug, Clone)]
+pub struct ContainerStatsResult {
+    /// Node that returned this result.
+    pub node: Option<String>,
+    /// Stats per container.
+    pub stats: Vec<ContainerStat>,
+}
+
+impl From<ProtoStats> for ContainerStatsResult {
+    fn from(proto: ProtoStats) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            stats: proto.stats.into_iter().map(ContainerStat::from).collect(),
+        }
+    }
+}
+
+impl ContainerStatsResult {
+    /// Total memory used by all containers, in bytes.
+    #[must_use]
+    pub fn total_memory_usage(&self) -> u64 {
+        self.stats.iter().map(|s| s.memory_usage).sum()
+    }
+}
+
+/// Response from container stats request.
+#[derive(Debug, Clone)]
+pub struct ContainerStatsResponse {
+    /// Results from each node.
+    pub results: Vec<ContainerStatsResult>,
+}
+
+impl From<ProtoStatsResponse> for ContainerStatsResponse {
+    fn from(proto: ProtoStatsResponse) -> Self {
+        Self {
+            results: proto
+                .messages
+                .into_iter()
+                .map(ContainerStatsResult::from)
+                .collect(),
+        }
+    }
+}
+
+impl ContainerStatsResponse {
+    /// Get the first result.
+    #[must_use]
+    pub fn first(&self) -> Option<&ContainerStatsResult> {
+        self.results.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+
+    #[test]
+    fn test_containers_request_to_proto() {
+        let proto: ProtoContainersRequest = ContainersRequest::system().into();
+        assert_eq!(proto.namespace, "system");
+        assert_eq!(proto.driver, 0);
+
+        let proto: ProtoStatsRequest = ContainersRequest::kubernetes().into();
+        assert_eq!(proto.namespace, "k8s.io");
+        assert_eq!(proto.driver, 1);
+    }
+
+    #[test]
+    fn test_containers_response_from_proto() {
+        let proto = ProtoContainersResponse {
+            messages: vec![ProtoContainer {
+                metadata: Some(Metadata {
+                    hostname: "10.0.0.1".to_string(),
+                    ..Default::default()
+                }),
+                containers: vec![ProtoContainerInfo {
+                    namespace: "system".to_string(),
+                    id: "apid".to_string(),
+                    image: "ghcr.io/siderolabs/apid:v1.7.0".to_string(),
+                    pid: 1234,
+                    status: "RUNNING".to_string(),
+                    name: "apid".to_string(),
+                    ..Default::default()
+                }],
+            }],
+        };
+
+        let response = ContainersResponse::from(proto);
+        let first = response.first().unwrap();
+        assert_eq!(first.node.as_deref(), Some("10.0.0.1"));
+        assert_eq!(first.containers[0].id, "apid");
+        assert_eq!(first.containers[0].pid, 1234);
+    }
+
+    #[test]
+    fn test_container_stats_response_from_proto() {
+        let stat = |id: &str, memory_usage: u64| ProtoStat {
+            namespace: "k8s.io".to_string(),
+            id: id.to_string(),
+            memory_usage,
+            cpu_usage: 5_000_000,
+            ..Default::default()
+        };
+        let proto = ProtoStatsResponse {
+            messages: vec![ProtoStats {
+                metadata: None,
+                stats: vec![stat("a", 1024), stat("b", 2048)],
+            }],
+        };
+
+        let response = ContainerStatsResponse::from(proto);
+        let first = response.first().unwrap();
+        assert_eq!(first.stats.len(), 2);
+        assert_eq!(first.stats[1].id, "b");
+        assert_eq!(first.total_memory_usage(), 3072);
+    }
+}
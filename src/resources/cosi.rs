@@ -0,0 +1,361 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for the COSI resource state API.
+//!
+//! Talos keeps its runtime state as COSI resources: machine status, cluster
+//! members, network links, and so on, the same objects `talosctl get`
+//! prints. Each resource is addressed by namespace, type and ID; its spec
+//! is decoded into a generic YAML value, or into your own type with
+//! [`CosiResource::spec_as`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use talos_api_rs::resources::CosiWatchRequest;
+//!
+//! // `talosctl get members --watch`
+//! let request = CosiWatchRequest::new("cluster", "Members.cluster.talos.dev")
+//!     .with_bootstrap_contents(true);
+//! ```
+
+use crate::api::generated::cosi::resource::{
+    Event as ProtoEvent, EventType as ProtoEventType, Metadata as ProtoMetadata,
+    Resource as ProtoResource, WatchOptions as ProtoWatchOptions,
+    WatchRequest as ProtoWatchRequest,
+};
+use crate::error::TalosError;
+use std::collections::HashMap;
+
+/// Watch API version that batches events into one response.
+const WATCH_API_VERSION: i32 = 1;
+
+// =============================================================================
+// Resources
+// =============================================================================
+
+/// Metadata of a COSI resource.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CosiMetadata {
+    /// Namespace (e.g. `runtime`, `network`, `cluster`).
+    pub namespace: String,
+    /// Resource type (e.g. `MachineStatuses.runtime.talos.dev`).
+    pub resource_type: String,
+    /// Resource ID.
+    pub id: String,
+    /// Version, incremented on every update.
+    pub version: String,
+    /// Controller that manages the resource, empty for user resources.
+    pub owner: String,
+    /// Lifecycle phase (`running` or `tearingDown`).
+    pub phase: String,
+    /// When the resource was created.
+    pub created: Option<prost_types::Timestamp>,
+    /// When the resource was last updated.
+    pub updated: Option<prost_types::Timestamp>,
+    /// Controllers blocking teardown of the resource.
+    pub finalizers: Vec<String>,
+    /// Labels.
+    pub labels: HashMap<String, String>,
+    /// Annotations.
+    pub annotations: HashMap<String, String>,
+}
+
+impl From<ProtoMetadata> for CosiMetadata {
+    fn from(proto: ProtoMetadata) -> Self {
+        Self {
+            namespace: proto.namespace,
+            resource_type: proto.r#type,
+            id: proto.id,
+            version: proto.version,
+            owner: proto.owner,
+            phase: proto.phase,
+            created: proto.created,
+            updated: proto.updated,
+            finalizers: proto.finalizers,
+            labels: proto.labels,
+            annotations: proto.annotations,
+        }
+    }
+}
+
+/// A COSI resource with its spec decoded from YAML.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosiResource {
+    /// Resource metadata.
+    pub metadata: CosiMetadata,
+    /// Spec as sent in YAML form; `Null` if the server sent none.
+    pub spec: serde_yaml::Value,
+    /// Spec in protobuf form, for callers with the Talos resource protos.
+    pub proto_spec: Vec<u8>,
+}
+
+impl CosiResource {
+    /// Decode the spec into `T`.
+    ///
+    /// Talos serialises specs with camelCase keys, as printed by
+    /// `talosctl get -o yaml`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Unknown` if the spec doesn't match `T`.
+    #[allow(clippy::result_large_err)]
+    pub fn spec_as<T: serde::de::DeserializeOwned>(&self) -> crate::error::Result<T> {
+        serde_yaml::from_value(self.spec.clone()).map_err(|e| {
+            TalosError::Unknown(format!(
+                "Failed to decode {} spec: {e}",
+                self.metadata.resource_type
+            ))
+        })
+    }
+}
+
+impl TryFrom<ProtoResource> for CosiResource {
+    type Error = TalosError;
+
+    fn try_from(proto: ProtoResource) -> crate::error::Result<Self> {
+        let metadata = CosiMetadata::from(proto.metadata.unwrap_or_default());
+        let spec = proto.spec.unwrap_or_default();
+        let yaml = if spec.yaml_spec.trim().is_empty() {
+            serde_yaml::Value::Null
+        } else {
+            serde_yaml::from_str(&spec.yaml_spec).map_err(|e| {
+                TalosError::Unknown(format!(
+                    "Failed to decode {} spec: {e}",
+                    metadata.resource_type
+                ))
+            })?
+        };
+
+        Ok(Self {
+            metadata,
+            spec: yaml,
+            proto_spec: spec.proto_spec,
+        })
+    }
+}
+
+// =============================================================================
+// Watch
+// =============================================================================
+
+/// Request to watch COSI resources of one type.
+#[derive(Debug, Clone)]
+pub struct CosiWatchRequest {
+    /// Namespace to watch.
+    pub namespace: String,
+    /// Resource type to watch.
+    pub resource_type: String,
+    /// Watch a single resource instead of the whole type.
+    pub id: Option<String>,
+    /// Replay up to this many past events first.
+    pub tail_events: i32,
+    /// Start with a `Created` event for every existing resource, followed by
+    /// [`CosiEventType::Bootstrapped`]. Only for watches of a whole type.
+    pub bootstrap_contents: bool,
+}
+
+impl CosiWatchRequest {
+    /// Watch every resource of `resource_type` in `namespace`.
+    #[must_use]
+    pub fn new(namespace: impl Into<String>, resource_type: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            resource_type: resource_type.into(),
+            id: None,
+            tail_events: 0,
+            bootstrap_contents: false,
+        }
+    }
+
+    /// Watch a single resource.
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Replay up to `count` past events.
+    #[must_use]
+    pub fn with_tail_events(mut self, count: i32) -> Self {
+        self.tail_events = count;
+        self
+    }
+
+    /// Start with the current contents (see [`bootstrap_contents`](Self::bootstrap_contents)).
+    #[must_use]
+    pub fn with_bootstrap_contents(mut self, enabled: bool) -> Self {
+        self.bootstrap_contents = enabled;
+        self
+    }
+}
+
+impl From<CosiWatchRequest> for ProtoWatchRequest {
+    fn from(req: CosiWatchRequest) -> Self {
+        Self {
+            namespace: req.namespace,
+            r#type: req.resource_type,
+            id: req.id.unwrap_or_default(),
+            options: Some(ProtoWatchOptions {
+                tail_events: req.tail_events,
+                bootstrap_contents: req.bootstrap_contents,
+                ..Default::default()
+            }),
+            api_version: WATCH_API_VERSION,
+        }
+    }
+}
+
+/// Kind of change reported by a watch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CosiEventType {
+    /// A resource was created (or existed when the watch bootstrapped).
+    Created,
+    /// A resource was updated.
+    Updated,
+    /// A resource was destroyed.
+    Destroyed,
+    /// All existing resources have been sent.
+    Bootstrapped,
+    /// The watch failed; see [`CosiEvent::error`].
+    Errored,
+    /// Keep-alive without a change.
+    Noop,
+    /// Event type this client doesn't know, with its raw wire value.
+    Unknown(i32),
+}
+
+impl From<i32> for CosiEventType {
+    fn from(value: i32) -> Self {
+        match ProtoEventType::try_from(value) {
+            Ok(ProtoEventType::Created) => Self::Created,
+            Ok(ProtoEventType::Updated) => Self::Updated,
+            Ok(ProtoEventType::Destroyed) => Self::Destroyed,
+            Ok(ProtoEventType::Bootstrapped) => Self::Bootstrapped,
+            Ok(ProtoEventType::Errored) => Self::Errored,
+            Ok(ProtoEventType::Noop) => Self::Noop,
+            Err(_) => Self::Unknown(value),
+        }
+    }
+}
+
+/// A change to a watched resource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosiEvent {
+    /// What happened.
+    pub event_type: CosiEventType,
+    /// The resource after the change (before it, for `Destroyed`).
+    pub resource: Option<CosiResource>,
+    /// The resource before an update.
+    pub old: Option<CosiResource>,
+    /// Error reported with an `Errored` event.
+    pub error: Option<String>,
+}
+
+impl TryFrom<ProtoEvent> for CosiEvent {
+    type Error = TalosError;
+
+    fn try_from(proto: ProtoEvent) -> crate::error::Result<Self> {
+        Ok(Self {
+            event_type: proto.event_type.into(),
+            resource: proto.resource.map(CosiResource::try_from).transpose()?,
+            old: proto.old.map(CosiResource::try_from).transpose()?,
+            error: proto.error.filter(|e| !e.is_empty()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::generated::cosi::resource::Spec as ProtoSpec;
+
+    fn member_resource() -> ProtoResource {
+        ProtoResource {
+            metadata: Some(ProtoMetadata {
+                namespace: "cluster".to_string(),
+                r#type: "Members.cluster.talos.dev".to_string(),
+                id: "cp-1".to_string(),
+                version: "3".to_string(),
+                labels: HashMap::from([("role".to_string(), "cp".to_string())]),
+                ..Default::default()
+            }),
+            spec: Some(ProtoSpec {
+                proto_spec: vec![0x0a, 0x01],
+                yaml_spec:
+                    "hostname: cp-1\naddresses:\n    - 10.0.0.2\nmachineType: controlplane\n"
+                        .to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_resource_from_proto() {
+        let resource = CosiResource::try_from(member_resource()).unwrap();
+        assert_eq!(resource.metadata.namespace, "cluster");
+        assert_eq!(resource.metadata.resource_type, "Members.cluster.talos.dev");
+        assert_eq!(resource.metadata.id, "cp-1");
+        assert_eq!(resource.metadata.labels["role"], "cp");
+        assert_eq!(resource.spec["machineType"].as_str(), Some("controlplane"));
+        assert_eq!(resource.proto_spec, vec![0x0a, 0x01]);
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Member {
+            hostname: String,
+            addresses: Vec<String>,
+        }
+        let member: Member = resource.spec_as().unwrap();
+        assert_eq!(member.hostname, "cp-1");
+        assert_eq!(member.addresses, vec!["10.0.0.2"]);
+        assert!(matches!(
+            resource.spec_as::<Vec<String>>(),
+            Err(TalosError::Unknown(_))
+        ));
+    }
+
+    #[test]
+    fn test_resource_without_yaml_spec() {
+        let mut proto = member_resource();
+        proto.spec = None;
+        let resource = CosiResource::try_from(proto).unwrap();
+        assert!(resource.spec.is_null());
+
+        let mut proto = member_resource();
+        proto.spec.as_mut().unwrap().yaml_spec = "key: [unclosed".to_string();
+        assert!(CosiResource::try_from(proto).is_err());
+    }
+
+    #[test]
+    fn test_watch_request_to_proto() {
+        let proto: ProtoWatchRequest = CosiWatchRequest::new("runtime", "MachineStatuses")
+            .with_id("machine")
+            .with_tail_events(5)
+            .with_bootstrap_contents(true)
+            .into();
+        assert_eq!(proto.namespace, "runtime");
+        assert_eq!(proto.r#type, "MachineStatuses");
+        assert_eq!(proto.id, "machine");
+        assert_eq!(proto.api_version, WATCH_API_VERSION);
+        let options = proto.options.unwrap();
+        assert_eq!(options.tail_events, 5);
+        assert!(options.bootstrap_contents);
+    }
+
+    #[test]
+    fn test_event_from_proto() {
+        let event = CosiEvent::try_from(ProtoEvent {
+            event_type: ProtoEventType::Updated as i32,
+            resource: Some(member_resource()),
+            old: Some(member_resource()),
+            error: Some(String::new()),
+        })
+        .unwrap();
+        assert_eq!(event.event_type, CosiEventType::Updated);
+        assert_eq!(event.resource.unwrap().metadata.id, "cp-1");
+        assert!(event.old.is_some());
+        assert_eq!(event.error, None);
+
+        assert_eq!(CosiEventType::from(3), CosiEventType::Bootstrapped);
+        assert_eq!(CosiEventType::from(42), CosiEventType::Unknown(42));
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Chunks of the `common.Data` streams shared by several Talos APIs.
+//!
+//! Dmesg, Logs, EtcdSnapshot, Read, Copy and PacketCapture all stream raw
+//! bytes wrapped in the same message, so they share one chunk type.
+
+use crate::api::common::Data as ProtoData;
+use bytes::Bytes;
+
+/// Chunk of a streamed `common.Data` response.
+///
+/// Chunks are raw slices of the underlying byte stream and need not end on
+/// a line or record boundary.
+#[derive(Debug, Clone)]
+pub struct DataChunk {
+    /// Raw data.
+    pub bytes: Bytes,
+    /// Node that sent this chunk.
+    ///
+    /// Only some messages carry metadata; chunks without it inherit the
+    /// node of the previous chunk.
+    pub node: Option<String>,
+}
+
+impl DataChunk {
+    /// Build a chunk from a streamed message, falling back to `node` when
+    /// the message carries no metadata.
+    pub(crate) fn from_proto(proto: ProtoData, node: Option<String>) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname).or(node),
+            bytes: Bytes::from(proto.bytes),
+        }
+    }
+
+    /// Convert to string, replacing invalid UTF-8.
+    #[must_use]
+    pub fn as_string_lossy(&self) -> std::borrow::Cow<'_, str> {
+        String::from_utf8_lossy(&self.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+
+    #[test]
+    fn test_from_proto_prefers_metadata_hostname() {
+        let proto = ProtoData {
+            metadata: Some(Metadata {
+                hostname: "cp-1".to_string(),
+                ..Default::default()
+            }),
+            bytes: b"hello".to_vec(),
+        };
+        let chunk = DataChunk::from_proto(proto, Some("previous".to_string()));
+        assert_eq!(chunk.node.as_deref(), Some("cp-1"));
+        assert_eq!(chunk.as_string_lossy(), "hello");
+    }
+
+    #[test]
+    fn test_from_proto_inherits_node_without_metadata() {
+        let proto = ProtoData {
+            metadata: None,
+            bytes: vec![0xff, b'a'],
+        };
+        let chunk = DataChunk::from_proto(proto, Some("cp-1".to_string()));
+        assert_eq!(chunk.node.as_deref(), Some("cp-1"));
+        assert_eq!(chunk.as_string_lossy(), "\u{fffd}a");
+    }
+}
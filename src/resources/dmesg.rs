@@ -4,6 +4,8 @@
 //!
 //! Provides access to the kernel message buffer (dmesg) for diagnostics.
 
+use super::data::DataChunk;
+use super::logs::{detect_text, grep_lines};
 use crate::api::generated::machine::DmesgRequest as ProtoDmesgRequest;
 
 /// Request for kernel message buffer (dmesg).
@@ -88,6 +90,10 @@ impl DmesgRequestBuilder {
     }
 }
 
+/// Chunk of kernel messages, as yielded by
+/// [`TalosClient::dmesg_stream`](crate::TalosClient::dmesg_stream).
+pub type DmesgChunk = DataChunk;
+
 /// Response containing kernel message buffer content.
 #[derive(Debug, Clone)]
 pub struct DmesgResponse {
@@ -121,6 +127,15 @@ impl DmesgResponse {
         String::from_utf8_lossy(&self.data)
     }
 
+    /// Guess whether the data is text rather than binary.
+    ///
+    /// See [`ReadResponse::detect_text`](super::ReadResponse::detect_text)
+    /// for the heuristic.
+    #[must_use]
+    pub fn detect_text(&self) -> bool {
+        detect_text(&self.data)
+    }
+
     /// Get the length in bytes.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -140,6 +155,22 @@ impl DmesgResponse {
             .map(|s| s.lines().collect())
             .unwrap_or_default()
     }
+
+    /// Get the number of lines.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.as_str().map(|s| s.lines().count()).unwrap_or_default()
+    }
+
+    /// Get the lines matching a regular expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if `pattern` is not a valid regex.
+    #[allow(clippy::result_large_err)]
+    pub fn grep(&self, pattern: &str) -> crate::error::Result<Vec<&str>> {
+        grep_lines(self.lines(), pattern)
+    }
 }
 
 #[cfg(test)]
@@ -193,4 +224,20 @@ mod tests {
         assert_eq!(response.lines().len(), 2);
         assert!(response.lines()[0].contains("Linux version"));
     }
+
+    #[test]
+    fn test_dmesg_response_grep() {
+        let data = b"[    0.000000] Linux version 5.15.0\n\
+[    1.204000] eth0: link up\n\
+[    1.500000] EXT4-fs (sda1): mounted filesystem\n\
+[    2.000000] eth0: link down\n"
+            .to_vec();
+        let response = DmesgResponse::new(data, None);
+
+        assert_eq!(response.line_count(), 4);
+        let matches = response.grep(r"eth0: link (up|down)").unwrap();
+        assert_eq!(matches.len(), 2);
+        assert!(matches[1].ends_with("link down"));
+        assert!(response.grep("[").is_err());
+    }
 }
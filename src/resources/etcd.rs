@@ -5,6 +5,7 @@
 //! This module provides high-level types for interacting with the etcd cluster
 //! running on Talos control plane nodes.
 
+use super::data::DataChunk;
 use crate::api::generated::machine::{
     EtcdAlarm as ProtoEtcdAlarm, EtcdAlarmDisarm as ProtoEtcdAlarmDisarm,
     EtcdAlarmDisarmResponse as ProtoEtcdAlarmDisarmResponse,
@@ -19,11 +20,13 @@ use crate::api::generated::machine::{
     EtcdMemberAlarm as ProtoEtcdMemberAlarm, EtcdMemberListRequest as ProtoEtcdMemberListRequest,
     EtcdMemberListResponse as ProtoEtcdMemberListResponse,
     EtcdMemberStatus as ProtoEtcdMemberStatus, EtcdMembers as ProtoEtcdMembers,
+    EtcdRecover as ProtoEtcdRecover, EtcdRecoverResponse as ProtoEtcdRecoverResponse,
     EtcdRemoveMemberByIdRequest as ProtoEtcdRemoveMemberByIdRequest,
     EtcdRemoveMemberByIdResponse as ProtoEtcdRemoveMemberByIdResponse,
     EtcdSnapshotRequest as ProtoEtcdSnapshotRequest, EtcdStatus as ProtoEtcdStatus,
     EtcdStatusResponse as ProtoEtcdStatusResponse,
 };
+use std::collections::BTreeMap;
 
 // =============================================================================
 // EtcdMemberList
@@ -33,6 +36,9 @@ use crate::api::generated::machine::{
 #[derive(Debug, Clone, Default)]
 pub struct EtcdMemberListRequest {
     /// If true, query only the local node's view of the cluster.
+    ///
+    /// Local views can lag behind the cluster; targeting several nodes and
+    /// merging with [`EtcdMemberListResponse::all_members`] shows the union.
     pub query_local: bool,
 }
 
@@ -124,6 +130,10 @@ impl From<ProtoEtcdMemberListResponse> for EtcdMemberListResponse {
 
 impl EtcdMemberListResponse {
     /// Get all unique members across all nodes.
+    ///
+    /// Members are deduplicated by ID. When nodes disagree on a member's
+    /// details, the first report wins; see
+    /// [`conflicting_hostnames`](Self::conflicting_hostnames).
     #[must_use]
     pub fn all_members(&self) -> Vec<&EtcdMember> {
         let mut seen_ids = std::collections::HashSet::new();
@@ -140,6 +150,30 @@ impl EtcdMemberListResponse {
         members
     }
 
+    /// Members whose hostname differs between the nodes' views, by ID.
+    ///
+    /// Each entry lists the distinct hostnames in the order they were
+    /// reported, so the first one is what [`all_members`](Self::all_members)
+    /// keeps. Empty hostnames (members that have not started yet) are not
+    /// counted as conflicts.
+    #[must_use]
+    pub fn conflicting_hostnames(&self) -> BTreeMap<u64, Vec<&str>> {
+        let mut hostnames: BTreeMap<u64, Vec<&str>> = BTreeMap::new();
+
+        for member in self.results.iter().flat_map(|r| &r.members) {
+            if member.hostname.is_empty() {
+                continue;
+            }
+            let seen = hostnames.entry(member.id).or_default();
+            if !seen.contains(&member.hostname.as_str()) {
+                seen.push(&member.hostname);
+            }
+        }
+
+        hostnames.retain(|_, names| names.len() > 1);
+        hostnames
+    }
+
     /// Find a member by hostname.
     #[must_use]
     pub fn find_by_hostname(&self, hostname: &str) -> Option<&EtcdMember> {
@@ -410,11 +444,87 @@ impl EtcdMemberStatus {
         !self.errors.is_empty()
     }
 
+    /// Check if this member knows of an elected leader.
+    #[must_use]
+    pub fn has_leader(&self) -> bool {
+        self.leader != 0
+    }
+
+    /// Check if a leader election happened since the `previous` sample.
+    ///
+    /// The raft term only increases when a new election starts, so a bump
+    /// between two samples of the same member indicates instability.
+    #[must_use]
+    pub fn election_occurred_since(&self, previous: &Self) -> bool {
+        self.raft_term > previous.raft_term
+    }
+
+    /// Check if the raft log advanced since the `previous` sample.
+    ///
+    /// An index that stays put while writes are expected means the member
+    /// is stalled.
+    #[must_use]
+    pub fn index_advanced(&self, previous: &Self) -> bool {
+        self.raft_index > previous.raft_index
+    }
+
     /// Get the database size in human-readable format.
     #[must_use]
     pub fn db_size_human(&self) -> String {
         humanize_bytes(self.db_size as u64)
     }
+
+    /// Share of the database file not in use, in percent.
+    ///
+    /// This is the space a defragmentation would reclaim.
+    #[must_use]
+    pub fn fragmentation_percent(&self) -> f64 {
+        if self.db_size <= 0 {
+            return 0.0;
+        }
+        let unused = (self.db_size - self.db_size_in_use).max(0);
+        unused as f64 / self.db_size as f64 * 100.0
+    }
+}
+
+impl std::fmt::Display for EtcdMemberStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let role = if self.is_learner {
+            "learner"
+        } else if self.is_leader() {
+            "leader"
+        } else {
+            "follower"
+        };
+        writeln!(f, "member {:x} ({})", self.member_id, role)?;
+        if self.has_leader() {
+            writeln!(f, "  leader: {:x}", self.leader)?;
+        } else {
+            writeln!(f, "  leader: none")?;
+        }
+        writeln!(
+            f,
+            "  db size: {} ({} in use, {:.1}% fragmented)",
+            self.db_size_human(),
+            humanize_bytes(self.db_size_in_use.max(0) as u64),
+            self.fragmentation_percent()
+        )?;
+        writeln!(
+            f,
+            "  raft: term {}, index {}, applied {}",
+            self.raft_term, self.raft_index, self.raft_applied_index
+        )?;
+        writeln!(
+            f,
+            "  version: protocol {}, storage {}",
+            self.protocol_version, self.storage_version
+        )?;
+        if self.has_errors() {
+            write!(f, "  errors: {}", self.errors.join("; "))
+        } else {
+            write!(f, "  errors: none")
+        }
+    }
 }
 
 /// Result from status request.
@@ -460,6 +570,35 @@ impl EtcdStatusResponse {
     pub fn first(&self) -> Option<&EtcdMemberStatus> {
         self.results.first().and_then(|r| r.member_status.as_ref())
     }
+
+    /// Check if at least one member reports a leader and no member reports errors.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        let statuses: Vec<_> = self
+            .results
+            .iter()
+            .filter_map(|r| r.member_status.as_ref())
+            .collect();
+        statuses.iter().any(|s| s.has_leader()) && !statuses.iter().any(|s| s.has_errors())
+    }
+}
+
+impl std::fmt::Display for EtcdStatusResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, result) in self.results.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            if let Some(node) = &result.node {
+                write!(f, "node {}: ", node)?;
+            }
+            match &result.member_status {
+                Some(status) => write!(f, "{}", status)?,
+                None => write!(f, "no member status")?,
+            }
+        }
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -669,6 +808,40 @@ impl EtcdDefragmentResponse {
     }
 }
 
+// =============================================================================
+// NOSPACE remediation
+// =============================================================================
+
+/// A member whose NOSPACE alarm was remediated.
+#[derive(Debug, Clone)]
+pub struct NospaceRemediation {
+    /// Member that raised the alarm.
+    pub member_id: u64,
+    /// Node the defragment and disarm were sent to.
+    pub node: String,
+    /// Response of the defragmentation.
+    pub defragment: EtcdDefragmentResponse,
+    /// Response of the alarm disarm.
+    pub disarm: EtcdAlarmDisarmResponse,
+}
+
+/// Report of a NOSPACE remediation run.
+#[derive(Debug, Clone)]
+pub struct NospaceRemediationReport {
+    /// Members that were defragmented and disarmed, in order.
+    pub remediated: Vec<NospaceRemediation>,
+    /// Alarms still active when re-checked afterwards.
+    pub remaining_alarms: Vec<EtcdMemberAlarm>,
+}
+
+impl NospaceRemediationReport {
+    /// Check if no alarms remain after remediation.
+    #[must_use]
+    pub fn is_clear(&self) -> bool {
+        self.remaining_alarms.is_empty()
+    }
+}
+
 // =============================================================================
 // Helpers
 // =============================================================================
@@ -722,13 +895,15 @@ impl From<EtcdSnapshotRequest> for ProtoEtcdSnapshotRequest {
 pub struct EtcdSnapshotResponse {
     /// The snapshot data.
     pub data: Vec<u8>,
+    /// Node that created the snapshot.
+    pub node: Option<String>,
 }
 
 impl EtcdSnapshotResponse {
     /// Create a new snapshot response from raw data.
     #[must_use]
     pub fn new(data: Vec<u8>) -> Self {
-        Self { data }
+        Self { data, node: None }
     }
 
     /// Get the snapshot data.
@@ -765,6 +940,56 @@ impl EtcdSnapshotResponse {
     }
 }
 
+/// Chunk of an etcd snapshot, as yielded by
+/// [`TalosClient::etcd_snapshot_stream`](crate::TalosClient::etcd_snapshot_stream).
+pub type EtcdSnapshotChunk = DataChunk;
+
+// =============================================================================
+// EtcdRecover
+// =============================================================================
+
+/// Result from uploading an etcd snapshot.
+#[derive(Debug, Clone)]
+pub struct EtcdRecoverResult {
+    /// Node that stored the snapshot.
+    pub node: Option<String>,
+}
+
+impl From<ProtoEtcdRecover> for EtcdRecoverResult {
+    fn from(proto: ProtoEtcdRecover) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+        }
+    }
+}
+
+/// Response from uploading an etcd snapshot.
+#[derive(Debug, Clone)]
+pub struct EtcdRecoverResponse {
+    /// Results from each node.
+    pub results: Vec<EtcdRecoverResult>,
+}
+
+impl From<ProtoEtcdRecoverResponse> for EtcdRecoverResponse {
+    fn from(proto: ProtoEtcdRecoverResponse) -> Self {
+        Self {
+            results: proto
+                .messages
+                .into_iter()
+                .map(EtcdRecoverResult::from)
+                .collect(),
+        }
+    }
+}
+
+impl EtcdRecoverResponse {
+    /// Check if the operation was successful.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        !self.results.is_empty()
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -782,6 +1007,41 @@ mod tests {
         assert!(req.query_local);
     }
 
+    #[test]
+    fn test_etcd_member_list_conflicting_hostnames() {
+        let member = |id: u64, hostname: &str| ProtoEtcdMember {
+            id,
+            hostname: hostname.to_string(),
+            ..Default::default()
+        };
+        let proto = ProtoEtcdMemberListResponse {
+            messages: vec![
+                ProtoEtcdMembers {
+                    members: vec![member(1, "cp-1"), member(2, "cp-2"), member(3, "")],
+                    ..Default::default()
+                },
+                ProtoEtcdMembers {
+                    members: vec![
+                        member(1, "cp-1.local"),
+                        member(2, "cp-2"),
+                        member(3, "cp-3"),
+                    ],
+                    ..Default::default()
+                },
+            ],
+        };
+        let response = EtcdMemberListResponse::from(proto);
+
+        let members = response.all_members();
+        assert_eq!(members.len(), 3);
+        assert_eq!(response.find_by_id(1).unwrap().hostname, "cp-1");
+        assert!(response.find_by_hostname("cp-1.local").is_none());
+
+        let conflicts = response.conflicting_hostnames();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[&1], vec!["cp-1", "cp-1.local"]);
+    }
+
     #[test]
     fn test_etcd_remove_member_by_id_request() {
         let req = EtcdRemoveMemberByIdRequest::new(12345);
@@ -791,6 +1051,69 @@ mod tests {
         assert_eq!(proto.member_id, 12345);
     }
 
+    fn sample_member_status() -> EtcdMemberStatus {
+        EtcdMemberStatus {
+            member_id: 0x3e8a,
+            protocol_version: "3.5.0".to_string(),
+            storage_version: "3.5.0".to_string(),
+            db_size: 20 * 1024 * 1024,
+            db_size_in_use: 15 * 1024 * 1024,
+            leader: 0x3e8a,
+            raft_index: 1234,
+            raft_term: 5,
+            raft_applied_index: 1230,
+            errors: Vec::new(),
+            is_learner: false,
+        }
+    }
+
+    #[test]
+    fn test_etcd_member_status_display() {
+        let status = sample_member_status();
+        assert_eq!(status.fragmentation_percent(), 25.0);
+        assert_eq!(
+            status.to_string(),
+            "member 3e8a (leader)\n\
+             \x20 leader: 3e8a\n\
+             \x20 db size: 20.00 MB (15.00 MB in use, 25.0% fragmented)\n\
+             \x20 raft: term 5, index 1234, applied 1230\n\
+             \x20 version: protocol 3.5.0, storage 3.5.0\n\
+             \x20 errors: none"
+        );
+
+        let follower = EtcdMemberStatus {
+            member_id: 0x1,
+            leader: 0,
+            errors: vec!["NOSPACE".to_string(), "slow disk".to_string()],
+            ..sample_member_status()
+        };
+        let rendered = follower.to_string();
+        assert!(rendered.starts_with("member 1 (follower)"));
+        assert!(rendered.contains("leader: none"));
+        assert!(rendered.contains("errors: NOSPACE; slow disk"));
+    }
+
+    #[test]
+    fn test_etcd_status_response_display() {
+        let response = EtcdStatusResponse {
+            results: vec![
+                EtcdStatusResult {
+                    node: Some("cp-1".to_string()),
+                    member_status: Some(sample_member_status()),
+                },
+                EtcdStatusResult {
+                    node: Some("cp-2".to_string()),
+                    member_status: None,
+                },
+            ],
+        };
+
+        let rendered = response.to_string();
+        assert!(rendered.starts_with("node cp-1: member 3e8a (leader)\n"));
+        assert!(rendered.contains("25.0% fragmented"));
+        assert!(rendered.ends_with("errors: none\nnode cp-2: no member status"));
+    }
+
     #[test]
     fn test_etcd_alarm_type() {
         assert_eq!(EtcdAlarmType::from(0), EtcdAlarmType::None);
@@ -819,10 +1142,78 @@ mod tests {
         };
 
         assert!(status.is_leader());
+        assert!(status.has_leader());
         assert!(!status.has_errors());
         assert_eq!(status.db_size_human(), "10.00 MB");
     }
 
+    #[test]
+    fn test_etcd_member_status_raft_progress() {
+        let previous = EtcdMemberStatus {
+            member_id: 100,
+            protocol_version: "3.5.0".to_string(),
+            storage_version: "3.5".to_string(),
+            db_size: 0,
+            db_size_in_use: 0,
+            leader: 100,
+            raft_index: 1000,
+            raft_term: 5,
+            raft_applied_index: 1000,
+            errors: vec![],
+            is_learner: false,
+        };
+
+        // Re-election: term bumped, log kept moving.
+        let reelected = EtcdMemberStatus {
+            leader: 200,
+            raft_term: 6,
+            raft_index: 1010,
+            ..previous.clone()
+        };
+        assert!(reelected.election_occurred_since(&previous));
+        assert!(reelected.index_advanced(&previous));
+
+        // Stalled: same term, same index.
+        let stalled = previous.clone();
+        assert!(!stalled.election_occurred_since(&previous));
+        assert!(!stalled.index_advanced(&previous));
+    }
+
+    #[test]
+    fn test_etcd_status_response_is_healthy() {
+        let status = |leader: u64, errors: Vec<String>| EtcdStatusResult {
+            node: Some("cp-1".to_string()),
+            member_status: Some(EtcdMemberStatus {
+                member_id: 100,
+                protocol_version: "3.5.0".to_string(),
+                storage_version: "3.5".to_string(),
+                db_size: 0,
+                db_size_in_use: 0,
+                leader,
+                raft_index: 1,
+                raft_term: 1,
+                raft_applied_index: 1,
+                errors,
+                is_learner: false,
+            }),
+        };
+
+        let healthy = EtcdStatusResponse {
+            results: vec![status(100, vec![])],
+        };
+        let no_leader = EtcdStatusResponse {
+            results: vec![status(0, vec![])],
+        };
+        let errored = EtcdStatusResponse {
+            results: vec![status(100, vec!["NOSPACE".to_string()])],
+        };
+
+        assert!(healthy.is_healthy());
+        assert!(!no_leader.is_healthy());
+        assert!(!errored.is_healthy());
+        assert!(!EtcdStatusResponse { results: vec![] }.is_healthy());
+    }
+
     #[test]
     fn test_humanize_bytes() {
         assert_eq!(humanize_bytes(500), "500 B");
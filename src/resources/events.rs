@@ -26,8 +26,25 @@
 //! # Ok(())
 //! # }
 //! ```
-
-use crate::api::generated::machine::{Event as ProtoEvent, EventsRequest as ProtoEventsRequest};
+//!
+//! Use [`TalosClient::events_stream`](crate::TalosClient::events_stream) to
+//! watch events as they happen and [`Event::kind`] to decode them.
+
+use super::cosi::CosiResource;
+use crate::api::generated::machine::machine_status_event::MachineStage as ProtoMachineStage;
+use crate::api::generated::machine::{
+    phase_event::Action as ProtoPhaseAction, sequence_event::Action as ProtoSequenceAction,
+    service_state_event::Action as ProtoServiceAction, task_event::Action as ProtoTaskAction,
+    AddressEvent as ProtoAddressEvent, ConfigLoadErrorEvent as ProtoConfigLoadErrorEvent,
+    ConfigValidationErrorEvent as ProtoConfigValidationErrorEvent, Event as ProtoEvent,
+    EventsRequest as ProtoEventsRequest, MachineStatusEvent as ProtoMachineStatusEvent,
+    PhaseEvent as ProtoPhaseEvent, RestartEvent as ProtoRestartEvent,
+    SequenceEvent as ProtoSequenceEvent, ServiceStateEvent as ProtoServiceStateEvent,
+    TaskEvent as ProtoTaskEvent,
+};
+use crate::error::TalosError;
+use prost::Message;
+use serde::Deserialize;
 
 // =============================================================================
 // EventsRequest
@@ -173,6 +190,220 @@ impl Event {
             .as_ref()
             .and_then(|d| d.type_url.rsplit('/').next())
     }
+
+    /// Get the time the node created this event.
+    ///
+    /// Talos event IDs are [xids](https://github.com/rs/xid), which start
+    /// with a creation timestamp in whole seconds. Returns `None` if the ID is
+    /// not an xid.
+    #[must_use]
+    pub fn timestamp(&self) -> Option<std::time::SystemTime> {
+        const ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuv";
+
+        if self.id.len() != 20 {
+            return None;
+        }
+        let mut bits: u128 = 0;
+        for c in self.id.bytes() {
+            let digit = ALPHABET.iter().position(|&a| a == c)?;
+            bits = (bits << 5) | digit as u128;
+        }
+        // 20 digits hold 100 bits: 96 bits of ID followed by 4 padding bits,
+        // with the timestamp in the top 32.
+        let seconds = (bits >> 68) as u64;
+        Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(seconds))
+    }
+
+    /// Decode the payload if this is a `MachineStatusEvent`.
+    ///
+    /// Returns `Ok(None)` for any other event type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Unknown` if the payload cannot be decoded.
+    #[allow(clippy::result_large_err)]
+    pub fn machine_status(&self) -> crate::error::Result<Option<MachineStatus>> {
+        let Some(data) = &self.data else {
+            return Ok(None);
+        };
+        if !data.type_url.ends_with("MachineStatusEvent") {
+            return Ok(None);
+        }
+
+        let proto = ProtoMachineStatusEvent::decode(data.value.as_slice()).map_err(|e| {
+            TalosError::Unknown(format!("Failed to decode MachineStatusEvent: {e}"))
+        })?;
+        Ok(Some(MachineStatus::from_proto(proto, self.node.clone())))
+    }
+
+    /// Decode the payload into a typed event.
+    ///
+    /// Unknown event types, and payloads that fail to decode, are returned
+    /// as [`MachineEventKind::Raw`]; `None` means the event has no payload.
+    #[must_use]
+    pub fn kind(&self) -> Option<MachineEventKind> {
+        let data = self.data.as_ref()?;
+        let name = data.type_url.rsplit(['/', '.']).next().unwrap_or_default();
+        let value = data.value.as_slice();
+
+        let kind = match name {
+            "SequenceEvent" => {
+                ProtoSequenceEvent::decode(value)
+                    .ok()
+                    .map(|e| MachineEventKind::Sequence {
+                        sequence: e.sequence,
+                        action: match ProtoSequenceAction::try_from(e.action) {
+                            Ok(ProtoSequenceAction::Start) => EventAction::Start,
+                            Ok(ProtoSequenceAction::Stop) => EventAction::Stop,
+                            Ok(ProtoSequenceAction::Noop) => EventAction::Noop,
+                            Err(_) => EventAction::Unknown(e.action),
+                        },
+                        error: e.error.map(|error| error.message),
+                    })
+            }
+            "PhaseEvent" => ProtoPhaseEvent::decode(value)
+                .ok()
+                .map(|e| MachineEventKind::Phase {
+                    phase: e.phase,
+                    action: match ProtoPhaseAction::try_from(e.action) {
+                        Ok(ProtoPhaseAction::Start) => EventAction::Start,
+                        Ok(ProtoPhaseAction::Stop) => EventAction::Stop,
+                        Err(_) => EventAction::Unknown(e.action),
+                    },
+                }),
+            "TaskEvent" => ProtoTaskEvent::decode(value)
+                .ok()
+                .map(|e| MachineEventKind::Task {
+                    task: e.task,
+                    action: match ProtoTaskAction::try_from(e.action) {
+                        Ok(ProtoTaskAction::Start) => EventAction::Start,
+                        Ok(ProtoTaskAction::Stop) => EventAction::Stop,
+                        Err(_) => EventAction::Unknown(e.action),
+                    },
+                }),
+            "ServiceStateEvent" => {
+                ProtoServiceStateEvent::decode(value)
+                    .ok()
+                    .map(|e| MachineEventKind::ServiceState {
+                        service: e.service,
+                        state: ProtoServiceAction::try_from(e.action)
+                            .map(|action| action.as_str_name().to_string())
+                            .unwrap_or_default(),
+                        message: e.message,
+                        healthy: e.health.filter(|h| !h.unknown).map(|h| h.healthy),
+                    })
+            }
+            "RestartEvent" => ProtoRestartEvent::decode(value)
+                .ok()
+                .map(|e| MachineEventKind::Restart { cmd: e.cmd }),
+            "ConfigLoadErrorEvent" => ProtoConfigLoadErrorEvent::decode(value)
+                .ok()
+                .map(|e| MachineEventKind::ConfigLoadError { error: e.error }),
+            "ConfigValidationErrorEvent" => ProtoConfigValidationErrorEvent::decode(value)
+                .ok()
+                .map(|e| MachineEventKind::ConfigValidationError { error: e.error }),
+            "AddressEvent" => {
+                ProtoAddressEvent::decode(value)
+                    .ok()
+                    .map(|e| MachineEventKind::Address {
+                        hostname: e.hostname,
+                        addresses: e.addresses,
+                    })
+            }
+            "MachineStatusEvent" => ProtoMachineStatusEvent::decode(value).ok().map(|e| {
+                MachineEventKind::MachineStatus(MachineStatus::from_proto(e, self.node.clone()))
+            }),
+            _ => None,
+        };
+
+        Some(kind.unwrap_or_else(|| MachineEventKind::Raw {
+            type_url: data.type_url.clone(),
+            value: data.value.clone(),
+        }))
+    }
+}
+
+/// Whether a sequence, phase, or task started or stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventAction {
+    /// Nothing happened (sequences only).
+    Noop,
+    /// Started.
+    Start,
+    /// Stopped.
+    Stop,
+    /// Action this client doesn't know, with its raw wire value.
+    Unknown(i32),
+}
+
+/// Typed payload of an [`Event`], as returned by [`Event::kind`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MachineEventKind {
+    /// A boot, upgrade, reset, or other sequence started or stopped.
+    Sequence {
+        /// Sequence name (e.g. `upgrade`).
+        sequence: String,
+        /// What happened.
+        action: EventAction,
+        /// Error message if the sequence failed.
+        error: Option<String>,
+    },
+    /// A phase of a sequence started or stopped.
+    Phase {
+        /// Phase name.
+        phase: String,
+        /// What happened.
+        action: EventAction,
+    },
+    /// A task of a phase started or stopped.
+    Task {
+        /// Task name.
+        task: String,
+        /// What happened.
+        action: EventAction,
+    },
+    /// A service changed state.
+    ServiceState {
+        /// Service ID.
+        service: String,
+        /// New state (e.g. `RUNNING`).
+        state: String,
+        /// State message.
+        message: String,
+        /// Health, if known.
+        healthy: Option<bool>,
+    },
+    /// The machine is restarting.
+    Restart {
+        /// Restart command passed to the kernel.
+        cmd: i64,
+    },
+    /// The machine configuration failed to load.
+    ConfigLoadError {
+        /// Error message.
+        error: String,
+    },
+    /// The machine configuration failed to validate.
+    ConfigValidationError {
+        /// Error message.
+        error: String,
+    },
+    /// The node's hostname or addresses changed.
+    Address {
+        /// Hostname.
+        hostname: String,
+        /// Current addresses.
+        addresses: Vec<String>,
+    },
+    /// Machine stage and readiness changed.
+    MachineStatus(MachineStatus),
+    /// An event type this crate does not decode.
+    Raw {
+        /// Type URL of the payload.
+        type_url: String,
+        /// Serialized payload.
+        value: Vec<u8>,
+    },
 }
 
 impl From<ProtoEvent> for Event {
@@ -189,6 +420,176 @@ impl From<ProtoEvent> for Event {
     }
 }
 
+// =============================================================================
+// MachineStatus
+// =============================================================================
+
+/// Lifecycle stage of a Talos machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MachineStage {
+    /// Stage not reported.
+    Unknown,
+    /// Machine is booting.
+    Booting,
+    /// Talos is being installed.
+    Installing,
+    /// Machine is in maintenance mode, waiting for a configuration.
+    Maintenance,
+    /// Machine has finished booting.
+    Running,
+    /// Machine is rebooting.
+    Rebooting,
+    /// Machine is shutting down.
+    ShuttingDown,
+    /// Machine is being reset.
+    Resetting,
+    /// Machine is being upgraded.
+    Upgrading,
+}
+
+impl From<i32> for MachineStage {
+    fn from(value: i32) -> Self {
+        match ProtoMachineStage::try_from(value) {
+            Ok(ProtoMachineStage::Booting) => Self::Booting,
+            Ok(ProtoMachineStage::Installing) => Self::Installing,
+            Ok(ProtoMachineStage::Maintenance) => Self::Maintenance,
+            Ok(ProtoMachineStage::Running) => Self::Running,
+            Ok(ProtoMachineStage::Rebooting) => Self::Rebooting,
+            Ok(ProtoMachineStage::ShuttingDown) => Self::ShuttingDown,
+            Ok(ProtoMachineStage::Resetting) => Self::Resetting,
+            Ok(ProtoMachineStage::Upgrading) => Self::Upgrading,
+            Ok(ProtoMachineStage::Unknown) | Err(_) => Self::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for MachineStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MachineStage::Unknown => write!(f, "unknown"),
+            MachineStage::Booting => write!(f, "booting"),
+            MachineStage::Installing => write!(f, "installing"),
+            MachineStage::Maintenance => write!(f, "maintenance"),
+            MachineStage::Running => write!(f, "running"),
+            MachineStage::Rebooting => write!(f, "rebooting"),
+            MachineStage::ShuttingDown => write!(f, "shutting down"),
+            MachineStage::Resetting => write!(f, "resetting"),
+            MachineStage::Upgrading => write!(f, "upgrading"),
+        }
+    }
+}
+
+/// A readiness condition the machine has not met yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmetCondition {
+    /// Condition name (e.g. "time", "network").
+    pub name: String,
+    /// Why the condition is not met.
+    pub reason: String,
+}
+
+/// Machine stage and readiness, as reported by `MachineStatusEvent`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MachineStatus {
+    /// Node that reported the status.
+    pub node: Option<String>,
+    /// Current lifecycle stage.
+    pub stage: MachineStage,
+    /// Whether the machine finished booting and all conditions are met.
+    pub ready: bool,
+    /// Conditions still blocking readiness.
+    pub unmet_conditions: Vec<UnmetCondition>,
+}
+
+/// Spec of a `MachineStatuses.runtime.talos.dev` resource, as YAML.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MachineStatusSpec {
+    #[serde(default)]
+    stage: serde_yaml::Value,
+    #[serde(default)]
+    status: MachineStatusSpecStatus,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MachineStatusSpecStatus {
+    #[serde(default)]
+    ready: bool,
+    #[serde(default)]
+    unmet_conditions: Vec<MachineStatusSpecCondition>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MachineStatusSpecCondition {
+    name: String,
+    #[serde(default)]
+    reason: String,
+}
+
+impl MachineStage {
+    /// Parse a stage as Talos writes it in resource specs: by name
+    /// (`shutting down`) or, from older nodes, by number.
+    fn from_spec(value: &serde_yaml::Value) -> Self {
+        if let Some(number) = value.as_i64() {
+            return i32::try_from(number).map_or(Self::Unknown, Self::from);
+        }
+        match value.as_str().unwrap_or_default() {
+            "booting" => Self::Booting,
+            "installing" => Self::Installing,
+            "maintenance" => Self::Maintenance,
+            "running" => Self::Running,
+            "rebooting" => Self::Rebooting,
+            "shutting down" => Self::ShuttingDown,
+            "resetting" => Self::Resetting,
+            "upgrading" => Self::Upgrading,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl MachineStatus {
+    /// Build a status from a `MachineStatuses.runtime.talos.dev` resource.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn from_resource(
+        resource: &CosiResource,
+        node: Option<String>,
+    ) -> crate::error::Result<Self> {
+        let spec: MachineStatusSpec = resource.spec_as()?;
+        Ok(Self {
+            node,
+            stage: MachineStage::from_spec(&spec.stage),
+            ready: spec.status.ready,
+            unmet_conditions: spec
+                .status
+                .unmet_conditions
+                .into_iter()
+                .map(|c| UnmetCondition {
+                    name: c.name,
+                    reason: c.reason,
+                })
+                .collect(),
+        })
+    }
+
+    fn from_proto(proto: ProtoMachineStatusEvent, node: Option<String>) -> Self {
+        let status = proto.status.unwrap_or_default();
+        Self {
+            node,
+            stage: MachineStage::from(proto.stage),
+            ready: status.ready,
+            unmet_conditions: status
+                .unmet_conditions
+                .into_iter()
+                .map(|c| UnmetCondition {
+                    name: c.name,
+                    reason: c.reason,
+                })
+                .collect(),
+        }
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -224,6 +625,22 @@ mod tests {
         assert_eq!(req.tail_id, "event-123");
     }
 
+    #[test]
+    fn test_event_timestamp_from_xid() {
+        let event = |id: &str| Event {
+            node: None,
+            id: id.to_string(),
+            actor_id: String::new(),
+            data: None,
+        };
+        assert_eq!(
+            event("9m4e2mr0ui3e8a215n4g").timestamp(),
+            Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_300_816_219))
+        );
+        assert!(event("event-123").timestamp().is_none());
+        assert!(event("9m4e2mr0ui3e8a215n4z").timestamp().is_none());
+    }
+
     #[test]
     fn test_events_request_builder() {
         let req = EventsRequest::new()
@@ -306,4 +723,215 @@ mod tests {
         // Returns the full type name after the last '/'
         assert_eq!(event.event_type(), Some("talos.runtime.MachineStatusEvent"));
     }
+
+    fn machine_status_event(event: ProtoMachineStatusEvent) -> Event {
+        use prost::Message;
+
+        Event {
+            node: Some("cp-1".to_string()),
+            id: "event-003".to_string(),
+            actor_id: String::new(),
+            data: Some(EventData {
+                type_url: "talos/runtime/machine.MachineStatusEvent".to_string(),
+                value: event.encode_to_vec(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_machine_status_decodes_ready_node() {
+        use crate::api::generated::machine::machine_status_event::MachineStatus as ProtoStatus;
+
+        let event = machine_status_event(ProtoMachineStatusEvent {
+            stage: ProtoMachineStage::Running as i32,
+            status: Some(ProtoStatus {
+                ready: true,
+                unmet_conditions: vec![],
+            }),
+        });
+
+        let status = event.machine_status().unwrap().unwrap();
+        assert_eq!(status.node, Some("cp-1".to_string()));
+        assert_eq!(status.stage, MachineStage::Running);
+        assert!(status.ready);
+        assert!(status.unmet_conditions.is_empty());
+    }
+
+    #[test]
+    fn test_machine_status_decodes_unmet_conditions() {
+        use crate::api::generated::machine::machine_status_event::machine_status::UnmetCondition as ProtoCondition;
+        use crate::api::generated::machine::machine_status_event::MachineStatus as ProtoStatus;
+
+        let event = machine_status_event(ProtoMachineStatusEvent {
+            stage: ProtoMachineStage::Booting as i32,
+            status: Some(ProtoStatus {
+                ready: false,
+                unmet_conditions: vec![ProtoCondition {
+                    name: "time".to_string(),
+                    reason: "time is not in sync yet".to_string(),
+                }],
+            }),
+        });
+
+        let status = event.machine_status().unwrap().unwrap();
+        assert_eq!(status.stage, MachineStage::Booting);
+        assert_eq!(status.stage.to_string(), "booting");
+        assert!(!status.ready);
+        assert_eq!(status.unmet_conditions[0].name, "time");
+    }
+
+    #[test]
+    fn test_machine_status_ignores_other_events() {
+        let event = Event {
+            node: None,
+            id: "event-004".to_string(),
+            actor_id: String::new(),
+            data: Some(EventData {
+                type_url: "talos/runtime/machine.ServiceStateEvent".to_string(),
+                value: vec![],
+            }),
+        };
+        assert!(event.machine_status().unwrap().is_none());
+
+        let mut garbage = machine_status_event(ProtoMachineStatusEvent::default());
+        garbage.data.as_mut().unwrap().value = vec![0xff, 0xff];
+        assert!(garbage.machine_status().is_err());
+    }
+
+    #[test]
+    fn test_machine_stage_from_unknown_value() {
+        assert_eq!(MachineStage::from(4), MachineStage::Running);
+        assert_eq!(MachineStage::from(42), MachineStage::Unknown);
+    }
+
+    fn event(type_url: &str, value: Vec<u8>) -> Event {
+        Event {
+            node: Some("node-1".to_string()),
+            id: "event-1".to_string(),
+            actor_id: String::new(),
+            data: Some(EventData {
+                type_url: type_url.to_string(),
+                value,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_event_kind_decodes_known_types() {
+        use crate::api::generated::common::Error as ProtoError;
+
+        let sequence = ProtoSequenceEvent {
+            sequence: "upgrade".to_string(),
+            action: ProtoSequenceAction::Stop as i32,
+            error: Some(ProtoError {
+                message: "install failed".to_string(),
+                ..Default::default()
+            }),
+        };
+        assert_eq!(
+            event(
+                "talos/runtime/machine.SequenceEvent",
+                sequence.encode_to_vec()
+            )
+            .kind(),
+            Some(MachineEventKind::Sequence {
+                sequence: "upgrade".to_string(),
+                action: EventAction::Stop,
+                error: Some("install failed".to_string()),
+            })
+        );
+
+        let service = ProtoServiceStateEvent {
+            service: "kubelet".to_string(),
+            action: ProtoServiceAction::Running as i32,
+            message: "Health check successful".to_string(),
+            health: None,
+        };
+        assert_eq!(
+            event(
+                "talos/runtime/machine.ServiceStateEvent",
+                service.encode_to_vec()
+            )
+            .kind(),
+            Some(MachineEventKind::ServiceState {
+                service: "kubelet".to_string(),
+                state: "RUNNING".to_string(),
+                message: "Health check successful".to_string(),
+                healthy: None,
+            })
+        );
+
+        let status = ProtoMachineStatusEvent {
+            stage: ProtoMachineStage::Upgrading as i32,
+            status: None,
+        };
+        match event("talos/runtime/MachineStatusEvent", status.encode_to_vec()).kind() {
+            Some(MachineEventKind::MachineStatus(status)) => {
+                assert_eq!(status.stage, MachineStage::Upgrading);
+                assert_eq!(status.node.as_deref(), Some("node-1"));
+            }
+            other => panic!("expected MachineStatus, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_event_kind_keeps_unknown_actions() {
+        let phase = ProtoPhaseEvent {
+            phase: "installImage".to_string(),
+            action: 7,
+        };
+        assert_eq!(
+            event("talos/runtime/machine.PhaseEvent", phase.encode_to_vec()).kind(),
+            Some(MachineEventKind::Phase {
+                phase: "installImage".to_string(),
+                action: EventAction::Unknown(7),
+            })
+        );
+
+        let task = ProtoTaskEvent {
+            task: "startEverything".to_string(),
+            action: ProtoTaskAction::Start as i32,
+        };
+        assert_eq!(
+            event("talos/runtime/machine.TaskEvent", task.encode_to_vec()).kind(),
+            Some(MachineEventKind::Task {
+                task: "startEverything".to_string(),
+                action: EventAction::Start,
+            })
+        );
+
+        let task = ProtoTaskEvent {
+            task: "startEverything".to_string(),
+            action: 9,
+        };
+        assert_eq!(
+            event("talos/runtime/machine.TaskEvent", task.encode_to_vec()).kind(),
+            Some(MachineEventKind::Task {
+                task: "startEverything".to_string(),
+                action: EventAction::Unknown(9),
+            })
+        );
+    }
+
+    #[test]
+    fn test_event_kind_falls_back_to_raw() {
+        let unknown = event("talos/runtime/machine.FutureEvent", vec![1, 2, 3]);
+        assert_eq!(
+            unknown.kind(),
+            Some(MachineEventKind::Raw {
+                type_url: "talos/runtime/machine.FutureEvent".to_string(),
+                value: vec![1, 2, 3],
+            })
+        );
+
+        // A payload that does not decode is kept raw too.
+        let garbled = event("talos/runtime/machine.TaskEvent", vec![0xff]);
+        assert!(matches!(garbled.kind(), Some(MachineEventKind::Raw { .. })));
+
+        let empty = Event {
+            data: None,
+            ..event("", vec![])
+        };
+        assert_eq!(empty.kind(), None);
+    }
 }
@@ -9,6 +9,7 @@ use crate::api::generated::machine::{
     DiskUsageRequest as ProtoDiskUsageRequest, FileInfo as ProtoFileInfo,
     ListRequest as ProtoListRequest, ReadRequest as ProtoReadRequest,
 };
+use std::collections::{BTreeMap, HashSet};
 
 // =============================================================================
 // List (Directory Listing)
@@ -222,13 +223,25 @@ impl FileInfo {
 pub struct ListResponse {
     /// File entries.
     pub entries: Vec<FileInfo>,
+    /// Root directory that was requested, for correlating batched calls.
+    pub requested_path: String,
 }
 
 impl ListResponse {
     /// Create a new response.
     #[must_use]
     pub fn new(entries: Vec<FileInfo>) -> Self {
-        Self { entries }
+        Self {
+            entries,
+            requested_path: String::new(),
+        }
+    }
+
+    /// Record the requested root directory.
+    #[must_use]
+    pub fn with_requested_path(mut self, path: impl Into<String>) -> Self {
+        self.requested_path = path.into();
+        self
     }
 
     /// Get the number of entries.
@@ -265,13 +278,28 @@ impl ListResponse {
 pub struct ReadRequest {
     /// Path to the file to read.
     pub path: String,
+    /// SHA-256 the streamed content must match, if set.
+    pub expected_sha256: Option<[u8; 32]>,
 }
 
 impl ReadRequest {
     /// Create a new read request.
     #[must_use]
     pub fn new(path: impl Into<String>) -> Self {
-        Self { path: path.into() }
+        Self {
+            path: path.into(),
+            expected_sha256: None,
+        }
+    }
+
+    /// Require the content to hash to `sha256`.
+    ///
+    /// The digest is computed while the file streams in; on mismatch the
+    /// read fails with `TalosError::Validation`.
+    #[must_use]
+    pub fn expect_sha256(mut self, sha256: [u8; 32]) -> Self {
+        self.expected_sha256 = Some(sha256);
+        self
     }
 }
 
@@ -288,13 +316,26 @@ pub struct ReadResponse {
     pub data: Vec<u8>,
     /// Node that returned this data.
     pub node: Option<String>,
+    /// Path that was requested, for correlating batched calls.
+    pub requested_path: String,
 }
 
 impl ReadResponse {
     /// Create a new response.
     #[must_use]
     pub fn new(data: Vec<u8>, node: Option<String>) -> Self {
-        Self { data, node }
+        Self {
+            data,
+            node,
+            requested_path: String::new(),
+        }
+    }
+
+    /// Record the requested path.
+    #[must_use]
+    pub fn with_requested_path(mut self, path: impl Into<String>) -> Self {
+        self.requested_path = path.into();
+        self
     }
 
     /// Get data as UTF-8 string.
@@ -305,12 +346,24 @@ impl ReadResponse {
         std::str::from_utf8(&self.data).ok()
     }
 
-    /// Get data as lossy UTF-8 string.
+    /// Get data as lossy UTF-8 string, replacing invalid UTF-8.
     #[must_use]
     pub fn as_string_lossy(&self) -> String {
         String::from_utf8_lossy(&self.data).into_owned()
     }
 
+    /// Guess whether the data is text rather than binary.
+    ///
+    /// Looks at the first 8 KiB: any NUL byte means binary, as does more
+    /// than 10% of invalid UTF-8 or control characters other than
+    /// whitespace and escapes. A lone invalid byte in a log line (e.g. a
+    /// Latin-1 character) still counts as text, so use
+    /// [`as_string_lossy`](Self::as_string_lossy) to display it.
+    #[must_use]
+    pub fn detect_text(&self) -> bool {
+        super::logs::detect_text(&self.data)
+    }
+
     /// Get data length.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -322,6 +375,26 @@ impl ReadResponse {
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
+
+    /// SHA-256 digest of the content.
+    #[must_use]
+    pub fn sha256(&self) -> [u8; 32] {
+        let digest = ring::digest::digest(&ring::digest::SHA256, &self.data);
+        let mut sha256 = [0u8; 32];
+        sha256.copy_from_slice(digest.as_ref());
+        sha256
+    }
+
+    /// MD5 digest of the content.
+    ///
+    /// Only suitable for comparing against published checksums, not for
+    /// integrity against tampering; prefer [`sha256`](Self::sha256).
+    #[must_use]
+    pub fn md5(&self) -> [u8; 16] {
+        use md5::Digest;
+
+        md5::Md5::digest(&self.data).into()
+    }
 }
 
 // =============================================================================
@@ -460,13 +533,23 @@ impl DiskUsageRequestBuilder {
         self
     }
 
-    /// Set recursion depth.
+    /// Set recursion depth (0 = unlimited).
     #[must_use]
     pub fn recursion_depth(mut self, depth: i32) -> Self {
         self.recursion_depth = depth;
         self
     }
 
+    /// Limit how many levels of subdirectories are walked, like `du -d`.
+    ///
+    /// `max_depth(1)` reports each path and its immediate children. As with
+    /// [`recursion_depth`](Self::recursion_depth), 0 means unlimited; depths
+    /// beyond `i32::MAX` are clamped.
+    #[must_use]
+    pub fn max_depth(self, depth: u32) -> Self {
+        self.recursion_depth(i32::try_from(depth).unwrap_or(i32::MAX))
+    }
+
     /// Include all files.
     #[must_use]
     pub fn all(mut self, all: bool) -> Self {
@@ -474,7 +557,10 @@ impl DiskUsageRequestBuilder {
         self
     }
 
-    /// Set size threshold.
+    /// Set the size threshold in bytes.
+    ///
+    /// A positive threshold excludes entries smaller than it, a negative one
+    /// excludes entries larger than its absolute value.
     #[must_use]
     pub fn threshold(mut self, threshold: i64) -> Self {
         self.threshold = threshold;
@@ -553,11 +639,55 @@ impl DiskUsageResponse {
     }
 
     /// Get total size across all entries.
+    ///
+    /// Directory sizes already include their children, so this counts
+    /// nested entries more than once; see [`total`](Self::total).
     #[must_use]
     pub fn total_size(&self) -> i64 {
         self.entries.iter().map(|e| e.size).sum()
     }
 
+    /// Total size of the outermost entries, per node.
+    ///
+    /// Entries below another reported entry are skipped, since their size
+    /// is already part of their ancestor's, as are entries with errors.
+    #[must_use]
+    pub fn total(&self) -> i64 {
+        let reported: HashSet<(Option<&str>, &str)> = self
+            .entries
+            .iter()
+            .filter(|e| !e.has_error())
+            .map(|e| (e.node.as_deref(), e.name.trim_end_matches('/')))
+            .collect();
+
+        self.entries
+            .iter()
+            .filter(|e| !e.has_error())
+            .filter(|e| {
+                let node = e.node.as_deref();
+                let mut parent = parent_dir(&e.name);
+                while let Some(dir) = parent {
+                    if reported.contains(&(node, dir.trim_end_matches('/'))) {
+                        return false;
+                    }
+                    parent = parent_dir(dir);
+                }
+                true
+            })
+            .map(|e| e.size)
+            .sum()
+    }
+
+    /// The `n` largest entries, biggest first; entries with errors are skipped.
+    #[must_use]
+    pub fn largest(&self, n: usize) -> Vec<&DiskUsageInfo> {
+        let mut entries: Vec<&DiskUsageInfo> =
+            self.entries.iter().filter(|e| !e.has_error()).collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.size));
+        entries.truncate(n);
+        entries
+    }
+
     /// Get the number of entries.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -569,6 +699,110 @@ impl DiskUsageResponse {
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
     }
+
+    /// Nest the entries into a `du`-style tree rooted at `/`.
+    ///
+    /// Paths are split on `/`, so `/var/log/messages` ends up under `var`
+    /// and `log`; relative names are treated as relative to the root.
+    /// Leaves keep their reported size, while every directory with children
+    /// gets the sum of its children's sizes (its own reported size is kept
+    /// in [`DuNode::reported_size`]). Entries with errors are skipped.
+    #[must_use]
+    pub fn rollup(&self) -> DuNode {
+        let mut root = DuBuilder::default();
+        for entry in self.entries.iter().filter(|e| !e.has_error()) {
+            let mut node = &mut root;
+            for component in entry.name.split('/').filter(|c| !c.is_empty()) {
+                node = node.children.entry(component.to_string()).or_default();
+            }
+            node.reported_size = Some(entry.size);
+        }
+        root.finish("/".to_string(), "/".to_string())
+    }
+}
+
+/// A directory or file in a [`DiskUsageResponse::rollup`] tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuNode {
+    /// Last path component (`/` for the root).
+    pub name: String,
+    /// Absolute path.
+    pub path: String,
+    /// Size in bytes: the reported size for leaves, the sum of the
+    /// children for directories.
+    pub size: i64,
+    /// Size the server reported for this path, if it sent an entry for it.
+    pub reported_size: Option<i64>,
+    /// Children, sorted by name.
+    pub children: Vec<DuNode>,
+}
+
+impl DuNode {
+    /// Get size in human-readable format.
+    #[must_use]
+    pub fn size_human(&self) -> String {
+        humanize_bytes(self.size.max(0) as u64)
+    }
+
+    /// Find the node for an absolute path in this subtree.
+    #[must_use]
+    pub fn find(&self, path: &str) -> Option<&DuNode> {
+        let relative = path.strip_prefix(self.path.as_str())?;
+        if self.path != "/" && !relative.is_empty() && !relative.starts_with('/') {
+            return None;
+        }
+        relative
+            .split('/')
+            .filter(|c| !c.is_empty())
+            .try_fold(self, |node, component| {
+                node.children.iter().find(|child| child.name == component)
+            })
+    }
+}
+
+/// Mutable tree used while building a [`DuNode`].
+#[derive(Default)]
+struct DuBuilder {
+    reported_size: Option<i64>,
+    children: BTreeMap<String, DuBuilder>,
+}
+
+impl DuBuilder {
+    fn finish(self, name: String, path: String) -> DuNode {
+        let children: Vec<DuNode> = self
+            .children
+            .into_iter()
+            .map(|(child, builder)| {
+                let child_path = if path == "/" {
+                    format!("/{}", child)
+                } else {
+                    format!("{}/{}", path, child)
+                };
+                builder.finish(child, child_path)
+            })
+            .collect();
+        let size = if children.is_empty() {
+            self.reported_size.unwrap_or(0)
+        } else {
+            children.iter().map(|c| c.size).sum()
+        };
+        DuNode {
+            name,
+            path,
+            size,
+            reported_size: self.reported_size,
+            children,
+        }
+    }
+}
+
+/// Parent directory of a `/`-separated path, if it has one.
+fn parent_dir(path: &str) -> Option<&str> {
+    let trimmed = path.trim_end_matches('/');
+    match trimmed.rfind('/')? {
+        0 => Some("/"),
+        idx => Some(&trimmed[..idx]),
+    }
 }
 
 /// Convert bytes to human-readable format.
@@ -595,6 +829,70 @@ fn humanize_bytes(bytes: u64) -> String {
 mod tests {
     use super::*;
 
+    fn du(name: &str, size: i64) -> DiskUsageInfo {
+        DiskUsageInfo {
+            node: None,
+            name: name.to_string(),
+            size,
+            error: None,
+            relative_name: String::new(),
+        }
+    }
+
+    fn assert_rolled_up(node: &DuNode) {
+        if !node.children.is_empty() {
+            assert_eq!(
+                node.size,
+                node.children.iter().map(|c| c.size).sum::<i64>(),
+                "{}",
+                node.path
+            );
+        }
+        node.children.iter().for_each(assert_rolled_up);
+    }
+
+    #[test]
+    fn test_disk_usage_rollup() {
+        let response = DiskUsageResponse::new(vec![
+            du("/", 9999),
+            du("/var", 4096),
+            du("/var/log", 4096),
+            du("/var/log/messages", 1000),
+            du("/var/log/pods/etcd.log", 500),
+            du("/var/lib/etcd/member/snap/db", 3000),
+            du("/etc/hosts", 200),
+            du("var/relative.txt", 7),
+            DiskUsageInfo {
+                error: Some("permission denied".to_string()),
+                ..du("/var/secret", 123)
+            },
+        ]);
+
+        let root = response.rollup();
+        assert_rolled_up(&root);
+
+        assert_eq!(root.path, "/");
+        assert_eq!(root.size, 1000 + 500 + 3000 + 200 + 7);
+        assert_eq!(root.reported_size, Some(9999));
+        let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["etc", "var"]);
+
+        let log = root.find("/var/log").unwrap();
+        assert_eq!(log.size, 1500);
+        assert_eq!(log.reported_size, Some(4096));
+        assert_eq!(root.find("/var/log/pods/etcd.log").unwrap().size, 500);
+        assert_eq!(root.find("/var/lib/etcd").unwrap().path, "/var/lib/etcd");
+        assert!(root.find("/var/secret").is_none());
+        assert!(log.find("/var/logs").is_none());
+    }
+
+    #[test]
+    fn test_disk_usage_rollup_empty() {
+        let root = DiskUsageResponse::default().rollup();
+        assert_eq!(root.size, 0);
+        assert!(root.children.is_empty());
+    }
+
     #[test]
     fn test_list_request_new() {
         let req = ListRequest::new("/var/log");
@@ -651,6 +949,40 @@ mod tests {
         let resp = ReadResponse::new(b"hello world".to_vec(), Some("node1".to_string()));
         assert_eq!(resp.as_str(), Some("hello world"));
         assert_eq!(resp.len(), 11);
+        assert!(resp.requested_path.is_empty());
+
+        let resp = resp.with_requested_path("/etc/motd");
+        assert_eq!(resp.requested_path, "/etc/motd");
+    }
+
+    #[test]
+    fn test_read_response_text_handling() {
+        let text = ReadResponse::new(b"127.0.0.1 localhost\n".to_vec(), None);
+        assert_eq!(text.as_string_lossy(), "127.0.0.1 localhost\n");
+        assert!(text.detect_text());
+
+        let invalid = ReadResponse::new(
+            b"# hosts file, edited by Jos\xe9 on the node\n".to_vec(),
+            None,
+        );
+        assert_eq!(invalid.as_str(), None);
+        assert!(invalid.as_string_lossy().contains('\u{FFFD}'));
+        assert!(invalid.detect_text());
+
+        let gzip = ReadResponse::new(vec![0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00], None);
+        assert!(!gzip.detect_text());
+    }
+
+    #[test]
+    fn test_read_response_digests() {
+        let resp = ReadResponse::new(b"hello world".to_vec(), None);
+        let sha256: String = resp.sha256().iter().map(|b| format!("{:02x}", b)).collect();
+        let md5: String = resp.md5().iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(
+            sha256,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+        assert_eq!(md5, "5eb63bbbe01eeed093cb22bb8f5acdc3");
     }
 
     #[test]
@@ -681,6 +1013,66 @@ mod tests {
         assert_eq!(req.threshold, 1024);
     }
 
+    #[test]
+    fn test_disk_usage_request_depth_to_proto() {
+        let proto: ProtoDiskUsageRequest = DiskUsageRequest::builder()
+            .path("/var")
+            .max_depth(1)
+            .threshold(-4096)
+            .build()
+            .into();
+        assert_eq!(proto.paths, vec!["/var"]);
+        assert_eq!(proto.recursion_depth, 1);
+        assert_eq!(proto.threshold, -4096);
+        assert!(!proto.all);
+
+        let proto: ProtoDiskUsageRequest = DiskUsageRequest::builder()
+            .max_depth(u32::MAX)
+            .build()
+            .into();
+        assert_eq!(proto.recursion_depth, i32::MAX);
+    }
+
+    #[test]
+    fn test_disk_usage_total_and_largest() {
+        let response = DiskUsageResponse::new(vec![
+            du("/var", 5000),
+            du("/var/log", 1500),
+            du("/var/log/pods", 1000),
+            du("/var/lib", 3000),
+            du("/etc/", 200),
+            du("/etc/hosts", 100),
+            DiskUsageInfo {
+                error: Some("permission denied".to_string()),
+                ..du("/root", 9000)
+            },
+            DiskUsageInfo {
+                node: Some("w-1".to_string()),
+                ..du("/var/log", 700)
+            },
+        ]);
+
+        assert_eq!(response.total(), 5000 + 200 + 700);
+        assert_eq!(
+            response.total_size(),
+            5000 + 1500 + 1000 + 3000 + 200 + 100 + 9000 + 700
+        );
+
+        let largest: Vec<&str> = response
+            .largest(3)
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(largest, vec!["/var", "/var/lib", "/var/log"]);
+        assert_eq!(response.largest(100).len(), 7);
+        assert!(DiskUsageResponse::default().largest(3).is_empty());
+
+        assert_eq!(parent_dir("/var/log"), Some("/var"));
+        assert_eq!(parent_dir("/var"), Some("/"));
+        assert_eq!(parent_dir("/"), None);
+        assert_eq!(parent_dir("var"), None);
+    }
+
     #[test]
     fn test_humanize_bytes() {
         assert_eq!(humanize_bytes(512), "512 B");
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for the Hostname API.
+
+use crate::api::generated::machine::{
+    Hostname as ProtoHostname, HostnameResponse as ProtoHostnameResponse,
+};
+
+/// Hostname reported by a node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostnameResult {
+    /// Node that returned this result.
+    pub node: Option<String>,
+    /// The node's hostname.
+    pub hostname: String,
+}
+
+impl From<ProtoHostname> for HostnameResult {
+    fn from(proto: ProtoHostname) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            hostname: proto.hostname,
+        }
+    }
+}
+
+/// Response from hostname request.
+#[derive(Debug, Clone)]
+pub struct HostnameResponse {
+    /// Results from each node.
+    pub results: Vec<HostnameResult>,
+}
+
+impl From<ProtoHostnameResponse> for HostnameResponse {
+    fn from(proto: ProtoHostnameResponse) -> Self {
+        Self {
+            results: proto
+                .messages
+                .into_iter()
+                .map(HostnameResult::from)
+                .collect(),
+        }
+    }
+}
+
+impl HostnameResponse {
+    /// Get the first result.
+    #[must_use]
+    pub fn first(&self) -> Option<&HostnameResult> {
+        self.results.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+
+    #[test]
+    fn test_hostname_response_from_proto() {
+        let proto = ProtoHostnameResponse {
+            messages: vec![
+                ProtoHostname {
+                    metadata: Some(Metadata {
+                        hostname: "10.0.0.1".to_string(),
+                        ..Default::default()
+                    }),
+                    hostname: "cp-1".to_string(),
+                },
+                ProtoHostname {
+                    metadata: None,
+                    hostname: "worker-1".to_string(),
+                },
+            ],
+        };
+
+        let response = HostnameResponse::from(proto);
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(
+            response.first(),
+            Some(&HostnameResult {
+                node: Some("10.0.0.1".to_string()),
+                hostname: "cp-1".to_string(),
+            })
+        );
+        assert_eq!(response.results[1].node, None);
+        assert_eq!(response.results[1].hostname, "worker-1");
+    }
+
+    #[test]
+    fn test_hostname_response_empty() {
+        let response = HostnameResponse::from(ProtoHostnameResponse { messages: vec![] });
+        assert!(response.first().is_none());
+    }
+}
@@ -233,6 +233,21 @@ impl ImagePullRequest {
         self.namespace = ContainerdNamespace::Cri;
         self
     }
+
+    /// Check the request before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the image reference is empty.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if self.reference.trim().is_empty() {
+            return Err(crate::error::TalosError::Validation(
+                "image reference must not be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl From<ImagePullRequest> for ProtoImagePullRequest {
@@ -405,6 +420,16 @@ mod tests {
         assert_eq!(req.namespace, ContainerdNamespace::Cri);
     }
 
+    #[test]
+    fn test_image_pull_request_validate() {
+        assert!(ImagePullRequest::new("nginx:latest").validate().is_ok());
+        assert!(matches!(
+            ImagePullRequest::new("").validate(),
+            Err(crate::error::TalosError::Validation(_))
+        ));
+        assert!(ImagePullRequest::new("  ").validate().is_err());
+    }
+
     #[test]
     fn test_image_pull_request_to_proto() {
         let req = ImagePullRequest::new("ghcr.io/test/image:v1").for_cri();
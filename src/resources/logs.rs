@@ -4,7 +4,9 @@
 //!
 //! Provides streaming access to service and container logs.
 
+use super::data::DataChunk;
 use crate::api::generated::machine::LogsRequest as ProtoLogsRequest;
+use crate::error::TalosError;
 
 /// Container driver type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -157,6 +159,10 @@ impl LogsRequestBuilder {
     }
 }
 
+/// Chunk of log output, as yielded by
+/// [`TalosClient::logs_stream`](crate::TalosClient::logs_stream).
+pub type LogChunk = DataChunk;
+
 /// Response containing log data.
 #[derive(Debug, Clone)]
 pub struct LogsResponse {
@@ -190,6 +196,15 @@ impl LogsResponse {
         String::from_utf8_lossy(&self.data)
     }
 
+    /// Guess whether the data is text rather than binary.
+    ///
+    /// See [`ReadResponse::detect_text`](super::ReadResponse::detect_text)
+    /// for the heuristic.
+    #[must_use]
+    pub fn detect_text(&self) -> bool {
+        detect_text(&self.data)
+    }
+
     /// Get the length in bytes.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -209,6 +224,70 @@ impl LogsResponse {
             .map(|s| s.lines().collect())
             .unwrap_or_default()
     }
+
+    /// Get the number of lines.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.as_str().map(|s| s.lines().count()).unwrap_or_default()
+    }
+
+    /// Get the lines matching a regular expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if `pattern` is not a valid regex.
+    #[allow(clippy::result_large_err)]
+    pub fn grep(&self, pattern: &str) -> crate::error::Result<Vec<&str>> {
+        grep_lines(self.lines(), pattern)
+    }
+}
+
+/// Number of leading bytes [`detect_text`] looks at.
+const TEXT_SNIFF_BYTES: usize = 8 * 1024;
+
+/// Guess whether `data` is text: no NUL byte and under 10% invalid UTF-8 or
+/// control characters (other than whitespace and escapes) in its first
+/// [`TEXT_SNIFF_BYTES`].
+pub(super) fn detect_text(data: &[u8]) -> bool {
+    let sample = &data[..data.len().min(TEXT_SNIFF_BYTES)];
+    if sample.contains(&0) {
+        return false;
+    }
+
+    let mut chars = 0usize;
+    let mut suspicious = 0usize;
+    let mut consumed = 0usize;
+    for chunk in sample.utf8_chunks() {
+        consumed += chunk.valid().len() + chunk.invalid().len();
+        for c in chunk.valid().chars() {
+            chars += 1;
+            if c.is_control() && !matches!(c, '\t' | '\n' | '\r' | '\x0c' | '\x1b') {
+                suspicious += 1;
+            }
+        }
+        // A sequence cut off by the sample boundary is not an error.
+        let cut_off = consumed == sample.len() && sample.len() < data.len();
+        if !chunk.invalid().is_empty() && !cut_off {
+            chars += 1;
+            suspicious += 1;
+        }
+    }
+
+    suspicious * 10 <= chars
+}
+
+/// Keep the lines matching `pattern`.
+#[allow(clippy::result_large_err)]
+pub(super) fn grep_lines<'a>(
+    lines: Vec<&'a str>,
+    pattern: &str,
+) -> crate::error::Result<Vec<&'a str>> {
+    let regex = regex::Regex::new(pattern)
+        .map_err(|e| TalosError::Validation(format!("Invalid grep pattern: {e}")))?;
+    Ok(lines
+        .into_iter()
+        .filter(|line| regex.is_match(line))
+        .collect())
 }
 
 #[cfg(test)]
@@ -265,4 +344,52 @@ mod tests {
         assert!(proto.follow);
         assert_eq!(proto.tail_lines, 50);
     }
+
+    #[test]
+    fn test_logs_response_grep() {
+        let response = LogsResponse::new(
+            b"kubelet starting\nerror: failed to pull image\nsynced pods\nE0101 error syncing\n"
+                .to_vec(),
+            None,
+        );
+
+        assert_eq!(response.line_count(), 4);
+        assert_eq!(
+            response.grep("(?i)^e.*error|^error").unwrap(),
+            vec!["error: failed to pull image", "E0101 error syncing"]
+        );
+        assert!(response.grep("timeout").unwrap().is_empty());
+        assert!(matches!(response.grep("("), Err(TalosError::Validation(_))));
+    }
+
+    #[test]
+    fn test_logs_response_text_handling() {
+        let valid = LogsResponse::new("etcd: leader is ✓ cp-1\n".as_bytes().to_vec(), None);
+        assert!(valid.as_str().is_ok());
+        assert_eq!(valid.as_string_lossy(), "etcd: leader is ✓ cp-1\n");
+        assert!(valid.detect_text());
+
+        // A stray Latin-1 byte in otherwise readable output.
+        let invalid = LogsResponse::new(b"kubelet: caf\xe9 unreachable, retrying\n".to_vec(), None);
+        assert!(invalid.as_str().is_err());
+        assert_eq!(
+            invalid.as_string_lossy(),
+            "kubelet: caf\u{FFFD} unreachable, retrying\n"
+        );
+        assert!(invalid.detect_text());
+
+        let binary = LogsResponse::new(vec![0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0], None);
+        assert!(!binary.detect_text());
+        assert!(!LogsResponse::new(vec![0xff; 64], None).detect_text());
+        assert!(!LogsResponse::new(vec![0x01; 64], None).detect_text());
+        assert!(LogsResponse::new(Vec::new(), None).detect_text());
+    }
+
+    #[test]
+    fn test_detect_text_ignores_cut_off_sequence() {
+        // A multi-byte character straddling the sniffed prefix is not an error.
+        let mut data = vec![b'a'; TEXT_SNIFF_BYTES - 1];
+        data.extend_from_slice("é".as_bytes());
+        assert!(detect_text(&data));
+    }
 }
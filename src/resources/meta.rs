@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for the MetaWrite and MetaDelete APIs.
+//!
+//! The META partition is a small key/value store that survives reinstalls.
+//! Talos uses it for flags such as the upgrade state and install image
+//! overrides. Keys are a single byte on disk, so only `0..=255` is accepted.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use talos_api_rs::resources::{MetaDeleteRequest, MetaWriteRequest};
+//!
+//! let request = MetaWriteRequest::new(0x0a, b"value".to_vec());
+//! assert!(request.validate().is_ok());
+//!
+//! let request = MetaDeleteRequest::new(0x0a);
+//! ```
+
+use crate::api::generated::machine::{
+    MetaDelete as ProtoMetaDelete, MetaDeleteRequest as ProtoMetaDeleteRequest,
+    MetaDeleteResponse as ProtoMetaDeleteResponse, MetaWrite as ProtoMetaWrite,
+    MetaWriteRequest as ProtoMetaWriteRequest, MetaWriteResponse as ProtoMetaWriteResponse,
+};
+use crate::error::TalosError;
+
+/// Largest key the META partition can store.
+pub const MAX_META_KEY: u32 = u8::MAX as u32;
+
+/// Reject keys that do not fit the single-byte META tag.
+#[allow(clippy::result_large_err)]
+fn validate_meta_key(key: u32) -> crate::error::Result<()> {
+    if key > MAX_META_KEY {
+        return Err(TalosError::Validation(format!(
+            "META key {key} is out of range (0..={MAX_META_KEY})"
+        )));
+    }
+    Ok(())
+}
+
+// =============================================================================
+// MetaWrite
+// =============================================================================
+
+/// Request to write a value to the META partition.
+#[derive(Debug, Clone)]
+pub struct MetaWriteRequest {
+    /// META key.
+    pub key: u32,
+    /// Raw value to store.
+    pub value: Vec<u8>,
+}
+
+impl MetaWriteRequest {
+    /// Create a request to write `value` under `key`.
+    #[must_use]
+    pub fn new(key: u32, value: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key,
+            value: value.into(),
+        }
+    }
+
+    /// Check the request before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the key is above [`MAX_META_KEY`].
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> crate::error::Result<()> {
+        validate_meta_key(self.key)
+    }
+}
+
+impl From<MetaWriteRequest> for ProtoMetaWriteRequest {
+    fn from(req: MetaWriteRequest) -> Self {
+        Self {
+            key: req.key,
+            value: req.value,
+        }
+    }
+}
+
+/// Result of a META write for a single node.
+#[derive(Debug, Clone)]
+pub struct MetaWriteResult {
+    /// Node that processed the request.
+    pub node: Option<String>,
+}
+
+impl From<ProtoMetaWrite> for MetaWriteResult {
+    fn from(proto: ProtoMetaWrite) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+        }
+    }
+}
+
+/// Response from a META write.
+#[derive(Debug, Clone)]
+pub struct MetaWriteResponse {
+    /// Results from each node.
+    pub results: Vec<MetaWriteResult>,
+}
+
+impl From<ProtoMetaWriteResponse> for MetaWriteResponse {
+    fn from(proto: ProtoMetaWriteResponse) -> Self {
+        Self {
+            results: proto.messages.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl MetaWriteResponse {
+    /// Check if any node acknowledged the write.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        !self.results.is_empty()
+    }
+
+    /// Get the first result (useful for single-node operations).
+    #[must_use]
+    pub fn first(&self) -> Option<&MetaWriteResult> {
+        self.results.first()
+    }
+}
+
+// =============================================================================
+// MetaDelete
+// =============================================================================
+
+/// Request to delete a key from the META partition.
+#[derive(Debug, Clone, Copy)]
+pub struct MetaDeleteRequest {
+    /// META key.
+    pub key: u32,
+}
+
+impl MetaDeleteRequest {
+    /// Create a request to delete `key`.
+    #[must_use]
+    pub fn new(key: u32) -> Self {
+        Self { key }
+    }
+
+    /// Check the request before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the key is above [`MAX_META_KEY`].
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> crate::error::Result<()> {
+        validate_meta_key(self.key)
+    }
+}
+
+impl From<MetaDeleteRequest> for ProtoMetaDeleteRequest {
+    fn from(req: MetaDeleteRequest) -> Self {
+        Self { key: req.key }
+    }
+}
+
+/// Result of a META delete for a single node.
+#[derive(Debug, Clone)]
+pub struct MetaDeleteResult {
+    /// Node that processed the request.
+    pub node: Option<String>,
+}
+
+impl From<ProtoMetaDelete> for MetaDeleteResult {
+    fn from(proto: ProtoMetaDelete) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+        }
+    }
+}
+
+/// Response from a META delete.
+#[derive(Debug, Clone)]
+pub struct MetaDeleteResponse {
+    /// Results from each node.
+    pub results: Vec<MetaDeleteResult>,
+}
+
+impl From<ProtoMetaDeleteResponse> for MetaDeleteResponse {
+    fn from(proto: ProtoMetaDeleteResponse) -> Self {
+        Self {
+            results: proto.messages.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl MetaDeleteResponse {
+    /// Check if any node acknowledged the delete.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        !self.results.is_empty()
+    }
+
+    /// Get the first result (useful for single-node operations).
+    #[must_use]
+    pub fn first(&self) -> Option<&MetaDeleteResult> {
+        self.results.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+
+    #[test]
+    fn test_meta_key_validation() {
+        assert!(MetaWriteRequest::new(0, vec![]).validate().is_ok());
+        assert!(MetaWriteRequest::new(MAX_META_KEY, vec![1])
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            MetaWriteRequest::new(256, vec![1]).validate(),
+            Err(TalosError::Validation(_))
+        ));
+        assert!(MetaDeleteRequest::new(0x0a).validate().is_ok());
+        assert!(matches!(
+            MetaDeleteRequest::new(u32::MAX).validate(),
+            Err(TalosError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_meta_requests_to_proto() {
+        let proto: ProtoMetaWriteRequest = MetaWriteRequest::new(0x0a, b"abc".to_vec()).into();
+        assert_eq!(proto.key, 0x0a);
+        assert_eq!(proto.value, b"abc");
+
+        let proto: ProtoMetaDeleteRequest = MetaDeleteRequest::new(0x0b).into();
+        assert_eq!(proto.key, 0x0b);
+    }
+
+    #[test]
+    fn test_meta_responses_from_proto() {
+        let metadata = Some(Metadata {
+            hostname: "10.0.0.1".to_string(),
+            ..Default::default()
+        });
+
+        let write = MetaWriteResponse::from(ProtoMetaWriteResponse {
+            messages: vec![ProtoMetaWrite {
+                metadata: metadata.clone(),
+            }],
+        });
+        assert!(write.is_success());
+        assert_eq!(write.first().unwrap().node.as_deref(), Some("10.0.0.1"));
+
+        let delete = MetaDeleteResponse::from(ProtoMetaDeleteResponse {
+            messages: vec![ProtoMetaDelete { metadata }],
+        });
+        assert!(delete.is_success());
+        assert!(
+            !MetaDeleteResponse::from(ProtoMetaDeleteResponse { messages: vec![] }).is_success()
+        );
+    }
+}
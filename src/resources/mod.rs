@@ -7,69 +7,110 @@
 
 mod advanced;
 mod bootstrap;
+mod bpf;
+mod cluster;
 mod configuration;
+mod containers;
+mod cosi;
+mod data;
 mod dmesg;
 mod etcd;
 mod events;
 mod files;
+mod hostname;
 mod images;
 mod kubeconfig;
 mod logs;
+mod meta;
+mod power;
 mod reset;
 mod services;
 mod system;
+mod system_stat;
 mod upgrade;
+mod version;
 
 pub use bootstrap::{
     BootstrapRequest, BootstrapRequestBuilder, BootstrapResponse, BootstrapResult,
 };
+pub use bpf::{BpfFilter, BpfInstruction};
+pub use cluster::{
+    ClusterHealthCheckRequest, ClusterMember, HealthCheckProgress, NodeRole,
+    DEFAULT_HEALTH_CHECK_WAIT_TIMEOUT,
+};
 pub use configuration::{
     ApplyConfigurationRequest, ApplyConfigurationRequestBuilder, ApplyConfigurationResponse,
-    ApplyConfigurationResult, ApplyMode,
+    ApplyConfigurationResult, ApplyMode, ApplyModeDetails, ApplyRollbackOutcome,
+    ApplyRollbackPolicy, ApplyWarning, NodeApplyWarnings, WarningKind, WarningSeverity,
+    DEFAULT_MAX_CONFIG_BYTES, DEFAULT_ROLLBACK_WATCH_TIMEOUT,
+};
+pub use containers::{
+    ContainerInfo, ContainerStat, ContainerStatsResponse, ContainerStatsResult, ContainersRequest,
+    ContainersResponse, ContainersResult,
 };
-pub use dmesg::{DmesgRequest, DmesgRequestBuilder, DmesgResponse};
+pub use cosi::{CosiEvent, CosiEventType, CosiMetadata, CosiResource, CosiWatchRequest};
+pub use data::DataChunk;
+pub use dmesg::{DmesgChunk, DmesgRequest, DmesgRequestBuilder, DmesgResponse};
 pub use etcd::{
     EtcdAlarmDisarmResponse, EtcdAlarmDisarmResult, EtcdAlarmListResponse, EtcdAlarmResult,
     EtcdAlarmType, EtcdDefragmentResponse, EtcdDefragmentResult, EtcdForfeitLeadershipRequest,
     EtcdForfeitLeadershipResponse, EtcdForfeitLeadershipResult, EtcdLeaveClusterRequest,
     EtcdLeaveClusterResponse, EtcdLeaveClusterResult, EtcdMember, EtcdMemberAlarm,
     EtcdMemberListRequest, EtcdMemberListResponse, EtcdMemberStatus, EtcdMembersResult,
-    EtcdRemoveMemberByIdRequest, EtcdRemoveMemberByIdResponse, EtcdRemoveMemberByIdResult,
+    EtcdRecoverResponse, EtcdRecoverResult, EtcdRemoveMemberByIdRequest,
+    EtcdRemoveMemberByIdResponse, EtcdRemoveMemberByIdResult, EtcdSnapshotChunk,
     EtcdSnapshotRequest, EtcdSnapshotResponse, EtcdStatusResponse, EtcdStatusResult,
+    NospaceRemediation, NospaceRemediationReport,
 };
+pub use hostname::{HostnameResponse, HostnameResult};
 pub use kubeconfig::KubeconfigResponse;
-pub use logs::{ContainerDriver, LogsRequest, LogsRequestBuilder, LogsResponse};
+pub use logs::{ContainerDriver, LogChunk, LogsRequest, LogsRequestBuilder, LogsResponse};
+pub use meta::{
+    MetaDeleteRequest, MetaDeleteResponse, MetaDeleteResult, MetaWriteRequest, MetaWriteResponse,
+    MetaWriteResult, MAX_META_KEY,
+};
+pub use power::{
+    RebootMode, RebootRequest, RebootResponse, RebootResult, ShutdownRequest, ShutdownResponse,
+    ShutdownResult,
+};
 pub use reset::{
-    ResetPartitionSpec, ResetRequest, ResetRequestBuilder, ResetResponse, ResetResult, WipeMode,
+    DrainReport, DrainStep, ResetPartitionSpec, ResetRequest, ResetRequestBuilder, ResetResponse,
+    ResetResult, WipeMode,
 };
 pub use services::{
-    ServiceRestartRequest, ServiceRestartResponse, ServiceRestartResult, ServiceStartRequest,
-    ServiceStartResponse, ServiceStartResult, ServiceStopRequest, ServiceStopResponse,
-    ServiceStopResult,
+    ServiceEvent, ServiceInfo, ServiceListResponse, ServiceListResult, ServiceRestartRequest,
+    ServiceRestartResponse, ServiceRestartResult, ServiceStartRequest, ServiceStartResponse,
+    ServiceStartResult, ServiceStopRequest, ServiceStopResponse, ServiceStopResult,
 };
+pub use system_stat::{CpuStat, SystemStatResponse, SystemStatResult};
 pub use upgrade::{
-    UpgradeRebootMode, UpgradeRequest, UpgradeRequestBuilder, UpgradeResponse, UpgradeResult,
+    NodeUpgradeOutcome, NodeUpgradeStatus, RollingUpgradeOptions, RollingUpgradeOrder,
+    RollingUpgradeReport, UpgradeRebootMode, UpgradeRequest, UpgradeRequestBuilder,
+    UpgradeResponse, UpgradeResult,
 };
+pub use version::{VersionResponse, VersionResult};
 
 pub use system::{
     CpuInfo, CpuInfoResponse, CpuInfoResult, DiskStat, DiskStatsResponse, DiskStatsResult,
-    LoadAvgResponse, LoadAvgResult, MemoryResponse, MemoryResult, MountStat, MountsResponse,
-    MountsResult, NetDevStat, NetworkDeviceStatsResponse, NetworkDeviceStatsResult, ProcessInfo,
-    ProcessesResponse, ProcessesResult,
+    LoadAvgResponse, LoadAvgResult, MemoryResponse, MemoryResult, MountDevice, MountStat,
+    MountsResponse, MountsResult, NetDevStat, NetworkDeviceStatsResponse, NetworkDeviceStatsResult,
+    ProcessInfo, ProcessNode, ProcessesResponse, ProcessesResult,
 };
 
 pub use files::{
     CopyRequest, CopyResponse, DiskUsageInfo, DiskUsageRequest, DiskUsageRequestBuilder,
-    DiskUsageResponse, FileInfo, FileType, ListRequest, ListRequestBuilder, ListResponse,
+    DiskUsageResponse, DuNode, FileInfo, FileType, ListRequest, ListRequestBuilder, ListResponse,
     ReadRequest, ReadResponse,
 };
 
+pub(crate) use advanced::PcapFramer;
 pub use advanced::{
     ConnectionRecord, ConnectionState, GenerateClientConfigurationRequest,
     GenerateClientConfigurationRequestBuilder, GenerateClientConfigurationResponse,
     GenerateClientConfigurationResult, L4ProtoFilter, NetstatFilter, NetstatRequest,
     NetstatRequestBuilder, NetstatResponse, NetstatResult, PacketCaptureRequest,
-    PacketCaptureRequestBuilder, PacketCaptureResponse, RollbackResponse, RollbackResult,
+    PacketCaptureRequestBuilder, PacketCaptureResponse, ProcessConnection, RollbackResponse,
+    RollbackResult, DEFAULT_CRT_TTL_SECONDS, KNOWN_ROLES,
 };
 
 pub use images::{
@@ -77,4 +118,7 @@ pub use images::{
     ImagePullResult,
 };
 
-pub use events::{Event, EventData, EventsRequest};
+pub use events::{
+    Event, EventAction, EventData, EventsRequest, MachineEventKind, MachineStage, MachineStatus,
+    UnmetCondition,
+};
@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for reboot and shutdown operations.
+
+use crate::api::machine::{
+    reboot_request::Mode as ProtoRebootMode, Reboot as ProtoReboot,
+    RebootRequest as ProtoRebootRequest, RebootResponse as ProtoRebootResponse,
+    Shutdown as ProtoShutdown, ShutdownRequest as ProtoShutdownRequest,
+    ShutdownResponse as ProtoShutdownResponse,
+};
+
+// =============================================================================
+// Reboot
+// =============================================================================
+
+/// How a node reboots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RebootMode {
+    /// Cordon and drain, then reboot (kexec if available), matching talosctl.
+    #[default]
+    Default,
+    /// Power cycle instead of reboot (bypasses kexec).
+    PowerCycle,
+    /// Reboot without cordoning and draining the node first.
+    Force,
+}
+
+impl From<RebootMode> for i32 {
+    fn from(mode: RebootMode) -> Self {
+        match mode {
+            RebootMode::Default => ProtoRebootMode::Default as i32,
+            RebootMode::PowerCycle => ProtoRebootMode::Powercycle as i32,
+            RebootMode::Force => ProtoRebootMode::Force as i32,
+        }
+    }
+}
+
+impl std::fmt::Display for RebootMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RebootMode::Default => write!(f, "default"),
+            RebootMode::PowerCycle => write!(f, "powercycle"),
+            RebootMode::Force => write!(f, "force"),
+        }
+    }
+}
+
+/// Request to reboot a Talos node.
+///
+/// # Example
+///
+/// ```no_run
+/// use talos_api_rs::resources::{RebootMode, RebootRequest};
+///
+/// let request = RebootRequest::new();
+/// let request = RebootRequest::with_mode(RebootMode::PowerCycle);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RebootRequest {
+    /// Reboot mode.
+    pub mode: RebootMode,
+}
+
+impl RebootRequest {
+    /// Create a reboot request with the default mode.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a reboot request with the given mode.
+    #[must_use]
+    pub fn with_mode(mode: RebootMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl From<RebootRequest> for ProtoRebootRequest {
+    fn from(req: RebootRequest) -> Self {
+        Self {
+            mode: req.mode.into(),
+        }
+    }
+}
+
+/// Result of a reboot operation for a single node.
+#[derive(Debug, Clone)]
+pub struct RebootResult {
+    /// Node hostname (if available from metadata).
+    pub node: Option<String>,
+    /// Actor ID that initiated the reboot.
+    pub actor_id: String,
+}
+
+impl From<ProtoReboot> for RebootResult {
+    fn from(proto: ProtoReboot) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            actor_id: proto.actor_id,
+        }
+    }
+}
+
+/// Response from a reboot operation.
+#[derive(Debug, Clone)]
+pub struct RebootResponse {
+    /// Results from each node.
+    pub results: Vec<RebootResult>,
+}
+
+impl From<ProtoRebootResponse> for RebootResponse {
+    fn from(proto: ProtoRebootResponse) -> Self {
+        Self {
+            results: proto.messages.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl RebootResponse {
+    /// Check if the reboot was initiated successfully.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        !self.results.is_empty()
+    }
+
+    /// Get the first result (useful for single-node operations).
+    #[must_use]
+    pub fn first(&self) -> Option<&RebootResult> {
+        self.results.first()
+    }
+}
+
+// =============================================================================
+// Shutdown
+// =============================================================================
+
+/// Request to shut down a Talos node.
+///
+/// # Example
+///
+/// ```no_run
+/// use talos_api_rs::resources::ShutdownRequest;
+///
+/// // Cordon and drain before powering off
+/// let request = ShutdownRequest::new();
+///
+/// // Power off immediately
+/// let request = ShutdownRequest::force();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShutdownRequest {
+    /// Shut down without cordoning and draining the node first.
+    pub force: bool,
+}
+
+impl ShutdownRequest {
+    /// Create a graceful shutdown request.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a shutdown request that skips cordoning and draining.
+    #[must_use]
+    pub fn force() -> Self {
+        Self { force: true }
+    }
+}
+
+impl From<ShutdownRequest> for ProtoShutdownRequest {
+    fn from(req: ShutdownRequest) -> Self {
+        Self { force: req.force }
+    }
+}
+
+/// Result of a shutdown operation for a single node.
+#[derive(Debug, Clone)]
+pub struct ShutdownResult {
+    /// Node hostname (if available from metadata).
+    pub node: Option<String>,
+    /// Actor ID that initiated the shutdown.
+    pub actor_id: String,
+}
+
+impl From<ProtoShutdown> for ShutdownResult {
+    fn from(proto: ProtoShutdown) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            actor_id: proto.actor_id,
+        }
+    }
+}
+
+/// Response from a shutdown operation.
+#[derive(Debug, Clone)]
+pub struct ShutdownResponse {
+    /// Results from each node.
+    pub results: Vec<ShutdownResult>,
+}
+
+impl From<ProtoShutdownResponse> for ShutdownResponse {
+    fn from(proto: ProtoShutdownResponse) -> Self {
+        Self {
+            results: proto.messages.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl ShutdownResponse {
+    /// Check if the shutdown was initiated successfully.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        !self.results.is_empty()
+    }
+
+    /// Get the first result (useful for single-node operations).
+    #[must_use]
+    pub fn first(&self) -> Option<&ShutdownResult> {
+        self.results.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+
+    #[test]
+    fn test_reboot_mode_conversion() {
+        assert_eq!(i32::from(RebootMode::Default), 0);
+        assert_eq!(i32::from(RebootMode::PowerCycle), 1);
+        assert_eq!(i32::from(RebootMode::Force), 2);
+        assert_eq!(RebootMode::PowerCycle.to_string(), "powercycle");
+    }
+
+    #[test]
+    fn test_reboot_request_to_proto() {
+        let proto: ProtoRebootRequest = RebootRequest::new().into();
+        assert_eq!(proto.mode, ProtoRebootMode::Default as i32);
+
+        let proto: ProtoRebootRequest = RebootRequest::with_mode(RebootMode::Force).into();
+        assert_eq!(proto.mode, ProtoRebootMode::Force as i32);
+    }
+
+    #[test]
+    fn test_shutdown_request_to_proto() {
+        let proto: ProtoShutdownRequest = ShutdownRequest::new().into();
+        assert!(!proto.force);
+
+        let proto: ProtoShutdownRequest = ShutdownRequest::force().into();
+        assert!(proto.force);
+    }
+
+    #[test]
+    fn test_power_responses_from_proto() {
+        let metadata = Some(Metadata {
+            hostname: "10.0.0.1".to_string(),
+            ..Default::default()
+        });
+
+        let reboot = RebootResponse::from(ProtoRebootResponse {
+            messages: vec![ProtoReboot {
+                metadata: metadata.clone(),
+                actor_id: "actor-1".to_string(),
+            }],
+        });
+        assert!(reboot.is_success());
+        assert_eq!(reboot.first().unwrap().node.as_deref(), Some("10.0.0.1"));
+        assert_eq!(reboot.first().unwrap().actor_id, "actor-1");
+
+        let shutdown = ShutdownResponse::from(ProtoShutdownResponse {
+            messages: vec![ProtoShutdown {
+                metadata,
+                actor_id: "actor-2".to_string(),
+            }],
+        });
+        assert!(shutdown.is_success());
+        assert_eq!(shutdown.first().unwrap().actor_id, "actor-2");
+
+        assert!(!ShutdownResponse::from(ProtoShutdownResponse { messages: vec![] }).is_success());
+    }
+}
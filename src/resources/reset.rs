@@ -293,6 +293,58 @@ impl ResetResponse {
     }
 }
 
+// =============================================================================
+// Drain
+// =============================================================================
+
+/// A step completed by [`TalosClient::drain_node`](crate::TalosClient::drain_node).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrainStep {
+    /// The node was the etcd leader and handed leadership over.
+    ForfeitedLeadership,
+    /// The node was a follower, so forfeiting leadership was skipped.
+    SkippedForfeit,
+    /// The node left the etcd cluster.
+    LeftEtcd,
+    /// The member list no longer contains the node.
+    ConfirmedRemoval,
+    /// The node was reset.
+    Reset,
+}
+
+impl std::fmt::Display for DrainStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ForfeitedLeadership => write!(f, "forfeited etcd leadership"),
+            Self::SkippedForfeit => write!(f, "skipped forfeit (not leader)"),
+            Self::LeftEtcd => write!(f, "left etcd cluster"),
+            Self::ConfirmedRemoval => write!(f, "confirmed etcd member removal"),
+            Self::Reset => write!(f, "reset node"),
+        }
+    }
+}
+
+/// Report of a completed node drain.
+#[derive(Debug, Clone)]
+pub struct DrainReport {
+    /// Node that was drained.
+    pub node: String,
+    /// etcd member ID the node had before leaving.
+    pub member_id: u64,
+    /// Steps in the order they completed.
+    pub steps: Vec<DrainStep>,
+    /// Response of the final reset.
+    pub reset: ResetResponse,
+}
+
+impl DrainReport {
+    /// Check if the node was the leader and forfeited leadership.
+    #[must_use]
+    pub fn was_leader(&self) -> bool {
+        self.steps.contains(&DrainStep::ForfeitedLeadership)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,12 +5,26 @@
 //! Provides functionality to start, stop, restart, and monitor Talos services.
 
 use crate::api::generated::machine::{
+    ServiceEvent as ProtoServiceEvent, ServiceInfo as ProtoServiceInfo,
+    ServiceList as ProtoServiceList, ServiceListResponse as ProtoServiceListResponse,
     ServiceRestart as ProtoServiceRestart, ServiceRestartRequest as ProtoServiceRestartRequest,
     ServiceRestartResponse as ProtoServiceRestartResponse, ServiceStart as ProtoServiceStart,
     ServiceStartRequest as ProtoServiceStartRequest,
     ServiceStartResponse as ProtoServiceStartResponse, ServiceStop as ProtoServiceStop,
     ServiceStopRequest as ProtoServiceStopRequest, ServiceStopResponse as ProtoServiceStopResponse,
 };
+use crate::error::TalosError;
+
+/// Reject service IDs that can never match a service.
+#[allow(clippy::result_large_err)]
+fn validate_service_id(id: &str) -> crate::error::Result<()> {
+    if id.trim().is_empty() {
+        return Err(TalosError::Validation(
+            "service id must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
 
 // =============================================================================
 // ServiceStart
@@ -29,6 +43,16 @@ impl ServiceStartRequest {
     pub fn new(id: impl Into<String>) -> Self {
         Self { id: id.into() }
     }
+
+    /// Check the request before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the service id is empty.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> crate::error::Result<()> {
+        validate_service_id(&self.id)
+    }
 }
 
 impl From<ServiceStartRequest> for ProtoServiceStartRequest {
@@ -99,6 +123,16 @@ impl ServiceStopRequest {
     pub fn new(id: impl Into<String>) -> Self {
         Self { id: id.into() }
     }
+
+    /// Check the request before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the service id is empty.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> crate::error::Result<()> {
+        validate_service_id(&self.id)
+    }
 }
 
 impl From<ServiceStopRequest> for ProtoServiceStopRequest {
@@ -169,6 +203,16 @@ impl ServiceRestartRequest {
     pub fn new(id: impl Into<String>) -> Self {
         Self { id: id.into() }
     }
+
+    /// Check the request before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalosError::Validation` if the service id is empty.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> crate::error::Result<()> {
+        validate_service_id(&self.id)
+    }
 }
 
 impl From<ServiceRestartRequest> for ProtoServiceRestartRequest {
@@ -222,6 +266,145 @@ impl ServiceRestartResponse {
     }
 }
 
+// =============================================================================
+// ServiceList
+// =============================================================================
+
+/// A state transition in a service's history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceEvent {
+    /// Event message.
+    pub msg: String,
+    /// State the service entered.
+    pub state: String,
+    /// When the event happened.
+    pub timestamp: Option<prost_types::Timestamp>,
+}
+
+impl From<ProtoServiceEvent> for ServiceEvent {
+    fn from(proto: ProtoServiceEvent) -> Self {
+        Self {
+            msg: proto.msg,
+            state: proto.state,
+            timestamp: proto.ts,
+        }
+    }
+}
+
+/// A service as reported by a node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceInfo {
+    /// Service ID (e.g. `kubelet`).
+    pub id: String,
+    /// Current state (e.g. `Running`).
+    pub state: String,
+    /// Health check result; `None` if the service has no health check or
+    /// its health is not known yet.
+    pub healthy: Option<bool>,
+    /// Last message from the health check.
+    pub health_last_message: String,
+    /// Recent state transitions, oldest first.
+    pub events: Vec<ServiceEvent>,
+}
+
+impl From<ProtoServiceInfo> for ServiceInfo {
+    fn from(proto: ProtoServiceInfo) -> Self {
+        let (healthy, health_last_message) = match proto.health {
+            Some(health) => (
+                (!health.unknown).then_some(health.healthy),
+                health.last_message,
+            ),
+            None => (None, String::new()),
+        };
+        Self {
+            id: proto.id,
+            state: proto.state,
+            healthy,
+            health_last_message,
+            events: proto
+                .events
+                .map(|e| e.events.into_iter().map(ServiceEvent::from).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Services reported by a single node.
+#[derive(Debug, Clone)]
+pub struct ServiceListResult {
+    /// Node that returned this result.
+    pub node: Option<String>,
+    /// Services on the node.
+    pub services: Vec<ServiceInfo>,
+}
+
+impl From<ProtoServiceList> for ServiceListResult {
+    fn from(proto: ProtoServiceList) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            services: proto.services.into_iter().map(ServiceInfo::from).collect(),
+        }
+    }
+}
+
+impl ServiceListResult {
+    /// Find a service by ID.
+    #[must_use]
+    pub fn find(&self, id: &str) -> Option<&ServiceInfo> {
+        self.services.iter().find(|service| service.id == id)
+    }
+
+    /// Services whose health check is failing.
+    #[must_use]
+    pub fn unhealthy(&self) -> Vec<&ServiceInfo> {
+        self.services
+            .iter()
+            .filter(|service| service.healthy == Some(false))
+            .collect()
+    }
+}
+
+/// Response from listing services.
+#[derive(Debug, Clone)]
+pub struct ServiceListResponse {
+    /// Results from each node.
+    pub results: Vec<ServiceListResult>,
+}
+
+impl From<ProtoServiceListResponse> for ServiceListResponse {
+    fn from(proto: ProtoServiceListResponse) -> Self {
+        Self {
+            results: proto
+                .messages
+                .into_iter()
+                .map(ServiceListResult::from)
+                .collect(),
+        }
+    }
+}
+
+impl ServiceListResponse {
+    /// Get the first result.
+    #[must_use]
+    pub fn first(&self) -> Option<&ServiceListResult> {
+        self.results.first()
+    }
+
+    /// Failing services on every node, paired with the node that reported them.
+    #[must_use]
+    pub fn unhealthy(&self) -> Vec<(Option<&str>, &ServiceInfo)> {
+        self.results
+            .iter()
+            .flat_map(|result| {
+                result
+                    .unhealthy()
+                    .into_iter()
+                    .map(move |service| (result.node.as_deref(), service))
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,4 +435,88 @@ mod tests {
         let proto: ProtoServiceRestartRequest = req.into();
         assert_eq!(proto.id, "etcd");
     }
+
+    #[test]
+    fn test_empty_service_id_rejected() {
+        assert!(ServiceStartRequest::new("kubelet").validate().is_ok());
+        assert!(matches!(
+            ServiceStartRequest::new("").validate(),
+            Err(TalosError::Validation(_))
+        ));
+        assert!(ServiceStopRequest::new("  ").validate().is_err());
+        assert!(ServiceRestartRequest::new("").validate().is_err());
+    }
+
+    #[test]
+    fn test_service_list_response_from_proto() {
+        use crate::api::common::Metadata;
+        use crate::api::generated::machine::{ServiceEvents, ServiceHealth};
+
+        let service = |id: &str, health: Option<ServiceHealth>| ProtoServiceInfo {
+            id: id.to_string(),
+            state: "Running".to_string(),
+            events: None,
+            health,
+        };
+        let proto = ProtoServiceListResponse {
+            messages: vec![ProtoServiceList {
+                metadata: Some(Metadata {
+                    hostname: "10.0.0.1".to_string(),
+                    ..Default::default()
+                }),
+                services: vec![
+                    ProtoServiceInfo {
+                        events: Some(ServiceEvents {
+                            events: vec![ProtoServiceEvent {
+                                msg: "Health check successful".to_string(),
+                                state: "Running".to_string(),
+                                ts: None,
+                            }],
+                        }),
+                        ..service(
+                            "apid",
+                            Some(ServiceHealth {
+                                healthy: true,
+                                ..Default::default()
+                            }),
+                        )
+                    },
+                    service(
+                        "kubelet",
+                        Some(ServiceHealth {
+                            healthy: false,
+                            last_message: "connection refused".to_string(),
+                            ..Default::default()
+                        }),
+                    ),
+                    service(
+                        "etcd",
+                        Some(ServiceHealth {
+                            unknown: true,
+                            ..Default::default()
+                        }),
+                    ),
+                    service("udevd", None),
+                ],
+            }],
+        };
+
+        let response = ServiceListResponse::from(proto);
+        let node = response.first().unwrap();
+        assert_eq!(node.node.as_deref(), Some("10.0.0.1"));
+
+        let apid = node.find("apid").unwrap();
+        assert_eq!(apid.healthy, Some(true));
+        assert_eq!(apid.events.len(), 1);
+        assert_eq!(apid.events[0].msg, "Health check successful");
+        assert_eq!(node.find("etcd").unwrap().healthy, None);
+        assert_eq!(node.find("udevd").unwrap().healthy, None);
+        assert!(node.find("missing").is_none());
+
+        let unhealthy = response.unhealthy();
+        assert_eq!(unhealthy.len(), 1);
+        assert_eq!(unhealthy[0].0, Some("10.0.0.1"));
+        assert_eq!(unhealthy[0].1.id, "kubelet");
+        assert_eq!(unhealthy[0].1.health_last_message, "connection refused");
+    }
 }
@@ -15,6 +15,7 @@ use crate::api::generated::machine::{
     NetworkDeviceStatsResponse as ProtoNetworkDeviceStatsResponse, Process as ProtoProcess,
     ProcessInfo as ProtoProcessInfo, ProcessesResponse as ProtoProcessesResponse,
 };
+use std::collections::{HashMap, HashSet};
 
 // =============================================================================
 // LoadAvg
@@ -63,6 +64,40 @@ impl From<ProtoLoadAvgResponse> for LoadAvgResponse {
     }
 }
 
+impl LoadAvgResult {
+    /// 1-minute load average divided by the number of CPU cores.
+    ///
+    /// Returns `None` if `cpu_count` is zero.
+    #[must_use]
+    pub fn load1_per_core(&self, cpu_count: usize) -> Option<f64> {
+        per_core(self.load1, cpu_count)
+    }
+
+    /// 5-minute load average divided by the number of CPU cores.
+    ///
+    /// Returns `None` if `cpu_count` is zero.
+    #[must_use]
+    pub fn load5_per_core(&self, cpu_count: usize) -> Option<f64> {
+        per_core(self.load5, cpu_count)
+    }
+
+    /// 15-minute load average divided by the number of CPU cores.
+    ///
+    /// Returns `None` if `cpu_count` is zero.
+    #[must_use]
+    pub fn load15_per_core(&self, cpu_count: usize) -> Option<f64> {
+        per_core(self.load15, cpu_count)
+    }
+}
+
+fn per_core(load: f64, cpu_count: usize) -> Option<f64> {
+    if cpu_count == 0 {
+        None
+    } else {
+        Some(load / cpu_count as f64)
+    }
+}
+
 impl LoadAvgResponse {
     /// Get the first result.
     #[must_use]
@@ -515,6 +550,80 @@ impl MountsResponse {
     pub fn first(&self) -> Option<&MountsResult> {
         self.results.first()
     }
+
+    /// Correlate device-backed mounts with the IO stats of their disk.
+    ///
+    /// Only mounts whose filesystem is a `/dev/` path are returned. Each is
+    /// matched, best effort, against the devices reported by the same node
+    /// in `disk_stats`: first by exact name (`sda1`), then by the parent
+    /// disk of a partition (`sda` for `sda1`, `nvme0n1` for `nvme0n1p2`).
+    /// Mounts that cannot be resolved, such as device-mapper volumes, are
+    /// kept with `disk: None`.
+    #[must_use]
+    pub fn with_disk_stats<'a>(
+        &'a self,
+        disk_stats: &'a DiskStatsResponse,
+    ) -> Vec<MountDevice<'a>> {
+        let by_node_name: HashMap<(Option<&str>, &str), &DiskStat> = disk_stats
+            .results
+            .iter()
+            .flat_map(|r| {
+                r.devices
+                    .iter()
+                    .map(move |d| ((r.node.as_deref(), d.name.as_str()), d))
+            })
+            .collect();
+
+        self.results
+            .iter()
+            .flat_map(|r| r.stats.iter().map(move |m| (r.node.as_deref(), m)))
+            .filter_map(|(node, mount)| {
+                let device = mount.filesystem.strip_prefix("/dev/")?;
+                let disk = by_node_name
+                    .get(&(node, device))
+                    .or_else(|| {
+                        partition_parent(device)
+                            .and_then(|parent| by_node_name.get(&(node, parent)))
+                    })
+                    .copied();
+                Some(MountDevice {
+                    node,
+                    mount,
+                    device,
+                    disk,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Parent disk name of a partition device, e.g. `sda` for `sda1` or
+/// `nvme0n1` for `nvme0n1p2`.
+fn partition_parent(device: &str) -> Option<&str> {
+    let base = device.trim_end_matches(|c: char| c.is_ascii_digit());
+    if base.len() == device.len() || base.is_empty() {
+        return None;
+    }
+    // Devices whose name ends in a digit separate partitions with a `p`.
+    match base.strip_suffix('p') {
+        Some(disk) if disk.ends_with(|c: char| c.is_ascii_digit()) => Some(disk),
+        _ => Some(base),
+    }
+}
+
+/// A mount joined with the IO stats of the device backing it.
+///
+/// Produced by [`MountsResponse::with_disk_stats`].
+#[derive(Debug, Clone)]
+pub struct MountDevice<'a> {
+    /// Node that reported the mount.
+    pub node: Option<&'a str>,
+    /// The mount, with capacity information.
+    pub mount: &'a MountStat,
+    /// Device name without the `/dev/` prefix.
+    pub device: &'a str,
+    /// IO stats of the device or its parent disk, if one could be matched.
+    pub disk: Option<&'a DiskStat>,
 }
 
 // =============================================================================
@@ -581,6 +690,105 @@ impl From<ProtoProcess> for ProcessesResult {
     }
 }
 
+impl ProcessesResult {
+    /// Child pids of each pid that has children, sorted ascending.
+    ///
+    /// Processes whose parent is not in the result are left out, as is a
+    /// process listed as its own parent.
+    #[must_use]
+    pub fn children(&self) -> HashMap<i32, Vec<i32>> {
+        let pids: HashSet<i32> = self.processes.iter().map(|p| p.pid).collect();
+        let mut children: HashMap<i32, Vec<i32>> = HashMap::new();
+        for process in &self.processes {
+            if process.ppid != process.pid && pids.contains(&process.ppid) {
+                children.entry(process.ppid).or_default().push(process.pid);
+            }
+        }
+        for pids in children.values_mut() {
+            pids.sort_unstable();
+            pids.dedup();
+        }
+        children
+    }
+
+    /// Build the process hierarchy, `pstree`-style.
+    ///
+    /// Returns one tree per root, sorted by pid: processes without a parent
+    /// (`ppid` 0, e.g. `init` and `kthreadd`) and orphans whose parent is
+    /// not in the result. A parent cycle, which a consistent snapshot never
+    /// contains, is broken at its lowest pid. Every process appears exactly
+    /// once; for duplicate pids the first entry wins.
+    #[must_use]
+    pub fn tree(&self) -> Vec<ProcessNode> {
+        let mut by_pid: HashMap<i32, &ProcessInfo> = HashMap::new();
+        for process in &self.processes {
+            by_pid.entry(process.pid).or_insert(process);
+        }
+        let children = self.children();
+
+        let mut pids: Vec<i32> = by_pid.keys().copied().collect();
+        pids.sort_unstable();
+
+        let mut visited = HashSet::new();
+        let mut roots = Vec::new();
+        for &pid in &pids {
+            let process = by_pid[&pid];
+            if process.ppid == pid || !by_pid.contains_key(&process.ppid) {
+                roots.push(build_process_node(pid, &by_pid, &children, &mut visited));
+            }
+        }
+        // Whatever is left hangs off a cycle.
+        for &pid in &pids {
+            if !visited.contains(&pid) {
+                roots.push(build_process_node(pid, &by_pid, &children, &mut visited));
+            }
+        }
+        roots
+    }
+}
+
+/// Build the subtree rooted at `pid`, skipping processes already placed.
+fn build_process_node(
+    pid: i32,
+    by_pid: &HashMap<i32, &ProcessInfo>,
+    children: &HashMap<i32, Vec<i32>>,
+    visited: &mut HashSet<i32>,
+) -> ProcessNode {
+    visited.insert(pid);
+    let mut node = ProcessNode {
+        process: by_pid[&pid].clone(),
+        children: Vec::new(),
+    };
+    for &child in children.get(&pid).into_iter().flatten() {
+        if !visited.contains(&child) {
+            node.children
+                .push(build_process_node(child, by_pid, children, visited));
+        }
+    }
+    node
+}
+
+/// A process and its children in a process tree.
+#[derive(Debug, Clone)]
+pub struct ProcessNode {
+    /// The process.
+    pub process: ProcessInfo,
+    /// Child processes, sorted by pid.
+    pub children: Vec<ProcessNode>,
+}
+
+impl ProcessNode {
+    /// Number of processes in this subtree, including this one.
+    #[must_use]
+    pub fn process_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(ProcessNode::process_count)
+            .sum::<usize>()
+    }
+}
+
 /// Response from processes request.
 #[derive(Debug, Clone)]
 pub struct ProcessesResponse {
@@ -629,6 +837,33 @@ mod tests {
         assert_eq!(result.load1, 0.5);
     }
 
+    #[test]
+    fn test_load_avg_per_core() {
+        let result = LoadAvgResult {
+            node: Some("node1".to_string()),
+            load1: 8.0,
+            load5: 4.0,
+            load15: 2.0,
+        };
+        assert_eq!(result.load1_per_core(4), Some(2.0));
+        assert_eq!(result.load5_per_core(4), Some(1.0));
+        assert_eq!(result.load15_per_core(4), Some(0.5));
+        assert_eq!(result.load1_per_core(64), Some(0.125));
+    }
+
+    #[test]
+    fn test_load_avg_per_core_zero_cores() {
+        let result = LoadAvgResult {
+            node: None,
+            load1: 1.0,
+            load5: 1.0,
+            load15: 1.0,
+        };
+        assert_eq!(result.load1_per_core(0), None);
+        assert_eq!(result.load5_per_core(0), None);
+        assert_eq!(result.load15_per_core(0), None);
+    }
+
     #[test]
     fn test_memory_result() {
         let result = MemoryResult {
@@ -728,4 +963,164 @@ mod tests {
         assert_eq!(proc.pid, 1);
         assert_eq!(proc.command, "init");
     }
+
+    fn process(pid: i32, ppid: i32, command: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            ppid,
+            state: "S".to_string(),
+            threads: 1,
+            cpu_time: 0.0,
+            virtual_memory: 0,
+            resident_memory: 0,
+            command: command.to_string(),
+            executable: String::new(),
+            args: String::new(),
+        }
+    }
+
+    fn shape(node: &ProcessNode) -> String {
+        if node.children.is_empty() {
+            return node.process.pid.to_string();
+        }
+        let children: Vec<String> = node.children.iter().map(shape).collect();
+        format!("{}({})", node.process.pid, children.join(" "))
+    }
+
+    #[test]
+    fn test_process_tree() {
+        let result = ProcessesResult {
+            node: None,
+            processes: vec![
+                process(1, 0, "init"),
+                process(2, 0, "kthreadd"),
+                process(120, 1, "containerd"),
+                process(300, 120, "containerd-shim"),
+                process(301, 300, "kubelet"),
+                process(302, 300, "etcd"),
+                process(50, 2, "kworker"),
+                process(900, 777, "orphan"),
+                process(901, 900, "orphan-child"),
+            ],
+        };
+
+        let tree = result.tree();
+        let shapes: Vec<String> = tree.iter().map(shape).collect();
+        assert_eq!(shapes, vec!["1(120(300(301 302)))", "2(50)", "900(901)"]);
+        assert_eq!(tree[0].process.command, "init");
+        assert_eq!(
+            tree.iter().map(ProcessNode::process_count).sum::<usize>(),
+            9
+        );
+
+        let children = result.children();
+        assert_eq!(children[&300], vec![301, 302]);
+        assert!(!children.contains_key(&777));
+    }
+
+    #[test]
+    fn test_process_tree_breaks_cycles() {
+        let result = ProcessesResult {
+            node: None,
+            processes: vec![
+                process(10, 11, "a"),
+                process(11, 10, "b"),
+                process(12, 11, "c"),
+                process(20, 20, "self-parent"),
+                process(20, 1, "duplicate"),
+            ],
+        };
+
+        let shapes: Vec<String> = result.tree().iter().map(shape).collect();
+        assert_eq!(shapes, vec!["20", "10(11(12))"]);
+    }
+
+    fn disk(name: &str, read_completed: u64) -> DiskStat {
+        DiskStat {
+            name: name.to_string(),
+            read_completed,
+            read_sectors: 0,
+            read_time_ms: 0,
+            write_completed: 0,
+            write_sectors: 0,
+            write_time_ms: 0,
+            io_in_progress: 0,
+            io_time_ms: 0,
+        }
+    }
+
+    fn mount(filesystem: &str, mounted_on: &str) -> MountStat {
+        MountStat {
+            filesystem: filesystem.to_string(),
+            size: 100,
+            available: 40,
+            mounted_on: mounted_on.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_mounts_with_disk_stats() {
+        let disk_stats = DiskStatsResponse {
+            results: vec![DiskStatsResult {
+                node: Some("node-1".to_string()),
+                total: None,
+                devices: vec![disk("sda", 10), disk("sda1", 7), disk("nvme0n1", 3)],
+            }],
+        };
+        let mounts = MountsResponse {
+            results: vec![MountsResult {
+                node: Some("node-1".to_string()),
+                stats: vec![
+                    mount("/dev/sda1", "/"),
+                    mount("/dev/nvme0n1p2", "/var"),
+                    mount("tmpfs", "/run"),
+                    mount("/dev/mapper/luks", "/data"),
+                ],
+            }],
+        };
+
+        let joined = mounts.with_disk_stats(&disk_stats);
+        assert_eq!(joined.len(), 3);
+
+        assert_eq!(joined[0].mount.mounted_on, "/");
+        assert_eq!(joined[0].device, "sda1");
+        assert_eq!(joined[0].disk.map(|d| d.name.as_str()), Some("sda1"));
+        assert_eq!(joined[0].mount.used(), 60);
+
+        assert_eq!(joined[1].device, "nvme0n1p2");
+        assert_eq!(joined[1].disk.map(|d| d.name.as_str()), Some("nvme0n1"));
+
+        assert_eq!(joined[2].device, "mapper/luks");
+        assert!(joined[2].disk.is_none());
+    }
+
+    #[test]
+    fn test_mounts_with_disk_stats_per_node() {
+        let disk_stats = DiskStatsResponse {
+            results: vec![DiskStatsResult {
+                node: Some("node-2".to_string()),
+                total: None,
+                devices: vec![disk("sda", 1)],
+            }],
+        };
+        let mounts = MountsResponse {
+            results: vec![MountsResult {
+                node: Some("node-1".to_string()),
+                stats: vec![mount("/dev/sda1", "/")],
+            }],
+        };
+
+        let joined = mounts.with_disk_stats(&disk_stats);
+        assert_eq!(joined.len(), 1);
+        assert!(joined[0].disk.is_none());
+    }
+
+    #[test]
+    fn test_partition_parent() {
+        assert_eq!(partition_parent("sda1"), Some("sda"));
+        assert_eq!(partition_parent("vdb12"), Some("vdb"));
+        assert_eq!(partition_parent("nvme0n1p2"), Some("nvme0n1"));
+        assert_eq!(partition_parent("mmcblk0p1"), Some("mmcblk0"));
+        assert_eq!(partition_parent("sda"), None);
+    }
 }
@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for the SystemStat API.
+//!
+//! Mirrors `/proc/stat`: CPU times are cumulative seconds since boot, so
+//! usage figures derived from a single sample are averages over the uptime.
+//! Compare two samples with [`CpuStat::usage_percent_since`] for current load.
+
+use crate::api::generated::machine::{
+    CpuStat as ProtoCpuStat, SystemStat as ProtoSystemStat,
+    SystemStatResponse as ProtoSystemStatResponse,
+};
+
+/// Cumulative CPU time per state, in seconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuStat {
+    /// Time in user mode.
+    pub user: f64,
+    /// Time in user mode with low priority.
+    pub nice: f64,
+    /// Time in kernel mode.
+    pub system: f64,
+    /// Idle time.
+    pub idle: f64,
+    /// Time waiting for I/O.
+    pub iowait: f64,
+    /// Time servicing interrupts.
+    pub irq: f64,
+    /// Time servicing soft interrupts.
+    pub soft_irq: f64,
+    /// Time stolen by the hypervisor.
+    pub steal: f64,
+    /// Time running a guest (included in `user`).
+    pub guest: f64,
+    /// Time running a low-priority guest (included in `nice`).
+    pub guest_nice: f64,
+}
+
+impl From<ProtoCpuStat> for CpuStat {
+    fn from(proto: ProtoCpuStat) -> Self {
+        Self {
+            user: proto.user,
+            nice: proto.nice,
+            system: proto.system,
+            idle: proto.idle,
+            iowait: proto.iowait,
+            irq: proto.irq,
+            soft_irq: proto.soft_irq,
+            steal: proto.steal,
+            guest: proto.guest,
+            guest_nice: proto.guest_nice,
+        }
+    }
+}
+
+impl CpuStat {
+    /// Total accounted time.
+    ///
+    /// Guest time is already part of `user` and `nice`, so it is not added
+    /// again.
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.user
+            + self.nice
+            + self.system
+            + self.idle
+            + self.iowait
+            + self.irq
+            + self.soft_irq
+            + self.steal
+    }
+
+    /// Busy time as a percentage of the total (`100 - idle%`).
+    ///
+    /// `iowait` counts as idle, as in `top`.
+    #[must_use]
+    pub fn usage_percent(&self) -> f64 {
+        busy_percent(self.total(), self.idle + self.iowait)
+    }
+
+    /// Busy percentage between an earlier sample and this one.
+    #[must_use]
+    pub fn usage_percent_since(&self, earlier: &CpuStat) -> f64 {
+        busy_percent(
+            self.total() - earlier.total(),
+            (self.idle + self.iowait) - (earlier.idle + earlier.iowait),
+        )
+    }
+}
+
+fn busy_percent(total: f64, idle: f64) -> f64 {
+    if total <= 0.0 {
+        0.0
+    } else {
+        (100.0 - idle / total * 100.0).clamp(0.0, 100.0)
+    }
+}
+
+/// Kernel statistics reported by a node.
+#[derive(Debug, Clone)]
+pub struct SystemStatResult {
+    /// Node that returned this result.
+    pub node: Option<String>,
+    /// Boot time as a Unix timestamp in seconds.
+    pub boot_time: u64,
+    /// Aggregate over all CPUs.
+    pub cpu_total: CpuStat,
+    /// Per-CPU breakdown.
+    pub cpu: Vec<CpuStat>,
+    /// Total interrupts serviced since boot.
+    pub irq_total: u64,
+    /// Context switches since boot.
+    pub context_switches: u64,
+    /// Processes created since boot.
+    pub process_created: u64,
+    /// Processes currently running.
+    pub process_running: u64,
+    /// Processes blocked on I/O.
+    pub process_blocked: u64,
+    /// Total soft interrupts serviced since boot.
+    pub soft_irq_total: u64,
+}
+
+impl From<ProtoSystemStat> for SystemStatResult {
+    fn from(proto: ProtoSystemStat) -> Self {
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            boot_time: proto.boot_time,
+            cpu_total: proto.cpu_total.map(CpuStat::from).unwrap_or_default(),
+            cpu: proto.cpu.into_iter().map(CpuStat::from).collect(),
+            irq_total: proto.irq_total,
+            context_switches: proto.context_switches,
+            process_created: proto.process_created,
+            process_running: proto.process_running,
+            process_blocked: proto.process_blocked,
+            soft_irq_total: proto.soft_irq_total,
+        }
+    }
+}
+
+impl SystemStatResult {
+    /// CPU usage over all CPUs since boot (`100 - idle%`).
+    #[must_use]
+    pub fn cpu_total_usage_percent(&self) -> f64 {
+        self.cpu_total.usage_percent()
+    }
+}
+
+/// Response from system stat request.
+#[derive(Debug, Clone)]
+pub struct SystemStatResponse {
+    /// Results from each node.
+    pub results: Vec<SystemStatResult>,
+}
+
+impl From<ProtoSystemStatResponse> for SystemStatResponse {
+    fn from(proto: ProtoSystemStatResponse) -> Self {
+        Self {
+            results: proto
+                .messages
+                .into_iter()
+                .map(SystemStatResult::from)
+                .collect(),
+        }
+    }
+}
+
+impl SystemStatResponse {
+    /// Get the first result.
+    #[must_use]
+    pub fn first(&self) -> Option<&SystemStatResult> {
+        self.results.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+
+    fn cpu(user: f64, system: f64, idle: f64, iowait: f64) -> ProtoCpuStat {
+        ProtoCpuStat {
+            user,
+            system,
+            idle,
+            iowait,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_system_stat_response_from_proto() {
+        let proto = ProtoSystemStatResponse {
+            messages: vec![ProtoSystemStat {
+                metadata: Some(Metadata {
+                    hostname: "10.0.0.1".to_string(),
+                    ..Default::default()
+                }),
+                boot_time: 1_700_000_000,
+                cpu_total: Some(cpu(200.0, 50.0, 700.0, 50.0)),
+                cpu: vec![cpu(100.0, 25.0, 350.0, 25.0), cpu(100.0, 25.0, 350.0, 25.0)],
+                context_switches: 42,
+                process_running: 3,
+                process_blocked: 1,
+                ..Default::default()
+            }],
+        };
+
+        let response = SystemStatResponse::from(proto);
+        let first = response.first().unwrap();
+        assert_eq!(first.node.as_deref(), Some("10.0.0.1"));
+        assert_eq!(first.boot_time, 1_700_000_000);
+        assert_eq!(first.cpu.len(), 2);
+        assert_eq!(first.context_switches, 42);
+        assert_eq!(first.process_running, 3);
+        assert_eq!(first.process_blocked, 1);
+        assert_eq!(first.cpu_total.total(), 1000.0);
+        assert!((first.cpu_total_usage_percent() - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cpu_stat_usage_percent() {
+        assert_eq!(CpuStat::default().usage_percent(), 0.0);
+
+        let earlier = CpuStat::from(cpu(100.0, 0.0, 100.0, 0.0));
+        let later = CpuStat::from(cpu(190.0, 0.0, 110.0, 0.0));
+        assert!((later.usage_percent_since(&earlier) - 90.0).abs() < 1e-9);
+        assert_eq!(earlier.usage_percent_since(&earlier), 0.0);
+    }
+}
@@ -4,18 +4,27 @@
 //!
 //! Provides functionality to upgrade Talos nodes to a new version.
 
+use super::cluster::{ClusterMember, NodeRole};
 use crate::api::generated::machine::{
     Upgrade as ProtoUpgrade, UpgradeRequest as ProtoUpgradeRequest,
     UpgradeResponse as ProtoUpgradeResponse,
 };
+use std::time::Duration;
 
 /// Reboot mode for upgrade.
+///
+/// Mirrors `talosctl upgrade --reboot-mode`, whose default is `default`.
+///
+/// - [`Default`](Self::Default): the node reboots into the new image using
+///   kexec when available, falling back to a regular reboot otherwise.
+/// - [`PowerCycle`](Self::PowerCycle): bypasses kexec and performs a full
+///   power cycle, which is useful when kexec is unreliable on the hardware.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum UpgradeRebootMode {
-    /// Default reboot mode.
+    /// Default reboot mode (kexec if available), matching talosctl.
     #[default]
     Default,
-    /// Power cycle instead of reboot.
+    /// Power cycle instead of reboot (bypasses kexec).
     PowerCycle,
 }
 
@@ -61,9 +70,9 @@ pub struct UpgradeRequest {
     pub preserve: bool,
     /// Stage the upgrade (don't apply immediately).
     pub stage: bool,
-    /// Force upgrade even if already on same version.
+    /// Force the upgrade, skipping pre-upgrade etcd health checks.
     pub force: bool,
-    /// Reboot mode.
+    /// Reboot mode (defaults to [`UpgradeRebootMode::Default`], like talosctl).
     pub reboot_mode: UpgradeRebootMode,
 }
 
@@ -80,6 +89,31 @@ impl UpgradeRequest {
         }
     }
 
+    /// Create a staged upgrade request.
+    ///
+    /// The image is downloaded and staged, and the upgrade is applied on the
+    /// next reboot. Data is preserved, matching `talosctl upgrade --stage --preserve`.
+    #[must_use]
+    pub fn staged(image: impl Into<String>) -> Self {
+        Self {
+            preserve: true,
+            stage: true,
+            ..Self::new(image)
+        }
+    }
+
+    /// Create a forced upgrade request.
+    ///
+    /// Skips the etcd health checks performed before upgrading a control plane
+    /// node, matching `talosctl upgrade --force`.
+    #[must_use]
+    pub fn force(image: impl Into<String>) -> Self {
+        Self {
+            force: true,
+            ..Self::new(image)
+        }
+    }
+
     /// Create a builder for customizing the upgrade request.
     #[must_use]
     pub fn builder(image: impl Into<String>) -> UpgradeRequestBuilder {
@@ -87,6 +121,19 @@ impl UpgradeRequest {
     }
 }
 
+impl UpgradeRequest {
+    /// Version tag of the image (e.g. `v1.6.0` for
+    /// `ghcr.io/siderolabs/installer:v1.6.0`), if it has one.
+    #[must_use]
+    pub fn image_tag(&self) -> Option<&str> {
+        let name = self.image.split('@').next()?;
+        let last = name.rsplit('/').next()?;
+        last.split_once(':')
+            .map(|(_, tag)| tag)
+            .filter(|tag| !tag.is_empty())
+    }
+}
+
 impl From<UpgradeRequest> for ProtoUpgradeRequest {
     fn from(req: UpgradeRequest) -> Self {
         Self {
@@ -136,7 +183,7 @@ impl UpgradeRequestBuilder {
         self
     }
 
-    /// Force upgrade even if already on same version.
+    /// Force the upgrade, skipping pre-upgrade etcd health checks.
     #[must_use]
     pub fn force(mut self, force: bool) -> Self {
         self.force = force;
@@ -215,6 +262,184 @@ impl UpgradeResponse {
     pub fn first(&self) -> Option<&UpgradeResult> {
         self.results.first()
     }
+
+    /// Check that at least `expected` nodes returned a result.
+    ///
+    /// A multi-node call succeeds even if some targeted nodes never answered;
+    /// pass [`NodeTarget::expected_responses`](crate::client::NodeTarget::expected_responses)
+    /// to confirm every node was reached.
+    #[must_use]
+    pub fn reached_all(&self, expected: usize) -> bool {
+        self.results.len() >= expected
+    }
+}
+
+// =============================================================================
+// Rolling upgrade
+// =============================================================================
+
+/// Which group of nodes a rolling upgrade visits first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RollingUpgradeOrder {
+    /// Workers first, then control-plane nodes (default).
+    #[default]
+    WorkersFirst,
+    /// Control-plane nodes first, then workers.
+    ControlPlaneFirst,
+}
+
+/// Nodes and pacing for [`TalosClient::rolling_upgrade`](crate::TalosClient::rolling_upgrade).
+///
+/// Control-plane nodes are always upgraded one at a time, with etcd
+/// required to be healthy before and after each one. Workers are upgraded
+/// in batches of `worker_concurrency`.
+#[derive(Debug, Clone)]
+pub struct RollingUpgradeOptions {
+    /// Control-plane nodes (IP or hostname), in upgrade order.
+    pub control_plane: Vec<String>,
+    /// Worker nodes (IP or hostname), in upgrade order.
+    pub workers: Vec<String>,
+    /// Which group goes first.
+    pub order: RollingUpgradeOrder,
+    /// Number of workers upgraded at the same time (at least 1).
+    pub worker_concurrency: usize,
+    /// How long a node may take to come back running the new version,
+    /// and etcd to become healthy again.
+    pub node_timeout: Duration,
+    /// Interval between health polls.
+    pub poll_interval: Duration,
+    /// Keep going after a node fails instead of skipping the rest.
+    pub continue_on_failure: bool,
+}
+
+impl RollingUpgradeOptions {
+    /// Upgrade the given control-plane and worker nodes.
+    #[must_use]
+    pub fn new(
+        control_plane: impl IntoIterator<Item = impl Into<String>>,
+        workers: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            control_plane: control_plane.into_iter().map(Into::into).collect(),
+            workers: workers.into_iter().map(Into::into).collect(),
+            order: RollingUpgradeOrder::default(),
+            worker_concurrency: 1,
+            node_timeout: Duration::from_secs(600),
+            poll_interval: Duration::from_secs(5),
+            continue_on_failure: false,
+        }
+    }
+
+    /// Upgrade discovered cluster members, addressed by their endpoint host.
+    ///
+    /// Members of unknown role are treated as workers.
+    #[must_use]
+    pub fn from_members(members: &[ClusterMember]) -> Self {
+        let node = |member: &ClusterMember| {
+            member
+                .endpoint
+                .parse::<http::Uri>()
+                .ok()
+                .and_then(|uri| uri.host().map(str::to_string))
+                .unwrap_or_else(|| member.name.clone())
+        };
+        Self::new(
+            members.iter().filter(|m| m.is_control_plane()).map(node),
+            members.iter().filter(|m| !m.is_control_plane()).map(node),
+        )
+    }
+
+    /// Set which group goes first.
+    #[must_use]
+    pub fn with_order(mut self, order: RollingUpgradeOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set the number of workers upgraded at the same time.
+    #[must_use]
+    pub fn with_worker_concurrency(mut self, concurrency: usize) -> Self {
+        self.worker_concurrency = concurrency;
+        self
+    }
+
+    /// Set how long each node may take to come back.
+    #[must_use]
+    pub fn with_node_timeout(mut self, timeout: Duration) -> Self {
+        self.node_timeout = timeout;
+        self
+    }
+
+    /// Set the interval between health polls.
+    #[must_use]
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Keep going after a node fails.
+    #[must_use]
+    pub fn continue_on_failure(mut self, continue_on_failure: bool) -> Self {
+        self.continue_on_failure = continue_on_failure;
+        self
+    }
+}
+
+/// What happened to a node during a rolling upgrade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeUpgradeStatus {
+    /// The node came back running `version`.
+    Upgraded {
+        /// Version tag the node reported.
+        version: String,
+    },
+    /// The upgrade or one of its safety checks failed.
+    Failed {
+        /// What went wrong.
+        error: String,
+    },
+    /// The node was not touched.
+    Skipped {
+        /// Why the node was skipped.
+        reason: String,
+    },
+}
+
+/// Outcome for one node of a rolling upgrade.
+#[derive(Debug, Clone)]
+pub struct NodeUpgradeOutcome {
+    /// Node as given in [`RollingUpgradeOptions`].
+    pub node: String,
+    /// Role the node was upgraded as.
+    pub role: NodeRole,
+    /// What happened.
+    pub status: NodeUpgradeStatus,
+}
+
+/// Per-node outcomes of a rolling upgrade, in the order nodes were visited.
+#[derive(Debug, Clone, Default)]
+pub struct RollingUpgradeReport {
+    /// One entry per node.
+    pub nodes: Vec<NodeUpgradeOutcome>,
+}
+
+impl RollingUpgradeReport {
+    /// Check if every node was upgraded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.nodes
+            .iter()
+            .all(|n| matches!(n.status, NodeUpgradeStatus::Upgraded { .. }))
+    }
+
+    /// Nodes whose upgrade failed.
+    #[must_use]
+    pub fn failed(&self) -> Vec<&NodeUpgradeOutcome> {
+        self.nodes
+            .iter()
+            .filter(|n| matches!(n.status, NodeUpgradeStatus::Failed { .. }))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -254,6 +479,35 @@ mod tests {
         assert_eq!(UpgradeRebootMode::PowerCycle.to_string(), "powercycle");
     }
 
+    #[test]
+    fn test_default_reboot_mode_matches_talosctl() {
+        assert_eq!(UpgradeRebootMode::default(), UpgradeRebootMode::Default);
+        assert_eq!(UpgradeRebootMode::default().to_string(), "default");
+
+        let req = UpgradeRequest::builder("test:v1.0").build();
+        assert_eq!(req.reboot_mode, UpgradeRebootMode::Default);
+    }
+
+    #[test]
+    fn test_staged_constructor() {
+        let proto: ProtoUpgradeRequest = UpgradeRequest::staged("test:v1.0").into();
+        assert_eq!(proto.image, "test:v1.0");
+        assert!(proto.stage);
+        assert!(proto.preserve);
+        assert!(!proto.force);
+        assert_eq!(proto.reboot_mode, 0);
+    }
+
+    #[test]
+    fn test_force_constructor() {
+        let proto: ProtoUpgradeRequest = UpgradeRequest::force("test:v1.0").into();
+        assert_eq!(proto.image, "test:v1.0");
+        assert!(proto.force);
+        assert!(!proto.stage);
+        assert!(!proto.preserve);
+        assert_eq!(proto.reboot_mode, 0);
+    }
+
     #[test]
     fn test_proto_conversion() {
         let req = UpgradeRequest::builder("test:v1.0")
@@ -266,4 +520,58 @@ mod tests {
         assert!(proto.stage);
         assert!(proto.force);
     }
+
+    fn upgrade_result(node: &str) -> UpgradeResult {
+        UpgradeResult {
+            node: Some(node.to_string()),
+            ack: "Upgrade request received".to_string(),
+            actor_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_upgrade_response_reached_all() {
+        let response = UpgradeResponse {
+            results: vec![upgrade_result("node1"), upgrade_result("node2")],
+        };
+
+        assert!(response.reached_all(2));
+        assert!(!response.reached_all(3));
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn test_upgrade_request_image_tag() {
+        let tag = |image: &str| UpgradeRequest::new(image).image_tag().map(str::to_string);
+        assert_eq!(
+            tag("ghcr.io/siderolabs/installer:v1.6.0").as_deref(),
+            Some("v1.6.0")
+        );
+        assert_eq!(
+            tag("localhost:5000/installer:v1.7.1").as_deref(),
+            Some("v1.7.1")
+        );
+        assert_eq!(
+            tag("factory.talos.dev/installer/abc:v1.8.0@sha256:0123").as_deref(),
+            Some("v1.8.0")
+        );
+        assert_eq!(tag("localhost:5000/installer"), None);
+        assert_eq!(tag("ghcr.io/siderolabs/installer@sha256:0123"), None);
+    }
+
+    #[test]
+    fn test_rolling_upgrade_options_from_members() {
+        let members = vec![
+            ClusterMember::new("cp-1", "https://10.0.0.1:50000", NodeRole::ControlPlane),
+            ClusterMember::new("w-1", "https://10.0.0.11:50000", NodeRole::Worker),
+            ClusterMember::new("other", "not a url", NodeRole::Unknown),
+        ];
+
+        let options = RollingUpgradeOptions::from_members(&members);
+        assert_eq!(options.control_plane, vec!["10.0.0.1"]);
+        assert_eq!(options.workers, vec!["10.0.0.11", "other"]);
+        assert_eq!(options.order, RollingUpgradeOrder::WorkersFirst);
+        assert_eq!(options.worker_concurrency, 1);
+        assert!(!options.continue_on_failure);
+    }
 }
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Typed wrappers for the machine Version API.
+//!
+//! Unlike the standalone `VersionService`, `MachineService.Version` reports
+//! per-node build information, so callers can gate behavior on the Talos
+//! version of each node.
+
+use crate::api::generated::machine::{
+    Version as ProtoVersion, VersionResponse as ProtoVersionResponse,
+};
+
+/// Version information reported by a node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionResult {
+    /// Node that returned this result.
+    pub node: Option<String>,
+    /// Release tag (e.g. `v1.6.0`).
+    pub tag: String,
+    /// Git commit SHA.
+    pub sha: String,
+    /// Build timestamp.
+    pub built: String,
+    /// Go version used for the build.
+    pub go_version: String,
+    /// Operating system (e.g. `linux`).
+    pub os: String,
+    /// CPU architecture (e.g. `amd64`).
+    pub arch: String,
+}
+
+impl From<ProtoVersion> for VersionResult {
+    fn from(proto: ProtoVersion) -> Self {
+        let version = proto.version.unwrap_or_default();
+        Self {
+            node: proto.metadata.map(|m| m.hostname),
+            tag: version.tag,
+            sha: version.sha,
+            built: version.built,
+            go_version: version.go_version,
+            os: version.os,
+            arch: version.arch,
+        }
+    }
+}
+
+impl VersionResult {
+    /// Parse the tag as `(major, minor, patch)`.
+    ///
+    /// A leading `v` and any pre-release or build suffix are ignored, so
+    /// `v1.7.0-alpha.1` yields `(1, 7, 0)`. Returns `None` if the tag is not
+    /// a version.
+    #[must_use]
+    pub fn semver(&self) -> Option<(u64, u64, u64)> {
+        let version = self.tag.strip_prefix('v').unwrap_or(&self.tag);
+        let core = version.split(['-', '+']).next()?;
+
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+        let major = parts.next()??;
+        let minor = parts.next()??;
+        let patch = parts.next()??;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some((major, minor, patch))
+    }
+}
+
+/// Response from machine version request.
+#[derive(Debug, Clone)]
+pub struct VersionResponse {
+    /// Results from each node.
+    pub results: Vec<VersionResult>,
+}
+
+impl From<ProtoVersionResponse> for VersionResponse {
+    fn from(proto: ProtoVersionResponse) -> Self {
+        Self {
+            results: proto
+                .messages
+                .into_iter()
+                .map(VersionResult::from)
+                .collect(),
+        }
+    }
+}
+
+impl VersionResponse {
+    /// Get the first result.
+    #[must_use]
+    pub fn first(&self) -> Option<&VersionResult> {
+        self.results.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Metadata;
+    use crate::api::generated::machine::VersionInfo as ProtoVersionInfo;
+
+    fn result(tag: &str) -> VersionResult {
+        VersionResult {
+            node: None,
+            tag: tag.to_string(),
+            sha: String::new(),
+            built: String::new(),
+            go_version: String::new(),
+            os: String::new(),
+            arch: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_version_response_from_proto() {
+        let proto = ProtoVersionResponse {
+            messages: vec![ProtoVersion {
+                metadata: Some(Metadata {
+                    hostname: "10.0.0.1".to_string(),
+                    ..Default::default()
+                }),
+                version: Some(ProtoVersionInfo {
+                    tag: "v1.6.0".to_string(),
+                    sha: "abcdef".to_string(),
+                    built: "2024-01-01T00:00:00Z".to_string(),
+                    go_version: "go1.21.5".to_string(),
+                    os: "linux".to_string(),
+                    arch: "amd64".to_string(),
+                }),
+                platform: None,
+                features: None,
+            }],
+        };
+
+        let response = VersionResponse::from(proto);
+        let first = response.first().unwrap();
+        assert_eq!(first.node.as_deref(), Some("10.0.0.1"));
+        assert_eq!(first.tag, "v1.6.0");
+        assert_eq!(first.go_version, "go1.21.5");
+        assert_eq!(first.arch, "amd64");
+        assert_eq!(first.semver(), Some((1, 6, 0)));
+    }
+
+    #[test]
+    fn test_version_result_semver() {
+        assert_eq!(result("v1.9.2").semver(), Some((1, 9, 2)));
+        assert_eq!(result("1.10.0").semver(), Some((1, 10, 0)));
+        assert_eq!(result("v1.7.0-alpha.1").semver(), Some((1, 7, 0)));
+        assert_eq!(result("v1.7.0+dirty").semver(), Some((1, 7, 0)));
+
+        assert_eq!(result("").semver(), None);
+        assert_eq!(result("v1.7").semver(), None);
+        assert_eq!(result("v1.7.0.1").semver(), None);
+        assert_eq!(result("latest").semver(), None);
+    }
+}
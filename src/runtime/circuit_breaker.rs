@@ -221,6 +221,18 @@ impl CircuitBreaker {
         }
     }
 
+    /// Record the success of an operation run without [`call`](Self::call).
+    pub async fn record_success(&self) {
+        self.total_calls.fetch_add(1, Ordering::Relaxed);
+        self.on_success().await;
+    }
+
+    /// Record the failure of an operation run without [`call`](Self::call).
+    pub async fn record_failure(&self) {
+        self.total_calls.fetch_add(1, Ordering::Relaxed);
+        self.on_failure().await;
+    }
+
     /// Record a successful operation.
     async fn on_success(&self) {
         let state = *self.state.read().await;
@@ -418,6 +430,21 @@ mod tests {
         assert_eq!(breaker.total_rejections(), 1);
     }
 
+    #[tokio::test]
+    async fn test_circuit_breaker_record_outcomes() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig::new().with_failure_threshold(2));
+
+        breaker.record_failure().await;
+        breaker.record_success().await;
+        breaker.record_failure().await;
+        assert_eq!(breaker.state().await, CircuitState::Closed);
+
+        breaker.record_failure().await;
+        assert_eq!(breaker.state().await, CircuitState::Open);
+        assert_eq!(breaker.total_calls(), 4);
+        assert_eq!(breaker.total_failures(), 3);
+    }
+
     #[tokio::test]
     async fn test_circuit_breaker_success_resets_failures() {
         let config = CircuitBreakerConfig::new().with_failure_threshold(3);
@@ -20,6 +20,7 @@
 //! let client = TalosClient::with_interceptor(config, interceptor).await?;
 //! ```
 
+use super::metrics::method_from_path;
 use std::fmt;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
@@ -245,9 +246,9 @@ impl InterceptorMetrics {
 
 /// A gRPC interceptor that logs requests.
 ///
-/// This interceptor logs the start of each request. For complete request/response
-/// logging including timing and response status, you should combine this with
-/// tower middleware or use the `RequestLogger` wrapper.
+/// This interceptor logs the start of each request with its method name. For
+/// complete request/response logging including timing and response status, you
+/// should combine this with tower middleware or use the `RequestLogger` wrapper.
 #[derive(Clone)]
 pub struct LoggingInterceptor {
     config: LoggingConfig,
@@ -322,22 +323,19 @@ impl LoggingInterceptor {
             String::new()
         };
 
+        // Interceptors don't see the URI; generated clients tag the method instead.
+        let method = request
+            .extensions()
+            .get::<tonic::GrpcMethod>()
+            .map_or("unknown", |m| m.method());
+        let msg = format!("gRPC request: {}{}", method, metadata_str);
+
         match self.config.success_level {
-            LogLevel::Trace => {
-                trace!(target: "talos_api::grpc", "gRPC request{}", metadata_str);
-            }
-            LogLevel::Debug => {
-                debug!(target: "talos_api::grpc", "gRPC request{}", metadata_str);
-            }
-            LogLevel::Info => {
-                info!(target: "talos_api::grpc", "gRPC request{}", metadata_str);
-            }
-            LogLevel::Warn => {
-                warn!(target: "talos_api::grpc", "gRPC request{}", metadata_str);
-            }
-            LogLevel::Error => {
-                error!(target: "talos_api::grpc", "gRPC request{}", metadata_str);
-            }
+            LogLevel::Trace => trace!(target: "talos_api::grpc", "{}", msg),
+            LogLevel::Debug => debug!(target: "talos_api::grpc", "{}", msg),
+            LogLevel::Info => info!(target: "talos_api::grpc", "{}", msg),
+            LogLevel::Warn => warn!(target: "talos_api::grpc", "{}", msg),
+            LogLevel::Error => error!(target: "talos_api::grpc", "{}", msg),
             LogLevel::Off => {}
         }
     }
@@ -391,10 +389,14 @@ impl RequestLogger {
     }
 
     /// Start tracking a request.
+    ///
+    /// `method` may be a method name or a full gRPC path such as
+    /// `/machine.MachineService/Memory`, which is logged as `Memory`; see
+    /// [`method_from_path`].
     #[must_use]
     pub fn start(&self, method: &str) -> RequestSpan {
         RequestSpan {
-            method: method.to_string(),
+            method: method_from_path(method).to_string(),
             start: Instant::now(),
         }
     }
@@ -583,6 +585,9 @@ mod tests {
         logger.finish_success(span);
         assert_eq!(logger.metrics().total_requests(), 1);
         assert_eq!(logger.metrics().successful_requests(), 1);
+
+        let span = logger.start("/machine.MachineService/Memory");
+        assert_eq!(span.method(), "Memory");
     }
 
     #[test]
@@ -29,16 +29,42 @@
 //! // Record a request
 //! metrics.record_request("Version", "10.0.0.1:50000", true, std::time::Duration::from_millis(42));
 //!
+//! // Or label it straight from the gRPC request path
+//! metrics.record_grpc_request(
+//!     "/machine.MachineService/Memory",
+//!     "10.0.0.1:50000",
+//!     true,
+//!     std::time::Duration::from_millis(7),
+//! );
+//!
 //! // Get Prometheus text format
 //! let output = metrics.to_prometheus_text();
 //! println!("{}", output);
 //! ```
+//!
+//! To record every call a client makes, attach the collector when building the
+//! client with [`TalosClientConfig::with_metrics`](crate::TalosClientConfig::with_metrics),
+//! or wrap any gRPC service in a [`MetricsInterceptor`].
 
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 
+use tonic::codegen::BoxFuture;
+
+/// gRPC method name from a request path.
+///
+/// `/machine.MachineService/Memory` -> `Memory`. A trailing slash is ignored
+/// and a path without a slash is returned unchanged.
+#[must_use]
+pub fn method_from_path(path: &str) -> &str {
+    let path = path.strip_suffix('/').unwrap_or(path);
+    path.rsplit_once('/').map_or(path, |(_, method)| method)
+}
+
 /// Configuration for the metrics collector.
 #[derive(Debug, Clone)]
 pub struct MetricsConfig {
@@ -170,6 +196,68 @@ impl Histogram {
     fn total_count(&self) -> u64 {
         self.count.load(Ordering::Relaxed)
     }
+
+    /// Estimate the `q` quantile in seconds, Prometheus `histogram_quantile`
+    /// style: find the bucket holding the target rank and interpolate
+    /// linearly between its bounds (the first bucket starts at 0).
+    ///
+    /// Ranks beyond the last bucket return that bucket's upper bound.
+    fn quantile(&self, q: f64) -> Option<f64> {
+        let total = self.total_count();
+        if total == 0 || !(0.0..=1.0).contains(&q) {
+            return None;
+        }
+
+        let rank = q * total as f64;
+        let mut lower_bound = 0.0;
+        let mut lower_count = 0u64;
+        for (bucket, count) in self.buckets.iter().zip(&self.counts) {
+            let count = count.load(Ordering::Relaxed);
+            if count as f64 >= rank && count > lower_count {
+                if bucket.is_infinite() {
+                    return Some(lower_bound);
+                }
+                let fraction = (rank - lower_count as f64) / (count - lower_count) as f64;
+                return Some(lower_bound + (bucket - lower_bound) * fraction);
+            }
+            if bucket.is_finite() {
+                lower_bound = *bucket;
+            }
+            lower_count = count;
+        }
+
+        self.buckets
+            .iter()
+            .rev()
+            .find(|bucket| bucket.is_finite())
+            .copied()
+    }
+}
+
+/// Apply `update` to the entry for `key`, creating it with `make` if missing.
+///
+/// Existing entries are updated under the read lock. A missing entry is
+/// created and updated under a single write lock via the entry API, so when
+/// several threads race to create the same key exactly one value is inserted
+/// and every caller's update lands on it.
+fn upsert<K, V>(
+    map: &RwLock<HashMap<K, V>>,
+    key: K,
+    make: impl FnOnce() -> V,
+    update: impl FnOnce(&V),
+) where
+    K: std::hash::Hash + Eq,
+{
+    {
+        let entries = map.read().expect("lock poisoned");
+        if let Some(value) = entries.get(&key) {
+            update(value);
+            return;
+        }
+    }
+
+    let mut entries = map.write().expect("lock poisoned");
+    update(entries.entry(key).or_insert_with(make));
 }
 
 /// Key for histogram lookup (method, endpoint).
@@ -234,48 +322,60 @@ impl MetricsCollector {
             status: if success { "success" } else { "error" }.to_string(),
         };
 
-        // Update counter
-        {
-            let counters = self.requests_total.read().expect("lock poisoned");
-            if let Some(counter) = counters.get(&labels) {
+        upsert(
+            &self.requests_total,
+            labels,
+            || AtomicU64::new(0),
+            |counter| {
                 counter.fetch_add(1, Ordering::Relaxed);
-            } else {
-                drop(counters);
-                let mut counters = self.requests_total.write().expect("lock poisoned");
-                counters
-                    .entry(labels)
-                    .or_insert_with(|| AtomicU64::new(0))
-                    .fetch_add(1, Ordering::Relaxed);
-            }
-        }
-
-        // Update histogram
-        let hist_key = (
-            if self.config.method_label {
-                Some(method.to_string())
-            } else {
-                None
-            },
-            if self.config.endpoint_label {
-                Some(endpoint.to_string())
-            } else {
-                None
             },
         );
 
-        {
-            let histograms = self.request_duration.read().expect("lock poisoned");
-            if let Some(hist) = histograms.get(&hist_key) {
-                hist.observe(duration.as_secs_f64());
-            } else {
-                drop(histograms);
-                let mut histograms = self.request_duration.write().expect("lock poisoned");
-                let hist = histograms
-                    .entry(hist_key)
-                    .or_insert_with(|| Histogram::new(self.config.histogram_buckets.clone()));
-                hist.observe(duration.as_secs_f64());
-            }
-        }
+        upsert(
+            &self.request_duration,
+            self.histogram_key(method, endpoint),
+            || Histogram::new(self.config.histogram_buckets.clone()),
+            |hist| hist.observe(duration.as_secs_f64()),
+        );
+    }
+
+    /// Record a completed request, labelled with the method from its gRPC path.
+    ///
+    /// `/machine.MachineService/Memory` is recorded as `Memory`; see
+    /// [`method_from_path`].
+    pub fn record_grpc_request(
+        &self,
+        path: &str,
+        endpoint: &str,
+        success: bool,
+        duration: Duration,
+    ) {
+        self.record_request(method_from_path(path), endpoint, success, duration);
+    }
+
+    /// Estimate the `q` quantile (e.g. `0.95`) of request latency.
+    ///
+    /// The estimate is only as precise as the configured
+    /// [`histogram_buckets`](MetricsConfig::histogram_buckets): it interpolates
+    /// linearly inside the bucket containing the quantile, and latencies above
+    /// the largest bucket are reported as that bucket's bound. `method` and
+    /// `endpoint` are ignored when the matching label is disabled.
+    ///
+    /// Returns `None` if `q` is outside `0.0..=1.0` or nothing was recorded.
+    pub fn quantile(&self, method: &str, endpoint: &str, q: f64) -> Option<Duration> {
+        let histograms = self.request_duration.read().expect("lock poisoned");
+        histograms
+            .get(&self.histogram_key(method, endpoint))?
+            .quantile(q)
+            .map(Duration::from_secs_f64)
+    }
+
+    /// Histogram key for `method` and `endpoint`, honouring the label config.
+    fn histogram_key(&self, method: &str, endpoint: &str) -> HistogramKey {
+        (
+            self.config.method_label.then(|| method.to_string()),
+            self.config.endpoint_label.then(|| endpoint.to_string()),
+        )
     }
 
     /// Update circuit breaker state (0=closed, 1=half-open, 2=open).
@@ -538,10 +638,164 @@ impl MetricsCollector {
     }
 }
 
+// =============================================================================
+// MetricsInterceptor
+// =============================================================================
+
+/// Tower layer recording every gRPC call into a shared [`MetricsCollector`].
+///
+/// Each call is labelled with the method from its request path and the
+/// endpoint given here, and timed until the response headers arrive. A call
+/// counts as successful if the server answered with HTTP 200 and no error
+/// `grpc-status` header; errors only reported in the trailers of a streamed
+/// response are not seen.
+///
+/// This is a layer rather than a [`tonic::service::Interceptor`] because an
+/// interceptor only sees the request, not the outcome.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use talos_api_rs::runtime::{MetricsCollector, MetricsInterceptor};
+/// use tower::Layer;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let metrics = Arc::new(MetricsCollector::with_defaults());
+/// let channel = tonic::transport::Channel::from_static("http://10.0.0.1:50000")
+///     .connect()
+///     .await?;
+/// let channel = MetricsInterceptor::new(metrics.clone(), "10.0.0.1:50000").layer(channel);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MetricsInterceptor {
+    collector: Arc<MetricsCollector>,
+    endpoint: String,
+}
+
+impl MetricsInterceptor {
+    /// Record calls into `collector`, labelled with `endpoint`.
+    #[must_use]
+    pub fn new(collector: Arc<MetricsCollector>, endpoint: impl Into<String>) -> Self {
+        Self {
+            collector,
+            endpoint: endpoint.into(),
+        }
+    }
+
+    /// The collector calls are recorded into.
+    #[must_use]
+    pub fn collector(&self) -> &Arc<MetricsCollector> {
+        &self.collector
+    }
+
+    /// Time `response` and record it as a call to `path`.
+    pub(crate) fn instrument<B, E>(
+        &self,
+        path: &str,
+        response: impl Future<Output = Result<http::Response<B>, E>> + Send + 'static,
+    ) -> BoxFuture<http::Response<B>, E>
+    where
+        B: Send + 'static,
+        E: Send + 'static,
+    {
+        let collector = self.collector.clone();
+        let endpoint = self.endpoint.clone();
+        let path = path.to_string();
+        let start = Instant::now();
+
+        Box::pin(async move {
+            let result = response.await;
+            let success = result.as_ref().is_ok_and(is_grpc_success);
+            collector.record_grpc_request(&path, &endpoint, success, start.elapsed());
+            result
+        })
+    }
+}
+
+/// Whether the response headers report a successful gRPC call.
+fn is_grpc_success<B>(response: &http::Response<B>) -> bool {
+    response.status().is_success()
+        && response
+            .headers()
+            .get("grpc-status")
+            .is_none_or(|status| status.as_bytes() == b"0")
+}
+
+impl<S> tower::Layer<S> for MetricsInterceptor {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService {
+            inner,
+            interceptor: self.clone(),
+        }
+    }
+}
+
+/// Service produced by [`MetricsInterceptor`].
+#[derive(Debug, Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+    interceptor: MetricsInterceptor,
+}
+
+impl<S, B, RB> tower::Service<http::Request<B>> for MetricsService<S>
+where
+    S: tower::Service<http::Request<B>, Response = http::Response<RB>>,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+    RB: Send + 'static,
+{
+    type Response = http::Response<RB>;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let path = request.uri().path().to_string();
+        self.interceptor.instrument(&path, self.inner.call(request))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_method_from_path() {
+        assert_eq!(method_from_path("/machine.MachineService/Memory"), "Memory");
+        assert_eq!(
+            method_from_path("/version.VersionService/Version"),
+            "Version"
+        );
+        assert_eq!(method_from_path("/grpc.health.v1.Health/Check/"), "Check");
+        assert_eq!(method_from_path("machine.MachineService/Read"), "Read");
+        assert_eq!(method_from_path("Memory"), "Memory");
+        assert_eq!(method_from_path("/"), "");
+        assert_eq!(method_from_path(""), "");
+    }
+
+    #[test]
+    fn test_record_grpc_request_labels_method() {
+        let metrics = MetricsCollector::with_defaults();
+        metrics.record_grpc_request(
+            "/machine.MachineService/Memory",
+            "10.0.0.1:50000",
+            true,
+            Duration::from_millis(10),
+        );
+
+        let output = metrics.to_prometheus_text();
+        assert!(output.contains("method=\"Memory\""));
+        assert!(!output.contains("MachineService"));
+    }
+
     #[test]
     fn test_metrics_config_default() {
         let config = MetricsConfig::default();
@@ -694,6 +948,67 @@ mod tests {
         assert_eq!(hist.total_count(), 1);
     }
 
+    #[test]
+    fn test_histogram_quantile_interpolates_within_bucket() {
+        let hist = Histogram::new(vec![0.01, 0.1, 1.0]);
+        // 50 fast, 40 medium, 10 slow observations.
+        for _ in 0..50 {
+            hist.observe(0.005);
+        }
+        for _ in 0..40 {
+            hist.observe(0.05);
+        }
+        for _ in 0..10 {
+            hist.observe(0.5);
+        }
+
+        let p50 = hist.quantile(0.5).unwrap();
+        assert!((0.0..=0.01).contains(&p50), "p50 = {p50}");
+        let p90 = hist.quantile(0.9).unwrap();
+        assert!((0.01..=0.1).contains(&p90), "p90 = {p90}");
+        let p95 = hist.quantile(0.95).unwrap();
+        assert!((0.1..=1.0).contains(&p95), "p95 = {p95}");
+        // Halfway through the last bucket's 10 observations.
+        assert!((p95 - 0.55).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_histogram_quantile_edge_cases() {
+        let hist = Histogram::new(vec![0.1, 1.0]);
+        assert_eq!(hist.quantile(0.5), None);
+
+        // Above every bucket: capped at the largest bound.
+        hist.observe(5.0);
+        assert_eq!(hist.quantile(0.99), Some(1.0));
+        assert_eq!(hist.quantile(1.5), None);
+    }
+
+    #[test]
+    fn test_collector_quantile() {
+        let metrics = MetricsCollector::new(
+            MetricsConfig::builder()
+                .histogram_buckets(vec![0.01, 0.1, 1.0])
+                .build(),
+        );
+        for _ in 0..99 {
+            metrics.record_request("Version", "10.0.0.1:50000", true, Duration::from_millis(5));
+        }
+        metrics.record_request(
+            "Version",
+            "10.0.0.1:50000",
+            true,
+            Duration::from_millis(500),
+        );
+
+        let p50 = metrics.quantile("Version", "10.0.0.1:50000", 0.5).unwrap();
+        assert!(p50 <= Duration::from_millis(10));
+        let p99 = metrics
+            .quantile("Version", "10.0.0.1:50000", 0.995)
+            .unwrap();
+        assert!(p99 > Duration::from_millis(100));
+        assert!(metrics.quantile("Memory", "10.0.0.1:50000", 0.5).is_none());
+    }
+
     #[test]
     fn test_metrics_without_labels() {
         let config = MetricsConfig::builder()
@@ -718,4 +1033,75 @@ mod tests {
         let uptime2 = metrics.uptime();
         assert!(uptime2 > uptime1);
     }
+
+    #[test]
+    fn test_record_request_concurrent_no_lost_observations() {
+        use std::sync::Arc;
+
+        const THREADS: usize = 8;
+        const CALLS: usize = 500;
+
+        let metrics = Arc::new(MetricsCollector::with_defaults());
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let metrics = Arc::clone(&metrics);
+                std::thread::spawn(move || {
+                    for i in 0..CALLS {
+                        // Fresh keys keep threads racing to create entries.
+                        let method = format!("Method{}", i % 50);
+                        metrics.record_request(
+                            &method,
+                            "10.0.0.1:50000",
+                            (i + t) % 2 == 0,
+                            Duration::from_millis(1),
+                        );
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(metrics.total_requests(), (THREADS * CALLS) as u64);
+        let observed: u64 = metrics
+            .request_duration
+            .read()
+            .unwrap()
+            .values()
+            .map(Histogram::total_count)
+            .sum();
+        assert_eq!(observed, (THREADS * CALLS) as u64);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_interceptor_records_calls() {
+        use tower::{Layer, Service};
+
+        let metrics = Arc::new(MetricsCollector::with_defaults());
+        let mut service = MetricsInterceptor::new(metrics.clone(), "10.0.0.1:50000").layer(
+            tower::service_fn(|request: http::Request<()>| async move {
+                let mut response = http::Response::new(());
+                if request.uri().path().ends_with("/Reset") {
+                    response
+                        .headers_mut()
+                        .insert("grpc-status", http::HeaderValue::from_static("14"));
+                }
+                Ok::<_, std::convert::Infallible>(response)
+            }),
+        );
+
+        for path in [
+            "/machine.MachineService/Memory",
+            "/machine.MachineService/Reset",
+        ] {
+            let request = http::Request::builder().uri(path).body(()).unwrap();
+            service.call(request).await.unwrap();
+        }
+
+        assert_eq!(metrics.total_requests(), 2);
+        assert_eq!(metrics.successful_requests(), 1);
+        assert_eq!(metrics.failed_requests(), 1);
+        assert!(metrics.quantile("Memory", "10.0.0.1:50000", 0.5).is_some());
+    }
 }
@@ -16,7 +16,10 @@ pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
 pub use logging::{
     InterceptorMetrics, LogLevel, LoggingConfig, LoggingInterceptor, RequestLogger, RequestSpan,
 };
-pub use metrics::{MetricsCollector, MetricsConfig, MetricsConfigBuilder, MetricsSnapshot};
+pub use metrics::{
+    method_from_path, MetricsCollector, MetricsConfig, MetricsConfigBuilder, MetricsInterceptor,
+    MetricsService, MetricsSnapshot,
+};
 pub use retry::{
     BackoffStrategy, CustomRetryPolicy, DefaultRetryPolicy, ExponentialBackoff, FixedBackoff,
     LinearBackoff, NoBackoff, NoRetryPolicy, RetryConfig, RetryConfigBuilder, RetryPolicy,
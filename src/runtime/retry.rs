@@ -337,6 +337,9 @@ impl RetryConfig {
 
 impl<P: RetryPolicy, B: BackoffStrategy> RetryConfig<P, B> {
     /// Execute an async operation with retry logic.
+    ///
+    /// The operation is always attempted once. A retry is only scheduled if
+    /// its backoff delay ends within the remaining `total_timeout` budget.
     pub async fn execute<T, E, F, Fut>(&self, mut operation: F) -> Result<T, E>
     where
         F: FnMut() -> Fut,
@@ -362,15 +365,16 @@ impl<P: RetryPolicy, B: BackoffStrategy> RetryConfig<P, B> {
                         return Err(e);
                     }
 
-                    // Check total timeout
+                    // Calculate delay, and give up if sleeping would exhaust
+                    // the total timeout before the next attempt could start
+                    let delay = self.backoff.delay(attempt);
                     if let Some(timeout) = self.total_timeout {
-                        if start.elapsed() >= timeout {
+                        let remaining = timeout.saturating_sub(start.elapsed());
+                        if remaining.is_zero() || delay >= remaining {
                             return Err(e);
                         }
                     }
 
-                    // Calculate delay and sleep
-                    let delay = self.backoff.delay(attempt);
                     tokio::time::sleep(delay).await;
 
                     attempt += 1;
@@ -490,11 +494,16 @@ impl AsGrpcStatus for crate::error::TalosError {
     fn grpc_code(&self) -> tonic::Code {
         match self {
             crate::error::TalosError::Api(status) => status.code(),
-            crate::error::TalosError::Transport(_) => tonic::Code::Unavailable,
+            crate::error::TalosError::Transport(_)
+            | crate::error::TalosError::EndpointTransport { .. } => tonic::Code::Unavailable,
+            // Certificates won't fix themselves, so don't retry.
+            crate::error::TalosError::Tls(_) => tonic::Code::Unauthenticated,
             crate::error::TalosError::Config(_) => tonic::Code::InvalidArgument,
             crate::error::TalosError::Validation(_) => tonic::Code::InvalidArgument,
             crate::error::TalosError::Connection(_) => tonic::Code::Unavailable,
             crate::error::TalosError::CircuitOpen(_) => tonic::Code::Unavailable,
+            crate::error::TalosError::Timeout(_) => tonic::Code::DeadlineExceeded,
+            crate::error::TalosError::NotFound(_) => tonic::Code::NotFound,
             crate::error::TalosError::Unknown(_) => tonic::Code::Internal,
         }
     }
@@ -656,4 +665,54 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
+
+    #[tokio::test]
+    async fn test_retry_execute_zero_total_timeout_single_attempt() {
+        let config = RetryConfig::builder()
+            .max_retries(5)
+            .backoff(NoBackoff::new())
+            .total_timeout(Duration::ZERO)
+            .build();
+
+        let call_count = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let call_count_clone = call_count.clone();
+
+        let result: Result<i32, tonic::Status> = config
+            .execute(|| {
+                let count = call_count_clone.clone();
+                async move {
+                    count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    Err(tonic::Status::unavailable("transient"))
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unavailable);
+        assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_execute_skips_backoff_beyond_total_timeout() {
+        let config = RetryConfig::builder()
+            .max_retries(5)
+            .backoff(FixedBackoff::from_millis(60))
+            .total_timeout(Duration::from_millis(100))
+            .build();
+
+        let call_count = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let call_count_clone = call_count.clone();
+        let result: Result<i32, tonic::Status> = config
+            .execute(|| {
+                let count = call_count_clone.clone();
+                async move {
+                    count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    Err(tonic::Status::unavailable("transient"))
+                }
+            })
+            .await;
+
+        // The retry at 60ms fits; a second backoff would end past 100ms.
+        assert!(result.is_err());
+        assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
 }
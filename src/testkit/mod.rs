@@ -1,25 +1,13 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use base64::prelude::*;
-use serde::Deserialize;
+use crate::client::{TalosClient, TalosClientConfig};
+use crate::config::TalosConfig;
+use crate::error::Result;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 
-#[derive(Deserialize, Debug)]
-struct TalosConfig {
-    contexts: std::collections::HashMap<String, ContextConfig>,
-}
-
-#[derive(Deserialize, Debug)]
-struct ContextConfig {
-    endpoints: Vec<String>,
-    ca: String,
-    crt: String,
-    key: String,
-}
-
 pub struct TalosCluster {
     pub name: String,
     pub endpoint: String,
@@ -85,38 +73,31 @@ impl TalosCluster {
         }
 
         // Parse talosconfig
-        let config_str = fs::read_to_string(&talosconfig_path).expect("Failed to read talosconfig");
-        let config: TalosConfig =
-            serde_yaml::from_str(&config_str).expect("Failed to parse talosconfig");
-
-        let (_, ctx) = config
-            .contexts
-            .iter()
-            .next()
+        let config =
+            TalosConfig::load_from_path(&talosconfig_path).expect("Failed to parse talosconfig");
+        let ctx = config
+            .active_context()
+            .or_else(|| config.contexts.values().next())
             .expect("No context in talosconfig");
+        let credentials = ctx
+            .credentials()
+            .expect("Failed to decode certs")
+            .expect("No certs in talosconfig");
 
-        // Helper to decode and write
-        let decode_and_write = |fname: &str, content: &str| -> PathBuf {
-            let bytes = BASE64_STANDARD
-                .decode(content)
-                .or_else(|_| BASE64_STANDARD.decode(content.replace('\n', "")))
-                .expect("Failed to decode cert");
+        let write = |fname: &str, content: &[u8]| -> PathBuf {
             let path = temp_dir.path().join(fname);
-            fs::write(&path, bytes).expect("Failed to write cert file");
+            fs::write(&path, content).expect("Failed to write cert file");
             path
         };
+        let ca_path = write("ca.crt", &credentials.ca);
+        let crt_path = write("client.crt", &credentials.crt);
+        let key_path = write("client.key", &credentials.key);
 
-        let ca_path = decode_and_write("ca.crt", &ctx.ca);
-        let crt_path = decode_and_write("client.crt", &ctx.crt);
-        let key_path = decode_and_write("client.key", &ctx.key);
-
-        // Format endpoint from first entry in endpoints array
-        let first_endpoint = ctx.endpoints.first().expect("No endpoints in talosconfig");
-        let endpoint = if first_endpoint.contains("://") {
-            first_endpoint.clone()
-        } else {
-            format!("https://{}", first_endpoint)
-        };
+        let endpoint = ctx
+            .endpoint_urls()
+            .into_iter()
+            .next()
+            .expect("No endpoints in talosconfig");
 
         Some(Self {
             name: name.to_string(),
@@ -128,6 +109,31 @@ impl TalosCluster {
             key_path,
         })
     }
+
+    /// Connects a `TalosClient` to the cluster.
+    /// Uses mTLS with the cluster certs and falls back to insecure mode if the
+    /// TLS handshake fails.
+    pub async fn client(&self) -> Result<TalosClient> {
+        let mtls_config = TalosClientConfig::builder(&self.endpoint)
+            .client_cert(self.crt_path.to_string_lossy())
+            .client_key(self.key_path.to_string_lossy())
+            .ca_cert(self.ca_path.to_string_lossy())
+            .build();
+
+        match TalosClient::new(mtls_config).await {
+            Ok(client) => Ok(client),
+            Err(e) if e.is_tls_failure() => {
+                println!("mTLS connection failed: {}", e);
+                println!("Falling back to insecure mode...");
+
+                let insecure_config = TalosClientConfig::builder(&self.endpoint)
+                    .insecure()
+                    .build();
+                TalosClient::new(insecure_config).await
+            }
+            Err(e) => Err(e),
+        }
+    }
 }
 
 impl Drop for TalosCluster {
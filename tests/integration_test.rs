@@ -1,7 +1,6 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use talos_api_rs::testkit::TalosCluster;
-use talos_api_rs::{TalosClient, TalosClientConfig};
 
 #[tokio::test]
 async fn test_cluster_lifecycle() {
@@ -19,34 +18,13 @@ async fn test_cluster_lifecycle() {
     println!("========================================");
     println!("Cluster provisioned at {}", cluster.endpoint);
 
-    // 2. Create Client with mTLS (using cluster certs)
-    let mtls_config = TalosClientConfig::builder(&cluster.endpoint)
-        .client_cert(cluster.crt_path.to_string_lossy())
-        .client_key(cluster.key_path.to_string_lossy())
-        .ca_cert(cluster.ca_path.to_string_lossy())
-        .build();
-
+    // 2. Create Client with mTLS (using cluster certs, insecure fallback)
     println!("\nUsing mTLS with certs from:");
     println!("  CA:  {}", cluster.ca_path.display());
     println!("  CRT: {}", cluster.crt_path.display());
     println!("  KEY: {}", cluster.key_path.display());
 
-    let client = match TalosClient::new(mtls_config).await {
-        Ok(c) => c,
-        Err(e) => {
-            // mTLS might fail due to ED25519 certs - fall back to insecure
-            println!("\nmTLS connection failed: {}", e);
-            println!("Falling back to insecure mode...\n");
-
-            let insecure_config = TalosClientConfig::builder(&cluster.endpoint)
-                .insecure()
-                .build();
-
-            TalosClient::new(insecure_config)
-                .await
-                .expect("Failed to connect insecurely")
-        }
-    };
+    let client = cluster.client().await.expect("Failed to connect");
 
     println!("\n--- Version API ---");
     let mut version_client = client.version();
@@ -70,81 +48,69 @@ async fn test_cluster_lifecycle() {
 
     // 3. Test Machine API - Hostname
     println!("\n--- Machine API: Hostname ---");
-    let mut machine_client = client.machine();
-    match machine_client.hostname(()).await {
+    match client.hostname().await {
         Ok(response) => {
-            for msg in &response.get_ref().messages {
-                let node = msg
-                    .metadata
-                    .as_ref()
-                    .map(|m| m.hostname.as_str())
-                    .unwrap_or("unknown");
-                println!("✓ Node: {} -> hostname: {}", node, msg.hostname);
+            for result in &response.results {
+                let node = result.node.as_deref().unwrap_or("unknown");
+                println!("✓ Node: {} -> hostname: {}", node, result.hostname);
             }
         }
-        Err(status) => {
-            println!("✗ Hostname call returned: {:?}", status.code());
+        Err(e) => {
+            println!("✗ Hostname call returned: {}", e);
             // mTLS required is expected - the transport worked
-            assert_ne!(status.code(), tonic::Code::Unavailable, "Transport failed");
+            if let talos_api_rs::TalosError::Api(status) = &e {
+                assert_ne!(status.code(), tonic::Code::Unavailable, "Transport failed");
+            }
         }
     }
 
     // 4. Test Machine API - ServiceList
     println!("\n--- Machine API: ServiceList ---");
-    let mut machine_client = client.machine();
-    match machine_client.service_list(()).await {
+    match client.service_list().await {
         Ok(response) => {
-            for msg in &response.get_ref().messages {
-                let node = msg
-                    .metadata
-                    .as_ref()
-                    .map(|m| m.hostname.as_str())
-                    .unwrap_or("unknown");
-                println!("✓ Node: {}", node);
+            for result in &response.results {
+                println!("✓ Node: {}", result.node.as_deref().unwrap_or("unknown"));
                 println!("  Services:");
-                for svc in &msg.services {
-                    let health = svc
-                        .health
-                        .as_ref()
-                        .map(|h| if h.healthy { "✓" } else { "✗" })
-                        .unwrap_or("?");
+                for svc in &result.services {
+                    let health = match svc.healthy {
+                        Some(true) => "✓",
+                        Some(false) => "✗",
+                        None => "?",
+                    };
                     println!("    {} {} [{}]", health, svc.id, svc.state);
                 }
             }
         }
-        Err(status) => {
-            println!("✗ ServiceList call returned: {:?}", status.code());
-            assert_ne!(status.code(), tonic::Code::Unavailable, "Transport failed");
+        Err(e) => {
+            println!("✗ ServiceList call returned: {}", e);
+            if let talos_api_rs::TalosError::Api(status) = &e {
+                assert_ne!(status.code(), tonic::Code::Unavailable, "Transport failed");
+            }
         }
     }
 
     // 5. Test Machine API - SystemStat
     println!("\n--- Machine API: SystemStat ---");
-    let mut machine_client = client.machine();
-    match machine_client.system_stat(()).await {
+    match client.system_stat().await {
         Ok(response) => {
-            for msg in &response.get_ref().messages {
-                let node = msg
-                    .metadata
-                    .as_ref()
-                    .map(|m| m.hostname.as_str())
-                    .unwrap_or("unknown");
-                println!("✓ Node: {}", node);
-                println!("  Boot time:         {}", msg.boot_time);
-                println!("  Processes running: {}", msg.process_running);
-                println!("  Processes blocked: {}", msg.process_blocked);
-                println!("  Context switches:  {}", msg.context_switches);
-                if let Some(cpu) = &msg.cpu_total {
-                    println!(
-                        "  CPU: user={:.1}% sys={:.1}% idle={:.1}%",
-                        cpu.user, cpu.system, cpu.idle
-                    );
-                }
+            for stat in &response.results {
+                println!("✓ Node: {}", stat.node.as_deref().unwrap_or("unknown"));
+                println!("  Boot time:         {}", stat.boot_time);
+                println!("  Processes running: {}", stat.process_running);
+                println!("  Processes blocked: {}", stat.process_blocked);
+                println!("  Context switches:  {}", stat.context_switches);
+                println!(
+                    "  CPU: {} cores, {:.1}% busy since boot",
+                    stat.cpu.len(),
+                    stat.cpu_total_usage_percent()
+                );
             }
         }
-        Err(status) => {
-            println!("✗ SystemStat call returned: {:?}", status.code());
-            assert_ne!(status.code(), tonic::Code::Unavailable, "Transport failed");
+        Err(e) => {
+            println!("✗ SystemStat call returned: {}", e);
+            if let talos_api_rs::TalosError::Api(status) = &e {
+                assert_ne!(status.code(), tonic::Code::Unavailable, "Transport failed");
+            }
         }
     }
 
@@ -179,7 +145,8 @@ cluster:
         .config_yaml(minimal_config)
         .mode(ApplyMode::Auto)
         .dry_run(true)
-        .build();
+        .build()
+        .expect("Failed to build apply request");
 
     match client.apply_configuration(request).await {
         Ok(apply_response) => {
@@ -338,14 +305,7 @@ cluster:
         Ok(response) => {
             if let Some(status) = response.first() {
                 println!("✓ etcd status retrieved");
-                println!("  Member ID: {}", status.member_id);
-                println!("  Protocol: {}", status.protocol_version);
-                println!("  DB Size: {}", status.db_size_human());
-                println!(
-                    "  Leader: {} (is_leader: {})",
-                    status.leader,
-                    status.is_leader()
-                );
+                println!("{}", status);
             }
         }
         Err(e) => {
@@ -378,6 +338,7 @@ cluster:
             println!("✓ Snapshot created successfully");
             println!("  Size: {}", response.size_human());
             println!("  Bytes: {}", response.len());
+            println!("  Node: {:?}", response.node);
         }
         Err(e) => {
             // Snapshot might fail if not on control plane